@@ -1,5 +1,15 @@
 use std::os::raw::c_int;
 
+/// libyuv的缩放滤波方式，越往后质量越好速度越慢
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterMode {
+    None = 0,
+    Linear = 1,
+    Bilinear = 2,
+    Box = 3,
+}
+
 extern "C" {
     // seems libyuv uses reverse byte order compared with our view
 
@@ -65,6 +75,38 @@ extern "C" {
         height: c_int,
     ) -> c_int;
 
+    pub fn I420Scale(
+        src_y: *const u8,
+        src_stride_y: c_int,
+        src_u: *const u8,
+        src_stride_u: c_int,
+        src_v: *const u8,
+        src_stride_v: c_int,
+        src_width: c_int,
+        src_height: c_int,
+        dst_y: *mut u8,
+        dst_stride_y: c_int,
+        dst_u: *mut u8,
+        dst_stride_u: c_int,
+        dst_v: *mut u8,
+        dst_stride_v: c_int,
+        dst_width: c_int,
+        dst_height: c_int,
+        filtering: FilterMode,
+    ) -> c_int;
+
+    pub fn ARGBScale(
+        src_argb: *const u8,
+        src_stride_argb: c_int,
+        src_width: c_int,
+        src_height: c_int,
+        dst_argb: *mut u8,
+        dst_stride_argb: c_int,
+        dst_width: c_int,
+        dst_height: c_int,
+        filtering: FilterMode,
+    ) -> c_int;
+
     pub fn I420ToARGB(
         src_y: *const u8,
         src_stride_y: c_int,
@@ -136,3 +178,95 @@ pub fn bgra_to_i420_n(width: usize, height: usize, src: &[u8]) ->(Vec<u8>,Vec<u8
     }
     return (dst_y,dst_u,dst_v)
 }
+
+fn i420_len(width: usize, height: usize) -> (usize, usize) {
+    let stride_uv = (width + 1) >> 1;
+    (width * height, stride_uv * ((height + 1) >> 1))
+}
+
+/// I420缩放，输入平面大小不对或者libyuv失败时返回None
+pub fn i420_scale(
+    src_width: usize,
+    src_height: usize,
+    src_y: &[u8],
+    src_u: &[u8],
+    src_v: &[u8],
+    dst_width: usize,
+    dst_height: usize,
+    filter: FilterMode,
+) -> Option<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+    if src_width == 0 || src_height == 0 || dst_width == 0 || dst_height == 0 {
+        return None;
+    }
+    let (src_y_len, src_uv_len) = i420_len(src_width, src_height);
+    if src_y.len() < src_y_len || src_u.len() < src_uv_len || src_v.len() < src_uv_len {
+        return None;
+    }
+    let (dst_y_len, dst_uv_len) = i420_len(dst_width, dst_height);
+    let mut dst_y = vec![0; dst_y_len];
+    let mut dst_u = vec![0; dst_uv_len];
+    let mut dst_v = vec![0; dst_uv_len];
+    let src_stride_uv = (src_width + 1) >> 1;
+    let dst_stride_uv = (dst_width + 1) >> 1;
+    let rs = unsafe {
+        I420Scale(
+            src_y.as_ptr(),
+            src_width as _,
+            src_u.as_ptr(),
+            src_stride_uv as _,
+            src_v.as_ptr(),
+            src_stride_uv as _,
+            src_width as _,
+            src_height as _,
+            dst_y.as_mut_ptr(),
+            dst_width as _,
+            dst_u.as_mut_ptr(),
+            dst_stride_uv as _,
+            dst_v.as_mut_ptr(),
+            dst_stride_uv as _,
+            dst_width as _,
+            dst_height as _,
+            filter,
+        )
+    };
+    if rs != 0 {
+        return None;
+    }
+    Some((dst_y, dst_u, dst_v))
+}
+
+/// ARGB(BGRA)缩放，输出按dst_width * 4紧密排列
+pub fn argb_scale(
+    src: &[u8],
+    src_stride: usize,
+    src_width: usize,
+    src_height: usize,
+    dst_width: usize,
+    dst_height: usize,
+    filter: FilterMode,
+) -> Option<Vec<u8>> {
+    if src_width == 0 || src_height == 0 || dst_width == 0 || dst_height == 0 {
+        return None;
+    }
+    if src_stride < src_width * 4 || src.len() < src_stride * src_height {
+        return None;
+    }
+    let mut dst = vec![0; dst_width * dst_height * 4];
+    let rs = unsafe {
+        ARGBScale(
+            src.as_ptr(),
+            src_stride as _,
+            src_width as _,
+            src_height as _,
+            dst.as_mut_ptr(),
+            (dst_width * 4) as _,
+            dst_width as _,
+            dst_height as _,
+            filter,
+        )
+    };
+    if rs != 0 {
+        return None;
+    }
+    Some(dst)
+}
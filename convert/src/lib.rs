@@ -1,8 +1,35 @@
 pub mod convert;
 #[cfg(test)]
 mod tests {
+    use crate::convert::{argb_scale, i420_scale, FilterMode};
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn test_i420_scale() {
+        let y = vec![200u8; 64 * 32];
+        let u = vec![100u8; 32 * 16];
+        let v = vec![50u8; 32 * 16];
+        let (dy, du, dv) = i420_scale(64, 32, &y, &u, &v, 32, 16, FilterMode::Box).unwrap();
+        assert_eq!(dy.len(), 32 * 16);
+        assert_eq!(du.len(), 16 * 8);
+        assert_eq!(dv.len(), 16 * 8);
+        assert_eq!(dy[0], 200);
+        assert_eq!(du[0], 100);
+        assert_eq!(dv[0], 50);
+        //平面太小
+        assert!(i420_scale(64, 32, &y[..10], &u, &v, 32, 16, FilterMode::Box).is_none());
+    }
+
+    #[test]
+    fn test_argb_scale() {
+        let src = vec![7u8; 8 * 4 * 4];
+        let dst = argb_scale(&src, 8 * 4, 8, 4, 4, 2, FilterMode::Bilinear).unwrap();
+        assert_eq!(dst.len(), 4 * 2 * 4);
+        assert!(dst.iter().all(|b| *b == 7));
+        assert!(argb_scale(&src, 8 * 4, 8, 5, 4, 2, FilterMode::None).is_none());
+    }
 }
@@ -0,0 +1,13 @@
+//! 审计日志，写入名为audit的日志目标，由log.yaml配置输出到单独的文件
+
+///记录一条审计事件，peer为对方的设备代号。
+///对方可以控制的内容转义换行等字符，不能伪造日志行
+pub fn record(peer: &str, event: &str, detail: &str) {
+    log::info!(
+        target: "audit",
+        "peer:{} event:{} {}",
+        peer.escape_debug(),
+        event,
+        detail.escape_debug()
+    );
+}
@@ -0,0 +1,244 @@
+//! 会话中的文字聊天，主控端和被控端共用。
+//! 每条消息带发送方生成的编号和时间，对方收到后回复确认，
+//! 聊天记录只保留到会话结束
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, RwLock,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use stream::{
+    message::{chat_message, ChatAck, ChatMessage, ChatText},
+    protobuf::Message,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    RemoteError, ResultType,
+};
+
+use crate::config::Config;
+
+//单条消息的最大字节数
+pub const MAX_TEXT_LEN: usize = 4096;
+//对方声明的发送者名称的最大字节数
+const MAX_SENDER_LEN: usize = 64;
+
+///一条聊天记录
+#[derive(Debug, Clone)]
+pub struct Entry {
+    //发送方生成的编号，收发两个方向各自编号
+    pub id: u64,
+    //Unix毫秒数
+    pub timestamp: u64,
+    //true为本端发出
+    pub outgoing: bool,
+    pub sender: String,
+    pub text: String,
+    //本端发出的消息是否已被对方确认，收到的消息总为true
+    pub delivered: bool,
+}
+
+pub struct Chat {
+    //被控端为建立该会话的主控端代号，主控端为空
+    peer_id: String,
+    sender: Mutex<Option<ChannelSender>>,
+    history: RwLock<Vec<Entry>>,
+    next_id: AtomicU64,
+    //收发消息时调用，参数为peer_id和消息，被控端可以据此写入审计日志
+    listener: RwLock<Option<fn(&str, &Entry)>>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+//超过长度时在字符边界截断
+fn truncate(mut text: String, max: usize) -> String {
+    if text.len() > max {
+        let mut end = max;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+    }
+    text
+}
+
+impl Chat {
+    pub fn new() -> Self {
+        Self::with_peer("")
+    }
+    pub fn with_peer(peer_id: &str) -> Self {
+        Self {
+            peer_id: peer_id.to_string(),
+            sender: Mutex::new(None),
+            history: RwLock::new(Vec::new()),
+            next_id: AtomicU64::new(1),
+            listener: RwLock::new(None),
+        }
+    }
+    pub fn set_listener(&self, listener: Option<fn(&str, &Entry)>) {
+        *self.listener.write().unwrap() = listener;
+    }
+    ///开始新的会话，清空上一次会话的记录，直到通道关闭
+    pub fn run(&self, sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+        self.history.write().unwrap().clear();
+        *self.sender.lock().unwrap() = Some(sender);
+        let rs = self.receive(receiver);
+        self.sender.lock().unwrap().take();
+        rs
+    }
+    fn receive(&self, receiver: ChannelReceiver) -> ResultType<()> {
+        while let Ok(data) = receiver.recv() {
+            let msg = ChatMessage::parse_from_bytes(&data)?;
+            match msg.union {
+                Some(chat_message::Union::text(text)) => {
+                    let mut ack = ChatMessage::new();
+                    ack.set_ack(ChatAck {
+                        id: text.id,
+                        ..Default::default()
+                    });
+                    self.send_message(ack)?;
+                    self.on_text(text);
+                }
+                Some(chat_message::Union::ack(ack)) => self.on_ack(ack.id),
+                None => {}
+            }
+        }
+        Ok(())
+    }
+    fn on_text(&self, text: ChatText) {
+        let entry = Entry {
+            id: text.id,
+            timestamp: text.timestamp,
+            outgoing: false,
+            //对方的内容不可信，超长时截断
+            sender: truncate(text.sender, MAX_SENDER_LEN),
+            text: truncate(text.text, MAX_TEXT_LEN),
+            delivered: true,
+        };
+        let mut history = self.history.write().unwrap();
+        //对方重发的消息只记录一次
+        if history.iter().any(|e| !e.outgoing && e.id == entry.id) {
+            return;
+        }
+        if let Some(listener) = *self.listener.read().unwrap() {
+            listener(&self.peer_id, &entry);
+        }
+        history.push(entry);
+    }
+    fn on_ack(&self, id: u64) {
+        let mut history = self.history.write().unwrap();
+        if let Some(entry) = history.iter_mut().find(|e| e.outgoing && e.id == id) {
+            entry.delivered = true;
+        }
+    }
+    fn send_message(&self, msg: ChatMessage) -> ResultType<()> {
+        let mut sender = match self.sender.lock().unwrap().clone() {
+            Some(sender) => sender,
+            None => Err(RemoteError::Channel(String::from("聊天通道未建立")))?,
+        };
+        sender.send(msg.write_to_bytes()?)
+    }
+    ///发送一条消息，返回消息编号
+    pub fn send(&self, text: &str) -> ResultType<u64> {
+        let text = text.trim();
+        if text.is_empty() {
+            Err(RemoteError::InvalidData(String::from("消息不能为空")))?;
+        }
+        if text.len() > MAX_TEXT_LEN {
+            Err(RemoteError::InvalidData(format!(
+                "消息不能超过{}字节",
+                MAX_TEXT_LEN
+            )))?;
+        }
+        let entry = Entry {
+            id: self.next_id.fetch_add(1, Ordering::SeqCst),
+            timestamp: now(),
+            outgoing: true,
+            sender: Config::get_id(),
+            text: text.to_string(),
+            delivered: false,
+        };
+        let mut msg = ChatMessage::new();
+        msg.set_text(ChatText {
+            id: entry.id,
+            timestamp: entry.timestamp,
+            sender: entry.sender.clone(),
+            text: entry.text.clone(),
+            ..Default::default()
+        });
+        self.send_message(msg)?;
+        let id = entry.id;
+        if let Some(listener) = *self.listener.read().unwrap() {
+            listener(&self.peer_id, &entry);
+        }
+        self.history.write().unwrap().push(entry);
+        Ok(id)
+    }
+    pub fn history(&self) -> Vec<Entry> {
+        self.history.read().unwrap().clone()
+    }
+    pub fn is_connected(&self) -> bool {
+        self.sender.lock().unwrap().is_some()
+    }
+}
+
+impl Default for Chat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history() {
+        let chat = Chat::new();
+        chat.history.write().unwrap().push(Entry {
+            id: 1,
+            timestamp: now(),
+            outgoing: true,
+            sender: String::from("me"),
+            text: String::from("hello"),
+            delivered: false,
+        });
+        let text = ChatText {
+            id: 1,
+            timestamp: now(),
+            sender: String::from("peer"),
+            text: String::from("工单号 1234"),
+            ..Default::default()
+        };
+        chat.on_text(text.clone());
+        chat.on_text(text);
+        //对方的编号和本端的编号互不影响
+        chat.on_ack(1);
+        let history = chat.history();
+        assert_eq!(history.len(), 2);
+        assert!(history[0].outgoing && history[0].delivered);
+        assert!(!history[1].outgoing);
+        assert_eq!(history[1].text, "工单号 1234");
+    }
+
+    #[test]
+    fn test_truncate_incoming() {
+        let chat = Chat::new();
+        chat.on_text(ChatText {
+            id: 1,
+            sender: "名".repeat(MAX_SENDER_LEN),
+            text: "字".repeat(MAX_TEXT_LEN),
+            ..Default::default()
+        });
+        let entry = &chat.history()[0];
+        assert!(entry.sender.len() <= MAX_SENDER_LEN);
+        assert!(entry.text.len() <= MAX_TEXT_LEN);
+        assert!(entry.text.chars().all(|c| c == '字'));
+    }
+}
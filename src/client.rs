@@ -1,4 +1,13 @@
+pub mod audio_client;
+pub mod chat_client;
+pub mod clipboard_client;
+pub mod file_browser_client;
+pub mod file_transfer_client;
+pub mod input_status;
 pub mod key_enent;
 pub mod mouse_event;
+pub mod port_forward_client;
+pub mod presence;
 pub mod video_client;
 pub mod remote_event_client;
+pub mod terminal_client;
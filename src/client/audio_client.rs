@@ -0,0 +1,302 @@
+//! 播放被控端的声音。收到的帧先进入抖动缓冲，按序号取出解码，
+//! 丢失的帧由Opus补偿，再按视频帧的时间戳对齐音画
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use stream::{
+    message::{audio_message, AudioFrame, AudioMessage},
+    protobuf::Message,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    RemoteError, ResultType,
+};
+
+use super::video_client;
+
+const SAMPLE_RATE: u32 = 48000;
+const CHANNELS: usize = 2;
+//每帧每声道的采样数，20ms
+const FRAME_SAMPLES: usize = SAMPLE_RATE as usize / 50;
+//开始播放前缓冲的帧数
+const TARGET_FRAMES: usize = 3;
+//序号跳跃超过该值时认为被控端重新开始发送，不再逐帧补偿
+const MAX_GAP: u32 = 10;
+//音频超前视频超过该值时等待
+const MAX_AHEAD_MS: u64 = 40;
+//音频落后视频超过该值时丢弃
+const MAX_BEHIND_MS: u64 = 200;
+//检查抖动缓冲的间隔
+const FILL_INTERVAL: Duration = Duration::from_millis(5);
+
+lazy_static::lazy_static! {
+    static ref SENDER:Mutex<Option<ChannelSender>> = Mutex::new(None);
+    static ref ENABLED:AtomicBool = AtomicBool::new(true);
+}
+
+///按序号排列收到的帧，缓冲到目标帧数后才开始取出
+pub struct JitterBuffer {
+    frames: BTreeMap<u32, AudioFrame>,
+    //下一个要取出的序号
+    next: Option<u32>,
+    target: usize,
+    buffering: bool,
+}
+
+impl JitterBuffer {
+    pub fn new(target: usize) -> Self {
+        Self {
+            frames: BTreeMap::new(),
+            next: None,
+            target,
+            buffering: true,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+    pub fn push(&mut self, frame: AudioFrame) {
+        //已经播放过的序号到得太晚，直接丢弃
+        if matches!(self.next, Some(next) if frame.seq < next) {
+            return;
+        }
+        self.frames.insert(frame.seq, frame);
+    }
+    ///下一个要播放的帧，已收到时才返回
+    pub fn peek(&self) -> Option<&AudioFrame> {
+        self.next.and_then(|next| self.frames.get(&next))
+    }
+    ///None为正在缓冲，Some(None)为该帧丢失，需要补偿
+    pub fn pop(&mut self) -> Option<Option<AudioFrame>> {
+        if self.buffering {
+            if self.frames.len() < self.target {
+                return None;
+            }
+            self.buffering = false;
+        }
+        let first = match self.frames.keys().next() {
+            Some(first) => *first,
+            None => {
+                self.buffering = true;
+                return None;
+            }
+        };
+        let next = match self.next {
+            Some(next) if first.saturating_sub(next) <= MAX_GAP => next,
+            _ => first,
+        };
+        self.next = Some(next.wrapping_add(1));
+        Some(self.frames.remove(&next))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SyncAction {
+    Play,
+    //超前视频，等视频跟上
+    Wait,
+    //落后视频太多，丢弃
+    Drop,
+}
+
+///按当前画面的时间决定音频帧如何处理，没有画面时直接播放
+pub fn sync_action(audio_ts: u64, video_now: Option<u64>) -> SyncAction {
+    match video_now {
+        Some(video) if audio_ts > video + MAX_AHEAD_MS => SyncAction::Wait,
+        Some(video) if audio_ts + MAX_BEHIND_MS < video => SyncAction::Drop,
+        _ => SyncAction::Play,
+    }
+}
+
+fn opus_error(e: opus::Error) -> RemoteError {
+    RemoteError::InvalidData(format!("Opus解码失败:{}", e))
+}
+fn to_error<E: std::fmt::Display>(e: E) -> RemoteError {
+    RemoteError::Channel(format!("音频播放失败:{}", e))
+}
+
+pub fn start(sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+    *SENDER.lock().unwrap() = Some(sender);
+    //重新连接后保持之前的开关
+    let rs = send_enabled(ENABLED.load(Ordering::SeqCst)).and_then(|_| play(receiver));
+    SENDER.lock().unwrap().take();
+    rs
+}
+
+fn play(receiver: ChannelReceiver) -> ResultType<()> {
+    let jitter = Arc::new(Mutex::new(JitterBuffer::new(TARGET_FRAMES)));
+    let closed = Arc::new(AtomicBool::new(false));
+    {
+        let jitter = jitter.clone();
+        let closed = closed.clone();
+        std::thread::spawn(move || {
+            let rs = receive(receiver, &jitter);
+            log::info!("音频接收结束:{:?}", rs);
+            closed.store(true, Ordering::SeqCst);
+        });
+    }
+    //声卡回调从这里取交错的采样，不够时补静音
+    let ring = Arc::new(Mutex::new(VecDeque::<f32>::new()));
+    let device = cpal::default_host()
+        .default_output_device()
+        .ok_or_else(|| RemoteError::Channel(String::from("没有可用的音频输出设备")))?;
+    let config = cpal::StreamConfig {
+        channels: CHANNELS as u16,
+        sample_rate: cpal::SampleRate(SAMPLE_RATE),
+        buffer_size: cpal::BufferSize::Default,
+    };
+    let output = ring.clone();
+    let stream = device
+        .build_output_stream(
+            &config,
+            move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
+                let mut output = output.lock().unwrap();
+                for sample in data.iter_mut() {
+                    *sample = output.pop_front().unwrap_or(0.0);
+                }
+            },
+            |e| log::info!("音频输出错误:{:?}", e),
+            None,
+        )
+        .map_err(to_error)?;
+    stream.play().map_err(to_error)?;
+
+    let mut decoder =
+        opus::Decoder::new(SAMPLE_RATE, opus::Channels::Stereo).map_err(opus_error)?;
+    let mut pcm = vec![0f32; FRAME_SAMPLES * CHANNELS];
+    while !closed.load(Ordering::SeqCst) {
+        std::thread::sleep(FILL_INTERVAL);
+        //关闭时丢弃已缓冲的声音
+        if !ENABLED.load(Ordering::SeqCst) {
+            ring.lock().unwrap().clear();
+            continue;
+        }
+        //声卡缓冲少于两帧时补充
+        while ring.lock().unwrap().len() < FRAME_SAMPLES * CHANNELS * 2 {
+            let mut jitter = jitter.lock().unwrap();
+            if let Some(frame) = jitter.peek() {
+                if sync_action(frame.timestamp, video_client::video_clock()) == SyncAction::Wait {
+                    break;
+                }
+            }
+            let data = match jitter.pop() {
+                None => break,
+                Some(Some(frame)) => {
+                    match sync_action(frame.timestamp, video_client::video_clock()) {
+                        SyncAction::Drop => continue,
+                        _ => frame.data,
+                    }
+                }
+                //空数据让解码器补偿丢失的帧
+                Some(None) => Vec::new(),
+            };
+            drop(jitter);
+            let len = decoder
+                .decode_float(&data, &mut pcm, false)
+                .map_err(opus_error)?;
+            ring.lock()
+                .unwrap()
+                .extend(pcm[..len * CHANNELS].iter().copied());
+        }
+    }
+    Ok(())
+}
+
+fn receive(receiver: ChannelReceiver, jitter: &Mutex<JitterBuffer>) -> ResultType<()> {
+    while let Ok(data) = receiver.recv() {
+        let msg = AudioMessage::parse_from_bytes(&data)?;
+        match msg.union {
+            Some(audio_message::Union::format(format)) => {
+                log::info!("音频格式:{}Hz,{}声道", format.sample_rate, format.channels);
+                if format.sample_rate != SAMPLE_RATE || format.channels != CHANNELS as u32 {
+                    Err(RemoteError::InvalidData(String::from("不支持的音频格式")))?;
+                }
+            }
+            Some(audio_message::Union::frame(frame)) => {
+                if ENABLED.load(Ordering::SeqCst) {
+                    jitter.lock().unwrap().push(frame);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn send_enabled(enabled: bool) -> ResultType<()> {
+    let mut sender = match SENDER.lock().unwrap().clone() {
+        Some(sender) => sender,
+        None => return Ok(()),
+    };
+    let mut msg = AudioMessage::new();
+    msg.set_enabled(enabled);
+    sender.send(msg.write_to_bytes()?)
+}
+
+///开关声音，关闭时被控端暂停发送
+pub fn set_enabled(enabled: bool) -> ResultType<()> {
+    ENABLED.store(enabled, Ordering::SeqCst);
+    send_enabled(enabled)
+}
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::SeqCst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(seq: u32) -> AudioFrame {
+        AudioFrame {
+            data: vec![seq as u8],
+            timestamp: seq as u64 * 20,
+            seq,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_jitter_buffer() {
+        let mut jitter = JitterBuffer::new(3);
+        jitter.push(frame(1));
+        jitter.push(frame(0));
+        //不足目标帧数时继续缓冲
+        assert!(jitter.pop().is_none());
+        jitter.push(frame(3));
+        //乱序到达的帧按序号取出，丢失的帧返回Some(None)
+        assert_eq!(jitter.pop().unwrap().unwrap().seq, 0);
+        assert_eq!(jitter.pop().unwrap().unwrap().seq, 1);
+        assert!(jitter.pop().unwrap().is_none());
+        //迟到的帧丢弃
+        jitter.push(frame(2));
+        assert_eq!(jitter.len(), 1);
+        assert_eq!(jitter.pop().unwrap().unwrap().seq, 3);
+        //取空后重新缓冲
+        assert!(jitter.pop().is_none());
+        jitter.push(frame(4));
+        assert!(jitter.pop().is_none());
+        //序号跳跃太大时从新的序号开始
+        jitter.push(frame(100));
+        jitter.push(frame(101));
+        assert_eq!(jitter.pop().unwrap().unwrap().seq, 4);
+        assert_eq!(jitter.pop().unwrap().unwrap().seq, 100);
+    }
+
+    #[test]
+    fn test_sync_action() {
+        assert_eq!(sync_action(1000, None), SyncAction::Play);
+        assert_eq!(sync_action(1000, Some(990)), SyncAction::Play);
+        assert_eq!(sync_action(1000, Some(1100)), SyncAction::Play);
+        assert_eq!(sync_action(1000, Some(900)), SyncAction::Wait);
+        assert_eq!(sync_action(1000, Some(1300)), SyncAction::Drop);
+    }
+}
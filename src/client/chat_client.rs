@@ -0,0 +1,20 @@
+use stream::{
+    remote_channel::{ChannelReceiver, ChannelSender},
+    ResultType,
+};
+
+use crate::chat::{Chat, Entry};
+
+lazy_static::lazy_static! {
+    static ref CHAT:Chat = Chat::new();
+}
+
+pub fn start(sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+    CHAT.run(sender, receiver)
+}
+pub fn send(text: &str) -> ResultType<u64> {
+    CHAT.send(text)
+}
+pub fn history() -> Vec<Entry> {
+    CHAT.history()
+}
@@ -0,0 +1,37 @@
+use std::sync::{atomic::AtomicBool, Arc};
+
+use crossbeam::atomic::AtomicCell;
+use stream::{
+    protobuf::Message,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    ResultType,
+};
+
+use crate::clipboard;
+
+lazy_static::lazy_static! {
+    //本次会话是否同步剪贴板
+    static ref ENABLED:Arc<AtomicBool> = Arc::new(AtomicBool::new(true));
+    static ref SENDER_CELL:AtomicCell<Option<ChannelSender>> =  AtomicCell::new(None);
+}
+
+pub fn start(sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+    ENABLED.store(true, std::sync::atomic::Ordering::SeqCst);
+    SENDER_CELL.store(Some(sender.clone()));
+    let rs = clipboard::start(sender, receiver, ENABLED.clone());
+    SENDER_CELL.take();
+    rs
+}
+pub fn is_enabled() -> bool {
+    ENABLED.load(std::sync::atomic::Ordering::SeqCst)
+}
+///开启或关闭剪贴板同步，并通知被控端
+pub fn set_enabled(enabled: bool) -> ResultType<()> {
+    ENABLED.store(enabled, std::sync::atomic::Ordering::SeqCst);
+    if let Some(mut sender) = SENDER_CELL.take() {
+        let rs = sender.send(clipboard::enable_message(enabled).write_to_bytes()?);
+        SENDER_CELL.store(Some(sender));
+        rs?;
+    }
+    Ok(())
+}
@@ -0,0 +1,171 @@
+//! 浏览被控端的文件，请求按id对应返回结果，调用方阻塞等待
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{channel, RecvTimeoutError, Sender},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use stream::{
+    message::{FileBrowserRequest, FileBrowserResponse, FileEntry, RemoveFile, RenameFile},
+    protobuf::Message,
+    rand::Rng,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    RemoteError, ResultType,
+};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+lazy_static::lazy_static! {
+    static ref SENDER:Mutex<Option<ChannelSender>> = Mutex::new(None);
+    static ref PENDING:Mutex<HashMap<u64, Sender<FileBrowserResponse>>> = Mutex::new(HashMap::new());
+    //被控端是否只读，收到第一个回复后才准确
+    static ref READ_ONLY:AtomicBool = AtomicBool::new(false);
+}
+
+pub fn start(sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+    *SENDER.lock().unwrap() = Some(sender);
+    let rs = receive(receiver);
+    SENDER.lock().unwrap().take();
+    //等待中的请求随发送端释放而结束
+    PENDING.lock().unwrap().clear();
+    rs
+}
+fn receive(receiver: ChannelReceiver) -> ResultType<()> {
+    while let Ok(data) = receiver.recv() {
+        let response = FileBrowserResponse::parse_from_bytes(&data)?;
+        READ_ONLY.store(response.read_only, Ordering::SeqCst);
+        if let Some(sender) = PENDING.lock().unwrap().remove(&response.id) {
+            let _ = sender.send(response);
+        }
+    }
+    Ok(())
+}
+
+fn call(mut request: FileBrowserRequest) -> ResultType<Vec<FileEntry>> {
+    let id: u64 = stream::rand::thread_rng().gen();
+    request.id = id;
+    let mut sender = match SENDER.lock().unwrap().clone() {
+        Some(sender) => sender,
+        None => Err(RemoteError::Channel(String::from("文件浏览通道未建立")))?,
+    };
+    let (response_sender, response_receiver) = channel();
+    PENDING.lock().unwrap().insert(id, response_sender);
+    if let Err(e) = sender.send(request.write_to_bytes()?) {
+        PENDING.lock().unwrap().remove(&id);
+        return Err(e);
+    }
+    let response = match response_receiver.recv_timeout(TIMEOUT) {
+        Ok(response) => response,
+        Err(RecvTimeoutError::Timeout) => {
+            PENDING.lock().unwrap().remove(&id);
+            Err(RemoteError::Peer(String::from("文件浏览请求超时")))?
+        }
+        Err(RecvTimeoutError::Disconnected) => Err(RemoteError::Disconnection)?,
+    };
+    if !response.error.is_empty() {
+        Err(RemoteError::Peer(response.error))?;
+    }
+    Ok(response.entries)
+}
+
+pub fn is_read_only() -> bool {
+    READ_ONLY.load(Ordering::SeqCst)
+}
+///允许访问的根目录，name为完整路径
+pub fn roots() -> ResultType<Vec<FileEntry>> {
+    let mut request = FileBrowserRequest::new();
+    request.set_roots(true);
+    call(request)
+}
+pub fn list_dir(path: &str) -> ResultType<Vec<FileEntry>> {
+    let mut request = FileBrowserRequest::new();
+    request.set_list_dir(path.to_string());
+    call(request)
+}
+pub fn stat(path: &str) -> ResultType<FileEntry> {
+    let mut request = FileBrowserRequest::new();
+    request.set_stat(path.to_string());
+    match call(request)?.pop() {
+        Some(entry) => Ok(entry),
+        None => Err(RemoteError::InvalidData(String::from("stat没有返回结果")))?,
+    }
+}
+pub fn mkdir(path: &str) -> ResultType<()> {
+    let mut request = FileBrowserRequest::new();
+    request.set_mkdir(path.to_string());
+    call(request)?;
+    Ok(())
+}
+pub fn rename(from: &str, to: &str) -> ResultType<()> {
+    let mut request = FileBrowserRequest::new();
+    request.set_rename(RenameFile {
+        from: from.to_string(),
+        to: to.to_string(),
+        ..Default::default()
+    });
+    call(request)?;
+    Ok(())
+}
+pub fn remove(path: &str, recursive: bool) -> ResultType<()> {
+    let mut request = FileBrowserRequest::new();
+    request.set_remove(RemoveFile {
+        path: path.to_string(),
+        recursive,
+        ..Default::default()
+    });
+    call(request)?;
+    Ok(())
+}
+
+//被控端可能是不同的系统，按路径中已有的分隔符拼接
+fn separator(path: &str) -> char {
+    if path.contains('\\') && !path.contains('/') {
+        '\\'
+    } else {
+        '/'
+    }
+}
+///拼接被控端的路径
+pub fn join(dir: &str, name: &str) -> String {
+    let separator = separator(dir);
+    if dir.ends_with(separator) {
+        format!("{}{}", dir, name)
+    } else {
+        format!("{}{}{}", dir, separator, name)
+    }
+}
+///被控端路径的上级目录，已经是根时返回None
+pub fn parent(path: &str) -> Option<String> {
+    let separator = separator(path);
+    let trimmed = path.trim_end_matches(separator);
+    let index = trimmed.rfind(separator)?;
+    //保留根目录的分隔符，如 / 和 C:\
+    if index == 0 || trimmed[..index].ends_with(':') {
+        return Some(trimmed[..=index].to_string());
+    }
+    Some(trimmed[..index].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        assert_eq!(join("/home/a", "b.txt"), "/home/a/b.txt");
+        assert_eq!(join("/", "home"), "/home");
+        assert_eq!(join("C:\\Users", "a"), "C:\\Users\\a");
+        assert_eq!(join("C:\\", "Users"), "C:\\Users");
+        assert_eq!(parent("/home/a").as_deref(), Some("/home"));
+        assert_eq!(parent("/home").as_deref(), Some("/"));
+        assert_eq!(parent("C:\\Users\\a").as_deref(), Some("C:\\Users"));
+        assert_eq!(parent("C:\\Users").as_deref(), Some("C:\\"));
+        assert_eq!(parent("C:\\"), None);
+        assert_eq!(parent("/"), None);
+    }
+}
@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+use directories_next::UserDirs;
+use stream::{
+    remote_channel::{ChannelReceiver, ChannelSender},
+    RemoteError, ResultType,
+};
+
+use crate::file_transfer::{self, Policy, Session, Transfer};
+
+lazy_static::lazy_static! {
+    static ref SESSION:std::sync::Mutex<Option<Session>> = std::sync::Mutex::new(None);
+}
+
+///主控端只接收自己请求的文件，保存到下载目录
+pub fn start(sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+    let default_dir = UserDirs::new()
+        .and_then(|dirs| dirs.download_dir().map(|dir| dir.to_path_buf()))
+        .unwrap_or_else(|| PathBuf::from("."));
+    let policy = Policy {
+        allowed_dirs: None,
+        default_dir,
+        accept_offers: false,
+        serve_requests: false,
+    };
+    let session = Session::new(sender, policy);
+    *SESSION.lock().unwrap() = Some(session.clone());
+    let rs = session.run(receiver);
+    SESSION.lock().unwrap().take();
+    rs
+}
+fn session() -> ResultType<Session> {
+    match SESSION.lock().unwrap().clone() {
+        Some(session) => Ok(session),
+        None => Err(RemoteError::Channel(String::from("文件传输通道未建立")))?,
+    }
+}
+///发送本机文件到被控端的目录，dir为空时使用被控端的默认目录
+pub fn send_file(path: &str, dir: &str) -> ResultType<u64> {
+    session()?.send_file(path, dir)
+}
+///请求被控端的文件，保存到本机下载目录
+pub fn request_file(path: &str) -> ResultType<u64> {
+    session()?.request_file(path)
+}
+pub fn transfers() -> Vec<Transfer> {
+    file_transfer::transfers()
+}
+pub fn clear_finished() {
+    file_transfer::clear_finished()
+}
@@ -0,0 +1,45 @@
+use std::sync::RwLock;
+
+use stream::{
+    message::{input_status, InputStatus},
+    protobuf::Message,
+    remote_channel::ChannelReceiver,
+};
+
+lazy_static::lazy_static! {
+    static ref STATUS:RwLock<Vec<InputStatus>> = RwLock::new(Vec::new());
+}
+
+///接收被控端返回的输入注入状态
+pub fn watch(receiver: ChannelReceiver) {
+    std::thread::spawn(move || {
+        while let Ok(data) = receiver.recv() {
+            match InputStatus::parse_from_bytes(&data) {
+                Ok(status) => {
+                    log::info!("输入状态:{:?}", status);
+                    let mut list = STATUS.write().unwrap();
+                    list.retain(|s| s.backend != status.backend || s.message != status.message);
+                    list.push(status);
+                }
+                Err(e) => {
+                    log::info!("输入状态解析失败:{:?}", e);
+                }
+            }
+        }
+    });
+}
+
+///被控端无法注入输入时的错误信息，没有错误时为空
+pub fn errors() -> Vec<String> {
+    STATUS
+        .read()
+        .unwrap()
+        .iter()
+        .filter(|status| status.code.enum_value_or_default() != input_status::Code::Ok)
+        .map(|status| format!("{}: {}", status.backend, status.message))
+        .collect()
+}
+
+pub fn clear() {
+    STATUS.write().unwrap().clear();
+}
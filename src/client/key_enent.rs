@@ -1,24 +1,55 @@
 use crossbeam::atomic::AtomicCell;
-use stream::message::{key_event, KeyEvent};
-use stream::protobuf::Message;
+use stream::message::{
+    key_event, special_key, text_input, KeyCombo, KeyEvent, KeyMacro, KeyboardMessage,
+    KeyboardSnapshot, SpecialKey, TextInput,
+};
 use stream::remote_channel::ChannelSender;
-use stream::{protobuf, ResultType};
-use winapi::ctypes::c_int;
-use winapi::shared::minwindef::{LPARAM, LRESULT, UINT, WPARAM};
-use winapi::shared::windef::HHOOK;
-use winapi::um::winuser;
+use stream::{protobuf, RemoteError, ResultType};
 
-use std::convert::TryFrom;
-use std::sync::atomic::AtomicBool;
+use std::collections::BTreeSet;
+use std::sync::{atomic::AtomicBool, Mutex};
+use std::time::Duration;
+
+use crate::{config::Config, keycode};
+
+//Windows使用低级键盘钩子，Linux使用X11键盘独占
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(windows)]
+mod windows;
+
+#[cfg(target_os = "linux")]
+use linux::run;
+#[cfg(windows)]
+use windows::run;
 
 lazy_static::lazy_static! {
     static ref RUN_STATUS:AtomicBool = AtomicBool::new(false);
     static ref SENDER_STATUS:AtomicBool = AtomicBool::new(false);
     static ref SENDER_CELL:AtomicCell<Option<ChannelSender>> =  AtomicCell::new(None);
     static ref IS_HOOK:AtomicBool = AtomicBool::new(false);
+    //文本模式，按键交给本机输入法，被控端输入提交的文本
+    static ref TEXT_MODE:AtomicBool = AtomicBool::new(false);
+    //已发送按下还没有抬起的键，发送时加锁保证快照和按键的顺序
+    static ref PRESSED:Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+    static ref SNAPSHOT_RUN:AtomicBool = AtomicBool::new(false);
 }
+//键盘快照的发送间隔
+const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(1);
+
+///鼠标移出窗口时不再捕获，已按下的键全部抬起
 pub fn hook(is_hook: bool) {
-    IS_HOOK.store(is_hook, std::sync::atomic::Ordering::SeqCst)
+    IS_HOOK.store(is_hook, std::sync::atomic::Ordering::SeqCst);
+    if !is_hook {
+        release_all();
+    }
+}
+pub fn set_text_mode(text_mode: bool) {
+    release_all();
+    TEXT_MODE.store(text_mode, std::sync::atomic::Ordering::SeqCst)
+}
+pub fn is_text_mode() -> bool {
+    TEXT_MODE.load(std::sync::atomic::Ordering::SeqCst)
 }
 pub fn is_run() -> bool {
     RUN_STATUS.load(std::sync::atomic::Ordering::SeqCst)
@@ -27,129 +58,180 @@ pub fn is_run() -> bool {
 
 pub fn start(sender: ChannelSender) -> ResultType<()> {
     SENDER_CELL.store(Some(sender));
+    PRESSED.lock().unwrap().clear();
     SENDER_STATUS.store(true, std::sync::atomic::Ordering::SeqCst);
     let run_status = RUN_STATUS.load(std::sync::atomic::Ordering::SeqCst);
 
     if !run_status {
         RUN_STATUS.store(true, std::sync::atomic::Ordering::SeqCst);
         std::thread::spawn(|| {
-            let hook = setup_hook();
-            message_loop();
-            remove_hook(hook);
+            run();
             RUN_STATUS.store(false, std::sync::atomic::Ordering::SeqCst);
         });
     }
+    if !SNAPSHOT_RUN.swap(true, std::sync::atomic::Ordering::SeqCst) {
+        std::thread::spawn(|| {
+            while SENDER_STATUS.load(std::sync::atomic::Ordering::SeqCst) {
+                std::thread::sleep(SNAPSHOT_INTERVAL);
+                send_snapshot();
+            }
+            SNAPSHOT_RUN.store(false, std::sync::atomic::Ordering::SeqCst);
+        });
+    }
     Ok(())
 }
 
-fn setup_hook() -> HHOOK {
-    unsafe {
-        let hook = winuser::SetWindowsHookExA(
-            winuser::WH_KEYBOARD_LL,
-            Some(callback),
-            std::ptr::null_mut(),
-            0,
-        );
+///抬起所有已按下的键
+pub fn release_all() {
+    let mut pressed = PRESSED.lock().unwrap();
+    for code in std::mem::take(&mut *pressed) {
+        let mut msg = KeyboardMessage::new();
+        msg.set_key_event(to_key_event(&code, key_event::Active::Up, 0));
+        send_message(msg);
+    }
+}
 
-        if hook.is_null() {
-            panic!("Windows hook null return");
-        }
+fn send_snapshot() {
+    let pressed = PRESSED.lock().unwrap();
+    let mut msg = KeyboardMessage::new();
+    msg.set_snapshot(KeyboardSnapshot {
+        pressed: pressed.iter().cloned().collect(),
+        ..Default::default()
+    });
+    send_message(msg);
+}
 
-        println!("Successfully hooked keyboard");
+#[cfg(not(any(windows, target_os = "linux")))]
+fn run() {
+    log::info!("当前平台不支持键盘捕获");
+}
+
+//是否需要捕获并发送按键，文本模式下不捕获，按键由窗口处理
+fn is_capture() -> bool {
+    IS_HOOK.load(std::sync::atomic::Ordering::SeqCst)
+        && SENDER_STATUS.load(std::sync::atomic::Ordering::SeqCst)
+        && !is_text_mode()
+}
 
-        hook
+///文本模式下窗口收到的按键，输入字符的按键由输入法处理，不发送
+pub fn send_key(vk: u32, down: bool, modifiers: u32) {
+    let code = match keycode::from_vk(vk as u16) {
+        Some(code) => code,
+        None => return,
+    };
+    let shortcut = modifiers
+        & (keycode::MODIFIER_CONTROL | keycode::MODIFIER_ALT | keycode::MODIFIER_META)
+        != 0;
+    if !shortcut && (keycode::is_printable(code) || code.starts_with("Shift") || code == "CapsLock")
+    {
+        return;
     }
+    let active = if down {
+        key_event::Active::Down
+    } else {
+        key_event::Active::Up
+    };
+    send(to_key_event(code, active, modifiers));
 }
 
-fn remove_hook(hook: HHOOK) {
-    unsafe {
-        let result = winuser::UnhookWindowsHookEx(hook);
+///输入法提交的文本
+pub fn send_text(text: String) {
+    if text.is_empty() {
+        return;
+    }
+    let _pressed = PRESSED.lock().unwrap();
+    let mut msg = KeyboardMessage::new();
+    msg.set_text_input(TextInput {
+        text,
+        composition: protobuf::ProtobufEnumOrUnknown::new(text_input::Composition::Commit),
+        ..Default::default()
+    });
+    send_message(msg);
+}
 
-        if result == 0 {
-            panic!("Windows unhook non-zero return");
-        }
+///发送特殊组合键，由被控端一次性执行
+pub fn send_special(name: special_key::Name) {
+    let _pressed = PRESSED.lock().unwrap();
+    let mut msg = KeyboardMessage::new();
+    msg.set_special_key(SpecialKey {
+        name: protobuf::ProtobufEnumOrUnknown::new(name),
+        ..Default::default()
+    });
+    send_message(msg);
+}
 
-        println!("Successfully unhooked keyboard");
+///执行配置中的按键宏
+pub fn send_macro(name: &str) -> ResultType<()> {
+    let config = match Config::get_macros().into_iter().find(|m| m.name == name) {
+        Some(config) => config,
+        None => Err(RemoteError::InvalidData(format!("按键宏不存在:{}", name)))?,
+    };
+    let mut steps = Vec::with_capacity(config.steps.len());
+    for step in &config.steps {
+        let codes = match keycode::parse_combo(step) {
+            Some(codes) => codes,
+            None => Err(RemoteError::InvalidData(format!(
+                "无法识别的组合键:{}",
+                step
+            )))?,
+        };
+        steps.push(KeyCombo {
+            codes,
+            ..Default::default()
+        });
     }
+    let _pressed = PRESSED.lock().unwrap();
+    let mut msg = KeyboardMessage::new();
+    msg.set_key_macro(KeyMacro {
+        name: config.name,
+        steps,
+        ..Default::default()
+    });
+    send_message(msg);
+    Ok(())
 }
 
-fn message_loop() {
-    // This function handles the event loop, which is necessary for the hook to function
-    let mut msg = winuser::MSG::default();
-    unsafe {
-        while 0 == winuser::GetMessageA(&mut msg, std::ptr::null_mut(), 0, 0) {
-            winuser::TranslateMessage(&msg);
-            winuser::DispatchMessageA(&msg);
-        }
+fn to_key_event(code: &str, active: key_event::Active, modifiers: u32) -> KeyEvent {
+    KeyEvent {
+        //旧版本被控端只识别虚拟键码
+        key: keycode::to_vk(code).unwrap_or_default() as u32,
+        active: protobuf::ProtobufEnumOrUnknown::from(active),
+        code: code.to_string(),
+        modifiers,
+        ..Default::default()
     }
 }
 
-#[allow(dead_code)]
-unsafe extern "system" fn callback(code: c_int, w_param: WPARAM, l_param: LPARAM) -> LRESULT {
-    let is_hook = IS_HOOK.load(std::sync::atomic::Ordering::SeqCst);
-    let is_send = SENDER_STATUS.load(std::sync::atomic::Ordering::SeqCst);
-    if is_hook && is_send && code == winuser::HC_ACTION {
-        let data = match UINT::try_from(w_param).unwrap() {
-            winuser::WM_KEYDOWN | winuser::WM_SYSKEYDOWN => {
-                let info: winuser::PKBDLLHOOKSTRUCT = std::mem::transmute(l_param);
-                let key_data = KeyEvent {
-                    key: (*info).vkCode,
-                    active: protobuf::ProtobufEnumOrUnknown::from(key_event::Active::Down),
-                    ..Default::default()
-                };
-                key_data.write_to_bytes().unwrap()
-                // match key_data.write_to_bytes() {
-                //     Ok(data) => {
-
-                //     }
-                //     Err(err) => {
-                //         log::info!("键盘数据序列化失败:{:?}", err);
-                //     }
-                // }
-
-                // println!("Keydown: {} scanCode:{},time:{}", (*info).vkCode, (*info).scanCode,(*info).time);
-            }
-
-            winuser::WM_KEYUP | winuser::WM_SYSKEYUP => {
-                let info: winuser::PKBDLLHOOKSTRUCT = std::mem::transmute(l_param);
-                let key_data = KeyEvent {
-                    key: (*info).vkCode,
-                    active: protobuf::ProtobufEnumOrUnknown::from(key_event::Active::Up),
-                    ..Default::default()
-                };
-                key_data.write_to_bytes().unwrap()
-                // match key_data.write_to_bytes() {
-                //     Ok(data) => {
-                //         if let Some(sender) = SENDER_CELL.take() {
-                //             if let Err(e) = TOKIO_RUNTIME.block_on(sender.send(data)) {
-                //                 SENDER_STATUS.store(false, std::sync::atomic::Ordering::SeqCst);
-                //                 log::info!("发送键盘事件失败:{:?}", e);
-                //             } else {
-                //                 SENDER_CELL.store(Some(sender));
-                //             }
-                //         }
-                //     }
-                //     Err(err) => {
-                //         log::info!("键盘数据序列化失败:{:?}", err);
-                //     }
-                // }
-
-                // println!("Keyup: {}", (*info).vkCode);
-            }
+fn send(event: KeyEvent) {
+    let mut pressed = PRESSED.lock().unwrap();
+    match event.active.enum_value_or_default() {
+        _ if event.code.is_empty() => {}
+        key_event::Active::Down => {
+            pressed.insert(event.code.clone());
+        }
+        _ => {
+            pressed.remove(&event.code);
+        }
+    }
+    let mut msg = KeyboardMessage::new();
+    msg.set_key_event(event);
+    send_message(msg);
+}
 
-            _ => return 1,
-        };
-        if let Some(mut sender) = SENDER_CELL.take() {
-            if let Err(e) = sender.send(data) {
-                SENDER_STATUS.store(false, std::sync::atomic::Ordering::SeqCst);
-                log::info!("发送键盘事件失败:{:?}", e);
-            } else {
-                SENDER_CELL.store(Some(sender));
-            }
+fn send_message(msg: KeyboardMessage) {
+    let data = match protobuf::Message::write_to_bytes(&msg) {
+        Ok(data) => data,
+        Err(err) => {
+            log::info!("键盘数据序列化失败:{:?}", err);
+            return;
+        }
+    };
+    if let Some(mut sender) = SENDER_CELL.take() {
+        if let Err(e) = sender.send(data) {
+            SENDER_STATUS.store(false, std::sync::atomic::Ordering::SeqCst);
+            log::info!("发送键盘事件失败:{:?}", e);
+        } else {
+            SENDER_CELL.store(Some(sender));
         }
-        return 1;
     }
-    winuser::CallNextHookEx(std::ptr::null_mut(), code, w_param, l_param);
-    0
-    //
 }
@@ -0,0 +1,119 @@
+use std::{ptr::null, time::Duration};
+
+use stream::message::key_event;
+use x11::xlib;
+
+use super::{is_capture, send, to_key_event};
+use crate::keycode;
+
+///鼠标在远程窗口内时独占键盘，X11把所有按键发给本连接
+pub fn run() {
+    let display = unsafe { xlib::XOpenDisplay(null()) };
+    if display.is_null() {
+        log::info!("无法连接X11，键盘捕获不可用");
+        return;
+    }
+    let mut grabbed = false;
+    while super::SENDER_STATUS.load(std::sync::atomic::Ordering::SeqCst) {
+        let capture = is_capture();
+        unsafe {
+            if capture && !grabbed {
+                grabbed = grab(display);
+            } else if !capture && grabbed {
+                xlib::XUngrabKeyboard(display, xlib::CurrentTime);
+                xlib::XFlush(display);
+                grabbed = false;
+            }
+            if !read_events(display, grabbed) {
+                std::thread::sleep(Duration::from_millis(10));
+            }
+        }
+    }
+    unsafe {
+        if grabbed {
+            xlib::XUngrabKeyboard(display, xlib::CurrentTime);
+        }
+        xlib::XCloseDisplay(display);
+    }
+}
+
+unsafe fn grab(display: *mut xlib::Display) -> bool {
+    let root = xlib::XDefaultRootWindow(display);
+    let rs = xlib::XGrabKeyboard(
+        display,
+        root,
+        xlib::False,
+        xlib::GrabModeAsync,
+        xlib::GrabModeAsync,
+        xlib::CurrentTime,
+    );
+    if rs != xlib::GrabSuccess {
+        //其他程序已经独占键盘，下次再试
+        log::info!("键盘独占失败:{}", rs);
+        return false;
+    }
+    xlib::XFlush(display);
+    true
+}
+
+//处理已到达的事件，没有事件时返回false
+unsafe fn read_events(display: *mut xlib::Display, grabbed: bool) -> bool {
+    if xlib::XPending(display) == 0 {
+        return false;
+    }
+    while xlib::XPending(display) > 0 {
+        let mut event: xlib::XEvent = std::mem::zeroed();
+        xlib::XNextEvent(display, &mut event);
+        let active = match event.get_type() {
+            xlib::KeyPress => key_event::Active::Down,
+            xlib::KeyRelease => key_event::Active::Up,
+            _ => continue,
+        };
+        if !grabbed {
+            continue;
+        }
+        let key = event.key;
+        match keycode::from_x11_keycode(key.keycode) {
+            Some(code) => send(to_key_event(code, active, modifiers(key.state))),
+            None => log::info!("未知的X11键码:{}", key.keycode),
+        }
+    }
+    true
+}
+
+//X11事件中的修饰键状态是按键之前的状态
+fn modifiers(state: u32) -> u32 {
+    let mut modifiers = 0;
+    let masks = [
+        (xlib::ShiftMask, keycode::MODIFIER_SHIFT),
+        (xlib::ControlMask, keycode::MODIFIER_CONTROL),
+        (xlib::Mod1Mask, keycode::MODIFIER_ALT),
+        (xlib::Mod4Mask, keycode::MODIFIER_META),
+        (xlib::LockMask, keycode::MODIFIER_CAPS_LOCK),
+        (xlib::Mod2Mask, keycode::MODIFIER_NUM_LOCK),
+    ];
+    for (mask, modifier) in masks.iter() {
+        if state & mask != 0 {
+            modifiers |= modifier;
+        }
+    }
+    modifiers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modifiers() {
+        assert_eq!(modifiers(0), 0);
+        assert_eq!(
+            modifiers(xlib::ShiftMask | xlib::ControlMask),
+            keycode::MODIFIER_SHIFT | keycode::MODIFIER_CONTROL
+        );
+        assert_eq!(
+            modifiers(xlib::Mod2Mask | xlib::Mod4Mask),
+            keycode::MODIFIER_NUM_LOCK | keycode::MODIFIER_META
+        );
+    }
+}
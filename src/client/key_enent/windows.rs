@@ -0,0 +1,106 @@
+use stream::message::key_event;
+use winapi::ctypes::c_int;
+use winapi::shared::minwindef::{LPARAM, LRESULT, UINT, WPARAM};
+use winapi::shared::windef::HHOOK;
+use winapi::um::winuser;
+
+use std::convert::TryFrom;
+
+use super::{is_capture, send, to_key_event};
+use crate::keycode;
+
+pub fn run() {
+    let hook = setup_hook();
+    message_loop();
+    remove_hook(hook);
+}
+
+fn setup_hook() -> HHOOK {
+    unsafe {
+        let hook = winuser::SetWindowsHookExA(
+            winuser::WH_KEYBOARD_LL,
+            Some(callback),
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if hook.is_null() {
+            panic!("Windows hook null return");
+        }
+
+        println!("Successfully hooked keyboard");
+
+        hook
+    }
+}
+
+fn remove_hook(hook: HHOOK) {
+    unsafe {
+        let result = winuser::UnhookWindowsHookEx(hook);
+
+        if result == 0 {
+            panic!("Windows unhook non-zero return");
+        }
+
+        println!("Successfully unhooked keyboard");
+    }
+}
+
+fn message_loop() {
+    // This function handles the event loop, which is necessary for the hook to function
+    let mut msg = winuser::MSG::default();
+    unsafe {
+        while 0 == winuser::GetMessageA(&mut msg, std::ptr::null_mut(), 0, 0) {
+            winuser::TranslateMessage(&msg);
+            winuser::DispatchMessageA(&msg);
+        }
+    }
+}
+
+unsafe fn modifiers() -> u32 {
+    let is_down = |vk: c_int| winuser::GetAsyncKeyState(vk) < 0;
+    let is_toggled = |vk: c_int| winuser::GetKeyState(vk) & 1 != 0;
+    let mut modifiers = 0;
+    if is_down(winuser::VK_SHIFT) {
+        modifiers |= keycode::MODIFIER_SHIFT;
+    }
+    if is_down(winuser::VK_CONTROL) {
+        modifiers |= keycode::MODIFIER_CONTROL;
+    }
+    if is_down(winuser::VK_MENU) {
+        modifiers |= keycode::MODIFIER_ALT;
+    }
+    if is_down(winuser::VK_LWIN) || is_down(winuser::VK_RWIN) {
+        modifiers |= keycode::MODIFIER_META;
+    }
+    if is_toggled(winuser::VK_CAPITAL) {
+        modifiers |= keycode::MODIFIER_CAPS_LOCK;
+    }
+    if is_toggled(winuser::VK_NUMLOCK) {
+        modifiers |= keycode::MODIFIER_NUM_LOCK;
+    }
+    modifiers
+}
+
+#[allow(dead_code)]
+unsafe extern "system" fn callback(code: c_int, w_param: WPARAM, l_param: LPARAM) -> LRESULT {
+    if is_capture() && code == winuser::HC_ACTION {
+        let active = match UINT::try_from(w_param).unwrap() {
+            winuser::WM_KEYDOWN | winuser::WM_SYSKEYDOWN => key_event::Active::Down,
+            winuser::WM_KEYUP | winuser::WM_SYSKEYUP => key_event::Active::Up,
+            _ => return 1,
+        };
+        let info: winuser::PKBDLLHOOKSTRUCT = std::mem::transmute(l_param);
+        let vk = (*info).vkCode;
+        let mut event = to_key_event(
+            keycode::from_vk(vk as u16).unwrap_or_default(),
+            active,
+            modifiers(),
+        );
+        event.key = vk;
+        send(event);
+        return 1;
+    }
+    winuser::CallNextHookEx(std::ptr::null_mut(), code, w_param, l_param);
+    0
+}
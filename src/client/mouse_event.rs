@@ -1,17 +1,252 @@
-use crossbeam::atomic::AtomicCell;
-use stream::{remote_channel::ChannelSender, ResultType};
+use std::{
+    sync::{atomic::AtomicU64, Condvar, Mutex},
+    time::Duration,
+};
+
+use stream::{
+    message::{mouse_event, InputBatch, MouseEvent},
+    protobuf::{Message, ProtobufEnumOrUnknown},
+    remote_channel::ChannelSender,
+    ResultType,
+};
 
 lazy_static::lazy_static! {
-    static ref SENDER_CELL:AtomicCell<Option<ChannelSender>> =  AtomicCell::new(None);
+    //待发送的事件，连续的移动合并为一个
+    static ref QUEUE:(Mutex<Vec<MouseEvent>>, Condvar) = (Mutex::new(Vec::new()), Condvar::new());
+    //每次建立连接加一，旧的发送线程据此退出
+    static ref GENERATION:AtomicU64 = AtomicU64::new(0);
+}
+//移动事件的发送间隔，按键和滚动立即发送
+const FLUSH_INTERVAL: Duration = Duration::from_millis(16);
+
+pub fn load(mut sender: ChannelSender) {
+    let generation = GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+    QUEUE.0.lock().unwrap().clear();
+    //发送可能阻塞，放在单独的线程，避免阻塞界面线程
+    std::thread::spawn(move || {
+        let (lock, cvar) = &*QUEUE;
+        while GENERATION.load(std::sync::atomic::Ordering::SeqCst) == generation {
+            let events = {
+                let mut events = lock.lock().unwrap();
+                if events.is_empty() || !has_urgent(&events) {
+                    events = cvar.wait_timeout(events, FLUSH_INTERVAL).unwrap().0;
+                }
+                std::mem::take(&mut *events)
+            };
+            if events.is_empty() {
+                continue;
+            }
+            let rs = send_batch(&mut sender, events);
+            if let Err(e) = rs {
+                log::info!("发送鼠标事件失败:{:?}", e);
+                break;
+            }
+        }
+    });
+}
+
+fn send_batch(sender: &mut ChannelSender, events: Vec<MouseEvent>) -> ResultType<()> {
+    let batch = InputBatch {
+        events,
+        ..Default::default()
+    };
+    sender.send(batch.write_to_bytes()?)
 }
 
-pub fn load(sender: ChannelSender) {
-    SENDER_CELL.store(Some(sender));
+fn is_move(active: mouse_event::Active) -> bool {
+    active == mouse_event::Active::Move || active == mouse_event::Active::MoveRelative
 }
-pub fn send(data: Vec<u8>) -> ResultType<()> {
-    if let Some(mut sender) = SENDER_CELL.take() {
-        sender.send(data)?;
-        SENDER_CELL.store(Some(sender));
+
+fn has_urgent(events: &[MouseEvent]) -> bool {
+    events
+        .iter()
+        .any(|event| !is_move(event.active.enum_value_or_default()))
+}
+
+//和队列最后一个同类事件合并，按键事件不合并，保证顺序
+fn coalesce(events: &mut Vec<MouseEvent>, event: MouseEvent) {
+    if let Some(last) = events.last_mut() {
+        let active = event.active.enum_value_or_default();
+        if last.active.enum_value_or_default() == active {
+            match active {
+                mouse_event::Active::Move if last.display_id == event.display_id => {
+                    *last = event;
+                    return;
+                }
+                mouse_event::Active::MoveRelative => {
+                    last.move_x += event.move_x;
+                    last.move_y += event.move_y;
+                    return;
+                }
+                mouse_event::Active::ScrollX | mouse_event::Active::ScrollY => {
+                    last.wheel_delta += event.wheel_delta;
+                    last.scroll_len += event.scroll_len;
+                    return;
+                }
+                _ => {}
+            }
+        }
+    }
+    events.push(event);
+}
+
+fn send_event(active: mouse_event::Active, event: MouseEvent) -> ResultType<()> {
+    let event = MouseEvent {
+        active: ProtobufEnumOrUnknown::new(active),
+        ..event
+    };
+    let (lock, cvar) = &*QUEUE;
+    coalesce(&mut lock.lock().unwrap(), event);
+    if !is_move(active) {
+        cvar.notify_one();
     }
     Ok(())
 }
+
+///按键按下或抬起，key取值见MouseEvent.key
+pub fn button(key: u32, down: bool) -> ResultType<()> {
+    let active = if down {
+        mouse_event::Active::Down
+    } else {
+        mouse_event::Active::Up
+    };
+    send_event(
+        active,
+        MouseEvent {
+            key,
+            ..Default::default()
+        },
+    )
+}
+///画面在视图中按比例居中显示时所占的区域(x, y, width, height)
+pub fn fit(view: (i32, i32), frame: (u32, u32)) -> Option<(f64, f64, f64, f64)> {
+    let (view_w, view_h) = (view.0 as f64, view.1 as f64);
+    let (frame_w, frame_h) = (frame.0 as f64, frame.1 as f64);
+    if view_w <= 0.0 || view_h <= 0.0 || frame_w <= 0.0 || frame_h <= 0.0 {
+        return None;
+    }
+    let scale = (view_w / frame_w).min(view_h / frame_h);
+    let (width, height) = (frame_w * scale, frame_h * scale);
+    Some((
+        (view_w - width) / 2.0,
+        (view_h - height) / 2.0,
+        width,
+        height,
+    ))
+}
+
+///视图坐标换算成画面上的归一化坐标，落在黑边上时返回None
+pub fn normalize(x: i32, y: i32, view: (i32, i32), frame: (u32, u32)) -> Option<(f32, f32)> {
+    let (left, top, width, height) = fit(view, frame)?;
+    let norm_x = (x as f64 - left) / width;
+    let norm_y = (y as f64 - top) / height;
+    if !(0.0..=1.0).contains(&norm_x) || !(0.0..=1.0).contains(&norm_y) {
+        return None;
+    }
+    Some((norm_x as f32, norm_y as f32))
+}
+
+///移动到画面上的归一化坐标，frame为画面大小，供旧版本被控端使用
+pub fn move_to(norm_x: f32, norm_y: f32, display_id: i32, frame: (u32, u32)) -> ResultType<()> {
+    send_event(
+        mouse_event::Active::Move,
+        MouseEvent {
+            move_x: (norm_x * frame.0 as f32) as i32,
+            move_y: (norm_y * frame.1 as f32) as i32,
+            normalized: true,
+            norm_x,
+            norm_y,
+            display_id,
+            ..Default::default()
+        },
+    )
+}
+pub fn move_relative(dx: i32, dy: i32) -> ResultType<()> {
+    send_event(
+        mouse_event::Active::MoveRelative,
+        MouseEvent {
+            move_x: dx,
+            move_y: dy,
+            ..Default::default()
+        },
+    )
+}
+///高精度滚动，120为一格，正数向下、向右
+pub fn scroll(delta_x: i32, delta_y: i32) -> ResultType<()> {
+    let to_event = |delta: i32| MouseEvent {
+        //旧版本被控端只认滚动格数
+        scroll_len: delta / 120,
+        wheel_delta: delta,
+        ..Default::default()
+    };
+    if delta_y != 0 {
+        send_event(mouse_event::Active::ScrollY, to_event(delta_y))?;
+    }
+    if delta_x != 0 {
+        send_event(mouse_event::Active::ScrollX, to_event(delta_x))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(active: mouse_event::Active, x: i32, delta: i32) -> MouseEvent {
+        MouseEvent {
+            active: ProtobufEnumOrUnknown::new(active),
+            move_x: x,
+            wheel_delta: delta,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_coalesce() {
+        let mut events = Vec::new();
+        coalesce(&mut events, event(mouse_event::Active::Move, 1, 0));
+        coalesce(&mut events, event(mouse_event::Active::Move, 2, 0));
+        coalesce(&mut events, event(mouse_event::Active::Down, 0, 0));
+        coalesce(&mut events, event(mouse_event::Active::Up, 0, 0));
+        coalesce(&mut events, event(mouse_event::Active::Down, 0, 0));
+        coalesce(&mut events, event(mouse_event::Active::MoveRelative, 3, 0));
+        coalesce(&mut events, event(mouse_event::Active::MoveRelative, 4, 0));
+        coalesce(&mut events, event(mouse_event::Active::ScrollY, 0, 60));
+        coalesce(&mut events, event(mouse_event::Active::ScrollY, 0, 60));
+        let summary: Vec<(mouse_event::Active, i32, i32)> = events
+            .iter()
+            .map(|e| (e.active.enum_value_or_default(), e.move_x, e.wheel_delta))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (mouse_event::Active::Move, 2, 0),
+                (mouse_event::Active::Down, 0, 0),
+                (mouse_event::Active::Up, 0, 0),
+                (mouse_event::Active::Down, 0, 0),
+                (mouse_event::Active::MoveRelative, 7, 0),
+                (mouse_event::Active::ScrollY, 0, 120),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        //16:9的画面显示在4:3的窗口中，上下有黑边
+        assert_eq!(
+            fit((800, 600), (1920, 1080)),
+            Some((0.0, 75.0, 800.0, 450.0))
+        );
+        assert_eq!(normalize(0, 75, (800, 600), (1920, 1080)), Some((0.0, 0.0)));
+        assert_eq!(
+            normalize(400, 300, (800, 600), (1920, 1080)),
+            Some((0.5, 0.5))
+        );
+        assert_eq!(
+            normalize(800, 525, (800, 600), (1920, 1080)),
+            Some((1.0, 1.0))
+        );
+        assert_eq!(normalize(400, 20, (800, 600), (1920, 1080)), None);
+        assert_eq!(normalize(400, 300, (800, 600), (0, 0)), None);
+    }
+}
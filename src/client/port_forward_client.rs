@@ -0,0 +1,146 @@
+//! 主控端的端口转发：本地转发在本机监听，远程转发由被控端监听后把连接转发过来
+
+use std::{
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+};
+
+use stream::{
+    message::{port_forward_message, ChannelType, ForwardListen, ForwardOpen, PortForwardMessage},
+    remote_channel::{ChannelOpener, ChannelReceiver, ChannelSender},
+    RemoteError, ResultType,
+};
+
+use crate::port_forward::{self, Kind, Tunnel, TunnelInfo};
+
+lazy_static::lazy_static! {
+    static ref OPENER:Mutex<Option<ChannelOpener>> = Mutex::new(None);
+}
+
+fn opener() -> ResultType<ChannelOpener> {
+    match OPENER.lock().unwrap().clone() {
+        Some(opener) => Ok(opener),
+        None => Err(RemoteError::Disconnection)?,
+    }
+}
+
+pub fn start(opener: ChannelOpener) {
+    port_forward::close_all();
+    *OPENER.lock().unwrap() = Some(opener);
+}
+pub fn stop() {
+    OPENER.lock().unwrap().take();
+    port_forward::close_all();
+}
+
+///本地转发，本机监听local_port，连接由被控端转发到host:port
+pub fn add_local(local_port: u16, host: &str, port: u32) -> ResultType<u32> {
+    opener()?;
+    let listener = TcpListener::bind(("127.0.0.1", local_port))?;
+    let tunnel = Tunnel::new(
+        Kind::Local,
+        listener.local_addr()?.to_string(),
+        format!("{}:{}", host, port),
+    );
+    port_forward::register(tunnel.clone());
+    let id = tunnel.id;
+    let open = ForwardOpen {
+        host: host.to_string(),
+        port,
+        ..Default::default()
+    };
+    std::thread::spawn(move || {
+        let accept_tunnel = tunnel.clone();
+        let rs = port_forward::accept_loop(listener, accept_tunnel, move |stream| {
+            if let Err(e) = forward(stream, &open, tunnel.clone()) {
+                log::info!("本地转发连接结束:{:?}", e);
+            }
+        });
+        log::info!("本地转发结束:{:?}", rs);
+        port_forward::close(id);
+    });
+    Ok(id)
+}
+
+fn forward(stream: TcpStream, open: &ForwardOpen, tunnel: Arc<Tunnel>) -> ResultType<()> {
+    let opener = opener()?;
+    let (mut sender, receiver) = opener.create_channel(ChannelType::PortForward)?;
+    let channel_id = sender.channel_id();
+    let mut msg = PortForwardMessage::new();
+    msg.set_open(open.clone());
+    let rs = port_forward::send(&mut sender, msg)
+        .and_then(|_| port_forward::wait_result(&receiver))
+        .and_then(|_| port_forward::pump(stream, sender, receiver, tunnel));
+    let _ = opener.destroy_channel(channel_id);
+    rs
+}
+
+///远程转发，被控端监听remote_port，连接由本机转发到host:port
+pub fn add_remote(remote_port: u32, host: &str, port: u32) -> ResultType<u32> {
+    let opener = opener()?;
+    let (mut sender, receiver) = opener.create_channel(ChannelType::PortForward)?;
+    let channel_id = sender.channel_id();
+    let mut msg = PortForwardMessage::new();
+    msg.set_listen(ForwardListen {
+        port: remote_port,
+        host: host.to_string(),
+        target_port: port,
+        ..Default::default()
+    });
+    if let Err(e) =
+        port_forward::send(&mut sender, msg).and_then(|_| port_forward::wait_result(&receiver))
+    {
+        let _ = opener.destroy_channel(channel_id);
+        return Err(e);
+    }
+    let tunnel = Tunnel::new(
+        Kind::Remote,
+        format!("{}", remote_port),
+        format!("{}:{}", host, port),
+    );
+    port_forward::register(tunnel.clone());
+    let id = tunnel.id;
+    //隧道关闭后销毁控制通道，被控端随之停止监听
+    std::thread::spawn(move || {
+        while !tunnel.is_closed() && opener.is_run() {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+        }
+        let _ = opener.destroy_channel(channel_id);
+        drop(receiver);
+    });
+    Ok(id)
+}
+
+///被控端转发过来的远程转发连接，只连接自己请求过的目标
+pub fn on_channel(mut sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+    let open = match port_forward::recv(&receiver)? {
+        port_forward_message::Union::open(open) => open,
+        _ => Err(RemoteError::InvalidData(String::from(
+            "端口转发通道的第一个消息不正确",
+        )))?,
+    };
+    let target = format!("{}:{}", open.host, open.port);
+    let tunnel = port_forward::tunnels()
+        .into_iter()
+        .find(|t| t.kind == Kind::Remote && t.target == target)
+        .and_then(|t| port_forward::find(t.id));
+    let tunnel = match tunnel {
+        Some(tunnel) => tunnel,
+        None => {
+            return port_forward::send_result(&mut sender, Err(format!("未请求转发到:{}", target)))
+        }
+    };
+    let stream = match port_forward::connect(&open.host, open.port) {
+        Ok(stream) => stream,
+        Err(e) => return port_forward::send_result(&mut sender, Err(e)),
+    };
+    port_forward::send_result(&mut sender, Ok(()))?;
+    port_forward::pump(stream, sender, receiver, tunnel)
+}
+
+pub fn remove(id: u32) {
+    port_forward::close(id);
+}
+pub fn tunnels() -> Vec<TunnelInfo> {
+    port_forward::tunnels()
+}
@@ -0,0 +1,23 @@
+//! 地址簿中设备的在线状态，由ID服务器的查询结果更新，
+//! 对方没有允许被查询时服务器不返回，同样按离线显示
+
+use std::{collections::HashSet, sync::RwLock};
+
+lazy_static::lazy_static! {
+    //None为还没有查询结果
+    static ref ONLINE:RwLock<Option<HashSet<String>>> = RwLock::new(None);
+}
+
+///IdClient的online_callback
+pub fn update(online: Vec<String>) {
+    *ONLINE.write().unwrap() = Some(online.into_iter().collect());
+}
+
+///还没有查询结果时返回None
+pub fn is_online(peer_id: &str) -> Option<bool> {
+    ONLINE
+        .read()
+        .unwrap()
+        .as_ref()
+        .map(|online| online.contains(peer_id))
+}
@@ -1,14 +1,34 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
 use sciter::video::{video_destination, AssetPtr};
 use stream::{
     message::ChannelType,
+    permission::Permissions,
     relay::{LoginResponseEnum, RelayClient},
-    remote_channel::{ChannelManager, ChannelReceiver, ChannelSender},
+    remote_channel::{ChannelManager, ChannelOpener, ChannelReceiver, ChannelSender},
+    utils::fingerprint,
     ResultType,
 };
 
-use crate::ui::remote::{RemoteEventHandler, RemoteWindow};
+use crate::{
+    config::Config,
+    ui::remote::{RemoteEventHandler, RemoteWindow},
+};
+
+use super::{
+    audio_client, chat_client, clipboard_client, file_browser_client, file_transfer_client,
+    input_status, key_enent, mouse_event, port_forward_client, terminal_client, video_client,
+};
+
+lazy_static::lazy_static! {
+    //被控端授予本次会话的权限
+    static ref PERMISSIONS:AtomicU32 = AtomicU32::new(Permissions::FULL.bits());
+}
 
-use super::{key_enent, mouse_event, video_client};
+///本次会话的权限，界面据此隐藏没有权限的功能
+pub fn permissions() -> Permissions {
+    Permissions::from_bits(PERMISSIONS.load(Ordering::SeqCst))
+}
 
 pub fn control_client_callback(
     peer_id: String,
@@ -56,7 +76,19 @@ where
     F: Fn(String, String, LoginResponseEnum) -> Option<Vec<u8>> + 'static,
 {
     log::info!("客户端通道");
-    let framed = RelayClient::start(
+    //和地址簿中记录的指纹不一致时可能是冒充的设备，不发送密码
+    let peer_fingerprint = fingerprint(&peer_pub_key);
+    Config::check_peer_fingerprint(&peer_id, &peer_fingerprint)?;
+    //地址簿中保存了密码时第一次不询问，密码错误后再询问
+    let saved_password = Config::get_peer_password(&peer_id);
+    let password_callback = move |peer_id: String, check_info: String, rs: LoginResponseEnum| {
+        if let (LoginResponseEnum::First, Some(password)) = (rs, &saved_password) {
+            return Some(password.clone().into_bytes());
+        }
+        password_callback(peer_id, check_info, rs)
+    };
+    let record_peer = peer_id.clone();
+    let (framed, permissions) = RelayClient::start(
         my_id,
         password_callback,
         relay_addr,
@@ -65,29 +97,108 @@ where
         peer_pub_key,
         relay_pub_key,
     )?;
+    Config::record_connection(&record_peer, &peer_fingerprint);
+    PERMISSIONS.store(permissions.bits(), Ordering::SeqCst);
+    //被控端只会为远程转发的连接建立通道
     let mut channel_manager = ChannelManager::new_relay(
         true,
         framed,
+        record_peer,
+        permissions & Permissions::PORT_FORWARD,
         create_channel_callback,
         destroy_channel_callback,
     )?;
 
-    log::info!("转发连接建立成功");
-    //视频通道
-    let video = channel_manager.create_channel_read(ChannelType::Video)?;
-    video_client::start(&mut site, video)?;
-    let key_channel = channel_manager.create_channel_write(ChannelType::KeyEvent)?;
-    key_enent::start(key_channel)?;
-    let mouse_channel = channel_manager.create_channel_write(ChannelType::MouseEvent)?;
-    mouse_event::load(mouse_channel);
+    log::info!("转发连接建立成功,权限:{}", permissions.describe());
+    //没有权限的通道不建立，被控端也会拒绝
+    //键盘、鼠标通道读写，被控端通过它返回输入注入的状态
+    input_status::clear();
+    if permissions.contains(Permissions::INPUT) {
+        let (key_channel, key_status) = channel_manager.create_channel(ChannelType::KeyEvent)?;
+        key_enent::start(key_channel)?;
+        input_status::watch(key_status);
+        let (mouse_channel, mouse_status) =
+            channel_manager.create_channel(ChannelType::MouseEvent)?;
+        mouse_event::load(mouse_channel);
+        input_status::watch(mouse_status);
+    }
+    //剪贴板通道，双向同步
+    if permissions.contains(Permissions::CLIPBOARD) {
+        let (clipboard_sender, clipboard) =
+            channel_manager.create_channel(ChannelType::Clipboard)?;
+        std::thread::spawn(move || {
+            let rs = clipboard_client::start(clipboard_sender, clipboard);
+            log::info!("剪贴板通道关闭:{:?}", rs);
+        });
+    }
+    if permissions.contains(Permissions::FILE) {
+        //文件传输通道，双向发送文件
+        let (file_sender, file) = channel_manager.create_channel(ChannelType::FileTransfer)?;
+        std::thread::spawn(move || {
+            let rs = file_transfer_client::start(file_sender, file);
+            log::info!("文件传输通道关闭:{:?}", rs);
+        });
+        //文件浏览通道，请求和回复
+        let (browser_sender, browser) = channel_manager.create_channel(ChannelType::FileBrowser)?;
+        std::thread::spawn(move || {
+            let rs = file_browser_client::start(browser_sender, browser);
+            log::info!("文件浏览通道关闭:{:?}", rs);
+        });
+    }
+    //终端通道，打开终端时被控端才启动shell
+    if permissions.contains(Permissions::TERMINAL) {
+        let (terminal_sender, terminal) = channel_manager.create_channel(ChannelType::Terminal)?;
+        std::thread::spawn(move || {
+            let rs = terminal_client::start(terminal_sender, terminal);
+            log::info!("终端通道关闭:{:?}", rs);
+        });
+    }
+    //端口转发，每个连接一个通道
+    if permissions.contains(Permissions::PORT_FORWARD) {
+        port_forward_client::start(channel_manager.opener());
+    }
+    //聊天通道
+    if permissions.contains(Permissions::CHAT) {
+        let (chat_sender, chat) = channel_manager.create_channel(ChannelType::Chat)?;
+        std::thread::spawn(move || {
+            let rs = chat_client::start(chat_sender, chat);
+            log::info!("聊天通道关闭:{:?}", rs);
+        });
+    }
+    //音频通道，主控端可以开关
+    if permissions.contains(Permissions::AUDIO) {
+        let (audio_sender, audio) = channel_manager.create_channel(ChannelType::Audio)?;
+        std::thread::spawn(move || {
+            let rs = audio_client::start(audio_sender, audio);
+            log::info!("音频通道关闭:{:?}", rs);
+        });
+    }
+    //视频通道，读写用于切换显示器
+    if permissions.contains(Permissions::VIEW) {
+        let (video_sender, video) = channel_manager.create_channel(ChannelType::Video)?;
+        std::thread::spawn(move || {
+            let rs = video_client::start(&mut site, video_sender, video);
+            log::info!("视频通道关闭:{:?}", rs);
+        });
+    }
     Ok(channel_manager)
 }
 pub fn create_channel_callback(
     sender: Option<ChannelSender>,
-    _receiver: Option<ChannelReceiver>,
+    receiver: Option<ChannelReceiver>,
     channel_type: ChannelType,
+    _opener: ChannelOpener,
 ) {
     log::info!("创建客户端通道：{:?},sender:{:?}", channel_type, sender);
+    //被控端只会为远程转发的连接建立通道
+    if let (ChannelType::PortForward, Some(sender), Some(receiver)) =
+        (channel_type, sender, receiver)
+    {
+        std::thread::spawn(move || {
+            let rs = port_forward_client::on_channel(sender, receiver);
+            log::info!("远程转发连接结束:{:?}", rs);
+        });
+    }
 }
 pub fn destroy_channel_callback(channel_id: u32, channel_type: ChannelType) {
     log::info!("销毁客户端通道：{:?},id:{}", channel_type, channel_id)
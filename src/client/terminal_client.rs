@@ -0,0 +1,144 @@
+//! 远程终端，界面定时取走输出，去掉控制序列后按纯文本显示
+
+use std::sync::Mutex;
+
+use stream::{
+    message::{terminal_message, TerminalMessage, TerminalSize},
+    protobuf::Message,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    RemoteError, ResultType,
+};
+
+lazy_static::lazy_static! {
+    static ref SENDER:Mutex<Option<ChannelSender>> = Mutex::new(None);
+    //未取走的输出，末尾可能是不完整的UTF-8字符
+    static ref OUTPUT:Mutex<Vec<u8>> = Mutex::new(Vec::new());
+    //shell退出码和错误，None为运行中或未打开
+    static ref EXIT:Mutex<Option<(i32, String)>> = Mutex::new(None);
+}
+
+pub fn start(sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+    *SENDER.lock().unwrap() = Some(sender);
+    let rs = receive(receiver);
+    SENDER.lock().unwrap().take();
+    rs
+}
+fn receive(receiver: ChannelReceiver) -> ResultType<()> {
+    while let Ok(data) = receiver.recv() {
+        let msg = TerminalMessage::parse_from_bytes(&data)?;
+        match msg.union {
+            Some(terminal_message::Union::output(output)) => {
+                OUTPUT.lock().unwrap().extend_from_slice(&output);
+            }
+            Some(terminal_message::Union::exit(exit)) => {
+                log::info!("终端退出:{},{}", exit.code, exit.error);
+                *EXIT.lock().unwrap() = Some((exit.code, exit.error));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn send(msg: TerminalMessage) -> ResultType<()> {
+    let mut sender = match SENDER.lock().unwrap().clone() {
+        Some(sender) => sender,
+        None => Err(RemoteError::Channel(String::from("终端通道未建立")))?,
+    };
+    sender.send(msg.write_to_bytes()?)
+}
+
+///打开终端，上一个shell退出后可以再次打开
+pub fn open(rows: u32, cols: u32) -> ResultType<()> {
+    EXIT.lock().unwrap().take();
+    OUTPUT.lock().unwrap().clear();
+    let mut msg = TerminalMessage::new();
+    msg.set_open(TerminalSize {
+        rows,
+        cols,
+        ..Default::default()
+    });
+    send(msg)
+}
+pub fn input(data: &[u8]) -> ResultType<()> {
+    let mut msg = TerminalMessage::new();
+    msg.set_input(data.to_vec());
+    send(msg)
+}
+pub fn resize(rows: u32, cols: u32) -> ResultType<()> {
+    let mut msg = TerminalMessage::new();
+    msg.set_resize(TerminalSize {
+        rows,
+        cols,
+        ..Default::default()
+    });
+    send(msg)
+}
+pub fn exit_status() -> Option<(i32, String)> {
+    EXIT.lock().unwrap().clone()
+}
+///取走已收到的输出，不完整的UTF-8字符留到下次
+pub fn take_output() -> String {
+    let mut output = OUTPUT.lock().unwrap();
+    let valid = match std::str::from_utf8(&output) {
+        Ok(_) => output.len(),
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        Err(_) => output.len(),
+    };
+    let text = String::from_utf8_lossy(&output[..valid]).to_string();
+    output.drain(..valid);
+    strip_control(&text)
+}
+
+///去掉ANSI控制序列和回车，退格删除前一个字符
+pub fn strip_control(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => match chars.next() {
+                //CSI，以0x40~0x7e结束
+                Some('[') => {
+                    for c in chars.by_ref() {
+                        if ('\x40'..='\x7e').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                //OSC，以BEL或ESC \结束
+                Some(']') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' {
+                            break;
+                        }
+                        if c == '\x1b' {
+                            chars.next();
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            },
+            '\x08' => {
+                result.pop();
+            }
+            '\n' | '\t' => result.push(c),
+            c if c.is_control() => {}
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_control() {
+        assert_eq!(strip_control("ls\r\n"), "ls\n");
+        assert_eq!(strip_control("\x1b[1;32muser\x1b[0m$ "), "user$ ");
+        assert_eq!(strip_control("\x1b]0;title\x07中文"), "中文");
+        assert_eq!(strip_control("ab\x08c"), "ac");
+    }
+}
@@ -1,28 +1,88 @@
-use std::{ptr::null_mut, sync::atomic::AtomicU64, time::Instant};
+use std::{
+    ptr::null_mut,
+    sync::{atomic::AtomicU64, RwLock},
+    time::Instant,
+};
 
+use crossbeam::atomic::AtomicCell;
 use openh264_sys::{
     ISVCDecoderVtbl, SBufferInfo, SDecodingParam, WelsCreateDecoder, WelsDestroyDecoder,
 };
 use sciter::video::{video_destination, AssetPtr, COLOR_SPACE};
-use stream::{remote_channel::ChannelReceiver, ResultType};
+use stream::{
+    message::{video_message, DisplayList, SetResolution, SwitchDisplay, VideoMessage},
+    protobuf::Message,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    ResultType,
+};
 
 lazy_static::lazy_static! {
     static ref FPS:AtomicU64 = AtomicU64::new(0);
+    static ref DISPLAYS:RwLock<DisplayList> = RwLock::new(DisplayList::new());
+    static ref SENDER_CELL:AtomicCell<Option<ChannelSender>> =  AtomicCell::new(None);
+    static ref FRAME_SIZE:AtomicCell<(u32, u32)> = AtomicCell::new((0, 0));
+    //最近一帧的被控端时间戳和显示的时间，音频据此同步
+    static ref VIDEO_CLOCK:AtomicCell<Option<(u64, Instant)>> = AtomicCell::new(None);
 }
 
 pub  fn start(
     site: &mut AssetPtr<video_destination>,
+    sender: ChannelSender,
     receiver: ChannelReceiver,
 ) -> ResultType<String> {
+    SENDER_CELL.store(Some(sender));
     let decoder = null_mut();
     let rs = start_( site, receiver, decoder);
     unsafe {
         WelsDestroyDecoder(decoder);
     }
+    SENDER_CELL.take();
+    VIDEO_CLOCK.store(None);
     rs
 }
 pub fn fps() -> u64 {
     FPS.load(std::sync::atomic::Ordering::SeqCst)
+}
+///当前画面大小，鼠标坐标据此换算
+pub fn frame_size() -> (u32, u32) {
+    FRAME_SIZE.load()
+}
+///当前显示的画面对应的被控端时间，没有画面时为None
+pub fn video_clock() -> Option<u64> {
+    VIDEO_CLOCK
+        .load()
+        .map(|(timestamp, shown)| timestamp + shown.elapsed().as_millis() as u64)
+}
+///被控端的显示器列表
+pub fn displays() -> DisplayList {
+    DISPLAYS.read().unwrap().clone()
+}
+///切换显示器，id为-1时显示所有显示器
+pub fn switch_display(id: i32) -> ResultType<()> {
+    let mut msg = VideoMessage::new();
+    msg.set_switch_display(SwitchDisplay {
+        id,
+        ..Default::default()
+    });
+    send(msg)
+}
+///请求被控端把画面缩小到不超过该分辨率，0表示原始分辨率
+pub fn set_resolution(width: u32, height: u32) -> ResultType<()> {
+    let mut msg = VideoMessage::new();
+    msg.set_set_resolution(SetResolution {
+        width,
+        height,
+        ..Default::default()
+    });
+    send(msg)
+}
+fn send(msg: VideoMessage) -> ResultType<()> {
+    if let Some(mut sender) = SENDER_CELL.take() {
+        let rs = sender.send(msg.write_to_bytes()?);
+        SENDER_CELL.store(Some(sender));
+        rs?;
+    }
+    Ok(())
 }
  fn start_(
     site: &mut AssetPtr<video_destination>,
@@ -54,6 +114,20 @@ pub fn fps() -> u64 {
                 } else {
                     return Ok(String::from("视频数据接收失败"));
                 };
+                let msg = if let Ok(msg) = VideoMessage::parse_from_bytes(&data) {
+                    msg
+                } else {
+                    return Ok(String::from("视频数据解析失败"));
+                };
+                let (data, timestamp) = match msg.union {
+                    Some(video_message::Union::video_frame(frame)) => (frame.data, frame.timestamp),
+                    Some(video_message::Union::display_list(list)) => {
+                        log::info!("显示器列表:{:?}", list);
+                        *DISPLAYS.write().unwrap() = list;
+                        continue;
+                    }
+                    _ => continue,
+                };
                 let dd = decoder_fn(
                     decoder,
                     data.as_ptr(),
@@ -70,6 +144,7 @@ pub fn fps() -> u64 {
                 if height != info.iHeight || width != info.iStride[0] {
                     height = info.iHeight;
                     width = info.iStride[0];
+                    FRAME_SIZE.store((width as u32, height as u32));
                     if let Err(_) =
                         site.start_streaming((width as i32, height as i32), COLOR_SPACE::Iyuv, None)
                     {
@@ -95,6 +170,7 @@ pub fn fps() -> u64 {
                 if let Err(_) = site.render_frame(&add) {
                     return Ok(String::from("视频数据播放失败"));
                 }
+                VIDEO_CLOCK.store(Some((timestamp, Instant::now())));
                 FPS.store(
                     1000 / (now.elapsed().as_millis() as u64),
                     std::sync::atomic::Ordering::SeqCst,
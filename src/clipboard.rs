@@ -0,0 +1,316 @@
+//! 剪贴板同步，主控端和被控端使用同一套逻辑。
+//! 本机剪贴板变化时发送给对方，收到对方的内容时写入本机剪贴板，
+//! 双方都记录最后一次同步内容的哈希，避免来回发送同一份内容
+
+use std::{
+    borrow::Cow,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use arboard::{Clipboard, ImageData};
+use stream::{
+    message::{clipboard_message, ClipboardData, ClipboardMessage},
+    protobuf::Message,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    sha2::{Digest, Sha256},
+    ResultType,
+};
+
+///单次同步的内容上限
+pub const MAX_SIZE: usize = 16 * 1024 * 1024;
+//检查本机剪贴板的间隔
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Content {
+    pub text: Option<String>,
+    pub html: Option<String>,
+    pub image: Option<Image>,
+}
+
+///RGBA像素
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    pub bytes: Vec<u8>,
+}
+
+impl Content {
+    pub fn is_empty(&self) -> bool {
+        self.text.is_none() && self.html.is_none() && self.image.is_none()
+    }
+    pub fn size(&self) -> usize {
+        self.text.as_ref().map_or(0, |text| text.len())
+            + self.html.as_ref().map_or(0, |html| html.len())
+            + self.image.as_ref().map_or(0, |image| image.bytes.len())
+    }
+    pub fn hash(&self) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        for part in [&self.text, &self.html].iter() {
+            match part {
+                Some(part) => {
+                    hasher.update([1u8]);
+                    hasher.update((part.len() as u64).to_le_bytes());
+                    hasher.update(part.as_bytes());
+                }
+                None => hasher.update([0u8]),
+            }
+        }
+        if let Some(image) = &self.image {
+            hasher.update(image.width.to_le_bytes());
+            hasher.update(image.height.to_le_bytes());
+            hasher.update(&image.bytes);
+        }
+        hasher.finalize()[..].into()
+    }
+    ///超过上限时依次去掉图片、HTML，只剩文本仍超过时返回None
+    pub fn limit(mut self, max: usize) -> Option<Content> {
+        if self.size() > max {
+            self.image = None;
+        }
+        if self.size() > max {
+            self.html = None;
+        }
+        if self.size() > max || self.is_empty() {
+            return None;
+        }
+        Some(self)
+    }
+    pub fn to_data(&self) -> ClipboardData {
+        let mut data = ClipboardData {
+            text: self.text.clone().unwrap_or_default(),
+            html: self.html.clone().unwrap_or_default(),
+            ..Default::default()
+        };
+        if let Some(image) = &self.image {
+            data.image = image.bytes.clone();
+            data.image_width = image.width;
+            data.image_height = image.height;
+        }
+        data
+    }
+    pub fn from_data(data: ClipboardData) -> Content {
+        let non_empty = |s: String| if s.is_empty() { None } else { Some(s) };
+        let image = if data.image.is_empty()
+            || data.image.len() != data.image_width as usize * data.image_height as usize * 4
+        {
+            None
+        } else {
+            Some(Image {
+                width: data.image_width,
+                height: data.image_height,
+                bytes: data.image,
+            })
+        };
+        Content {
+            text: non_empty(data.text),
+            html: non_empty(data.html),
+            image,
+        }
+    }
+}
+
+fn read(clipboard: &mut Clipboard) -> Content {
+    Content {
+        text: clipboard.get_text().ok().filter(|text| !text.is_empty()),
+        html: clipboard.get().html().ok().filter(|html| !html.is_empty()),
+        image: clipboard.get_image().ok().map(|image| Image {
+            width: image.width as u32,
+            height: image.height as u32,
+            bytes: image.bytes.into_owned(),
+        }),
+    }
+}
+
+fn write(clipboard: &mut Clipboard, content: &Content) -> Result<(), arboard::Error> {
+    //同一时间剪贴板只保留一种格式，按图片、HTML、文本的优先级写入
+    if let Some(image) = &content.image {
+        return clipboard.set_image(ImageData {
+            width: image.width as usize,
+            height: image.height as usize,
+            bytes: Cow::Borrowed(&image.bytes),
+        });
+    }
+    if let Some(html) = &content.html {
+        return clipboard.set_html(html, content.text.as_ref());
+    }
+    if let Some(text) = &content.text {
+        return clipboard.set_text(text);
+    }
+    Ok(())
+}
+
+///同步开关，关闭后不发送也不写入本机剪贴板
+pub fn enable_message(enabled: bool) -> ClipboardMessage {
+    let mut msg = ClipboardMessage::new();
+    msg.set_enabled(enabled);
+    msg
+}
+
+///运行剪贴板同步直到通道断开，enabled为本端的开关，对方的开关由对方发来的消息更新
+pub fn start(
+    mut sender: ChannelSender,
+    receiver: ChannelReceiver,
+    enabled: Arc<AtomicBool>,
+) -> ResultType<()> {
+    let mut clipboard = Clipboard::new()?;
+    //最后一次同步内容的哈希
+    let last: Arc<Mutex<Vec<u8>>> = Arc::new(Mutex::new(read(&mut clipboard).hash()));
+    let remote_enabled = Arc::new(AtomicBool::new(true));
+    let closed = Arc::new(AtomicBool::new(false));
+    {
+        let last = last.clone();
+        let enabled = enabled.clone();
+        let remote_enabled = remote_enabled.clone();
+        let closed = closed.clone();
+        std::thread::spawn(move || {
+            //X11下写入的内容由这个实例提供，需要一直持有
+            let rs = receive(receiver, last, enabled, remote_enabled);
+            log::info!("剪贴板接收结束:{:?}", rs);
+            closed.store(true, Ordering::SeqCst);
+        });
+    }
+    while !closed.load(Ordering::SeqCst) {
+        std::thread::sleep(POLL_INTERVAL);
+        if !enabled.load(Ordering::SeqCst) || !remote_enabled.load(Ordering::SeqCst) {
+            continue;
+        }
+        let content = read(&mut clipboard);
+        let hash = content.hash();
+        {
+            let mut last = last.lock().unwrap();
+            if *last == hash {
+                continue;
+            }
+            *last = hash;
+        }
+        let content = match content.limit(MAX_SIZE) {
+            Some(content) => content,
+            None => {
+                log::info!("剪贴板内容为空或超过上限，不同步");
+                continue;
+            }
+        };
+        let mut msg = ClipboardMessage::new();
+        msg.set_data(content.to_data());
+        sender.send(msg.write_to_bytes()?)?;
+    }
+    Ok(())
+}
+
+fn receive(
+    receiver: ChannelReceiver,
+    last: Arc<Mutex<Vec<u8>>>,
+    enabled: Arc<AtomicBool>,
+    remote_enabled: Arc<AtomicBool>,
+) -> ResultType<()> {
+    let mut clipboard = Clipboard::new()?;
+    while let Ok(data) = receiver.recv() {
+        let msg = ClipboardMessage::parse_from_bytes(&data)?;
+        match msg.union {
+            Some(clipboard_message::Union::data(data)) => {
+                if !enabled.load(Ordering::SeqCst) {
+                    continue;
+                }
+                let content = Content::from_data(data);
+                if content.size() > MAX_SIZE {
+                    log::info!("收到的剪贴板内容超过上限:{}", content.size());
+                    continue;
+                }
+                //写入后读回的内容可能和收到的不完全一样，以读回的为准
+                let mut last = last.lock().unwrap();
+                if let Err(e) = write(&mut clipboard, &content) {
+                    log::info!("写入剪贴板失败:{:?}", e);
+                    continue;
+                }
+                *last = read(&mut clipboard).hash();
+            }
+            Some(clipboard_message::Union::enabled(remote)) => {
+                log::info!("对方剪贴板同步:{}", remote);
+                remote_enabled.store(remote, Ordering::SeqCst);
+                //重新开启时把当前内容同步过去
+                if remote {
+                    last.lock().unwrap().clear();
+                }
+            }
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(s: &str) -> Content {
+        Content {
+            text: Some(s.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(text("a").hash(), text("a").hash());
+        assert_ne!(text("a").hash(), text("b").hash());
+        //文本和HTML内容相同时也要区分
+        let html = Content {
+            html: Some(String::from("a")),
+            ..Default::default()
+        };
+        assert_ne!(text("a").hash(), html.hash());
+    }
+
+    #[test]
+    fn test_limit() {
+        let content = Content {
+            text: Some(String::from("abc")),
+            html: Some(String::from("<b>abc</b>")),
+            image: Some(Image {
+                width: 2,
+                height: 2,
+                bytes: vec![0; 16],
+            }),
+        };
+        assert_eq!(content.clone().limit(100), Some(content.clone()));
+        let limited = content.clone().limit(20).unwrap();
+        assert!(limited.image.is_none() && limited.html.is_some());
+        assert_eq!(content.clone().limit(5), Some(text("abc")));
+        assert_eq!(content.limit(2), None);
+        assert_eq!(Content::default().limit(100), None);
+    }
+
+    #[test]
+    fn test_data() {
+        let content = Content {
+            text: Some(String::from("中文")),
+            html: None,
+            image: Some(Image {
+                width: 1,
+                height: 2,
+                bytes: vec![1; 8],
+            }),
+        };
+        assert_eq!(Content::from_data(content.to_data()), content);
+        //像素数量不对的图片丢弃
+        let mut data = content.to_data();
+        data.image_height = 3;
+        assert!(Content::from_data(data).image.is_none());
+    }
+
+    //需要X11环境，例如：Xvfb :99 & DISPLAY=:99 cargo test -- --ignored
+    #[test]
+    #[ignore]
+    fn test_clipboard_round_trip() {
+        let mut clipboard = Clipboard::new().unwrap();
+        let content = text("onedesk剪贴板");
+        write(&mut clipboard, &content).unwrap();
+        assert_eq!(read(&mut clipboard).text, content.text);
+    }
+}
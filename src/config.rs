@@ -1,11 +1,26 @@
 
-use std::sync::RwLock;
+use std::{
+    path::{Path, PathBuf},
+    sync::RwLock,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
-use directories_next::ProjectDirs;
+use directories_next::{ProjectDirs, UserDirs};
 use serde_derive::{Deserialize, Serialize};
-use stream::sha2::Digest;
-use stream::{rand::Rng, utils::RsaPrivKey};
+use stream::{
+    permission::Permissions,
+    rand::Rng,
+    relay::Credentials,
+    utils::{derive_password, password_verifier, Aes128GcmUtil, RsaPrivKey},
+    RemoteError, ResultType,
+};
 const APPNAME: &str = "onedesk";
+//派生密码校验值的迭代次数
+const PASSWORD_ROUNDS: u32 = 100_000;
+//固定密码的最小长度
+const MIN_PERMANENT_PASSWORD_LEN: usize = 8;
+//最近连接显示的数量
+const RECENT_PEERS: usize = 10;
 const CHARS: &'static [char] = &[
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i',
     'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B',
@@ -15,17 +30,123 @@ const CHARS: &'static [char] = &[
 
 lazy_static::lazy_static! {
     static ref CONFIG: RwLock<Config> = RwLock::new(Config::load());
-    static ref PRIV:RsaPrivKey =  RsaPrivKey::new().unwrap();
+    //本机的身份密钥，对方按公钥指纹识别本机，每次启动都要相同
+    static ref PRIV:RsaPrivKey = Config::load_priv_key();
+    //临时密码只保存在内存中，每次会话成功后更换
+    static ref ONE_TIME_PASSWORD:RwLock<String> = RwLock::new(Config::random_password());
+    //加密地址簿中保存的密码，单独存放在本机，不随配置文件复制
+    static ref LOCAL_KEY:Vec<u8> = Config::load_local_key();
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
 pub struct Config {
     id: String,
+    //旧版本明文保存的密码，读取后不再写回
+    #[serde(default, skip_serializing)]
     password: String,
+    //按键宏
+    #[serde(default)]
+    macros: Vec<KeyMacro>,
+    //被控端允许接收和发送文件的目录，为空时只允许下载目录
+    #[serde(default)]
+    file_transfer_dirs: Vec<String>,
+    //文件浏览允许访问的根目录，为空时同文件传输目录
+    #[serde(default)]
+    file_browser_roots: Vec<String>,
+    //文件浏览只读，不允许新建、重命名和删除
+    #[serde(default)]
+    file_browser_read_only: bool,
+    //是否允许主控端打开终端
+    #[serde(default)]
+    terminal_enabled: bool,
+    //终端运行的shell，为空时使用系统默认的shell
+    #[serde(default)]
+    terminal_shell: String,
+    //端口转发允许连接的目标，如 127.0.0.1:8080、localhost:*，为空时不允许
+    #[serde(default)]
+    port_forward_targets: Vec<String>,
+    //远程转发允许被控端监听的端口，为空时不允许
+    #[serde(default)]
+    port_forward_listen_ports: Vec<u32>,
+    //音频源，为空时使用系统默认的监听源，tone为测试音，file:路径为PCM文件
+    #[serde(default)]
+    audio_source: String,
+    //主控端密码正确后还需要被控端的用户确认
+    #[serde(default)]
+    ask_before_accept: bool,
+    //固定密码派生的公钥，为空时未设置，用于无人值守。
+    //旧版本保存的哈希校验值不能检查登录证明，升级后需要重新设置
+    #[serde(default)]
+    permanent_public_key: Vec<u8>,
+    //仅查看密码派生的公钥，为空时不启用
+    #[serde(default)]
+    view_public_key: Vec<u8>,
+    //派生密码校验值的盐和迭代次数，本机的密码共用
+    #[serde(default)]
+    password_salt: Vec<u8>,
+    #[serde(default)]
+    password_rounds: u32,
+    //第二因素验证码的密钥，为空时不要求验证码
+    #[serde(default)]
+    totp_secret: Vec<u8>,
+    //设备密码授予的权限，为空时完全控制
+    #[serde(default)]
+    permissions: Option<u32>,
+    //地址簿，连接成功的设备自动加入
+    #[serde(default)]
+    peers: Vec<Peer>,
+    //允许其他设备通过ID服务器查询本机是否在线
+    #[serde(default)]
+    discoverable: bool,
+}
+
+///地址簿中的设备
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Peer {
+    pub id: String,
+    #[serde(default)]
+    pub alias: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    //最近一次连接成功的unix秒数，0为没有连接过
+    #[serde(default)]
+    pub last_connected: u64,
+    //第一次连接时记录的公钥指纹，之后不一致时拒绝连接
+    #[serde(default)]
+    pub fingerprint: String,
+    //用本机密钥加密的密码，前12字节为nonce，为空时每次询问
+    #[serde(default)]
+    pub saved_password: Vec<u8>,
+}
+
+impl Peer {
+    pub fn has_password(&self) -> bool {
+        !self.saved_password.is_empty()
+    }
+}
+
+///每一步是一组组合键，如ControlLeft+KeyC
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct KeyMacro {
+    pub name: String,
+    pub steps: Vec<String>,
 }
 
 impl Config {
     fn load() -> Self {
+        let mut conf = Config::load_file();
+        //首次运行时生成盐，旧版本的明文密码随之从文件中去掉
+        if conf.password_salt.is_empty() || !conf.password.is_empty() {
+            if conf.password_salt.is_empty() {
+                conf.password_salt = stream::rand::thread_rng().gen::<[u8; 16]>().to_vec();
+                conf.password_rounds = PASSWORD_ROUNDS;
+            }
+            conf.password.clear();
+            conf.store();
+        }
+        conf
+    }
+    fn load_file() -> Self {
         if let Some(project) = ProjectDirs::from("", "", APPNAME) {
             let mut path = project.data_local_dir().to_path_buf();
             path.push("user.yaml");
@@ -64,27 +185,474 @@ impl Config {
         w.id = id;
         w.store();
     }
+    ///临时密码，显示在界面上
     pub fn get_password() -> String {
-        CONFIG.read().unwrap().password.clone()
+        ONE_TIME_PASSWORD.read().unwrap().clone()
     }
-    pub fn get_password_hash() -> Vec<u8> {
-        let password = CONFIG.read().unwrap().password.clone();
-        let mut hasher2 = stream::sha2::Sha256::new();
-        hasher2.update(password);
-        hasher2.finalize()[..].into()
+    fn verifier(salt: &[u8], rounds: u32, password: &str) -> Vec<u8> {
+        password_verifier(&derive_password(password.as_bytes(), salt, rounds))
     }
-
-    pub fn update_password() -> String {
+    ///登录时使用的各个密码的校验值和对应的权限，临时密码在登录时比对当前的值
+    pub fn get_credentials() -> Credentials {
+        let r = CONFIG.read().unwrap();
+        let mut verifiers = Vec::new();
+        if !r.permanent_public_key.is_empty() {
+            verifiers.push((
+                r.permanent_public_key.clone(),
+                Config::permissions(&r),
+                true,
+            ));
+        }
+        if !r.view_public_key.is_empty() {
+            verifiers.push((r.view_public_key.clone(), Permissions::VIEW_ONLY, true));
+        }
+        Credentials {
+            salt: r.password_salt.clone(),
+            rounds: r.password_rounds,
+            verifiers,
+            //临时密码显示在界面上，有人在场，不需要验证码
+            one_time: Some(Config::use_one_time_password),
+            ..Default::default()
+        }
+    }
+    ///作为Credentials::one_time，参数检查校验值能否通过本次的登录证明。
+    ///更换时确认临时密码没有变化，同一个临时密码只能登录一次
+    pub fn use_one_time_password(check: &dyn Fn(&[u8]) -> bool) -> Option<Permissions> {
+        let (salt, rounds) = {
+            let r = CONFIG.read().unwrap();
+            (r.password_salt.clone(), r.password_rounds)
+        };
+        let password = Config::get_password();
+        //派生密钥耗时，不能在锁内进行
+        if !check(&Config::verifier(&salt, rounds, &password)) {
+            return None;
+        }
+        if !consume_one_time(&mut ONE_TIME_PASSWORD.write().unwrap(), &password) {
+            return None;
+        }
+        Some(Config::permissions(&CONFIG.read().unwrap()))
+    }
+    fn random_password() -> String {
         let mut rng = stream::rand::thread_rng();
-        let rs: String = (0..6)
+        (0..6)
             .map(|_| CHARS[rng.gen::<usize>() % CHARS.len()])
-            .collect();
-        Config::set_password(rs.clone());
+            .collect()
+    }
+
+    ///更换临时密码，旧的立即失效
+    pub fn update_password() -> String {
+        let rs = Config::random_password();
+        *ONE_TIME_PASSWORD.write().unwrap() = rs.clone();
         rs
     }
-    fn set_password(password: String) {
+    //为空时清除，否则只保存校验值
+    fn new_verifier(config: &Config, password: &str) -> ResultType<Vec<u8>> {
+        if password.is_empty() {
+            return Ok(Vec::new());
+        }
+        if password.chars().count() < MIN_PERMANENT_PASSWORD_LEN {
+            Err(RemoteError::InvalidData(format!(
+                "密码不能少于{}位",
+                MIN_PERMANENT_PASSWORD_LEN
+            )))?;
+        }
+        Ok(Config::verifier(
+            &config.password_salt,
+            config.password_rounds,
+            password,
+        ))
+    }
+    pub fn is_permanent_password_set() -> bool {
+        !CONFIG.read().unwrap().permanent_public_key.is_empty()
+    }
+    ///设置固定密码，为空时清除
+    pub fn set_permanent_password(password: &str) -> ResultType<()> {
+        let mut w = CONFIG.write().unwrap();
+        w.permanent_public_key = Config::new_verifier(&w, password)?;
+        w.store();
+        Ok(())
+    }
+    pub fn is_view_password_set() -> bool {
+        !CONFIG.read().unwrap().view_public_key.is_empty()
+    }
+    ///设置仅查看密码，为空时不启用
+    pub fn set_view_password(password: &str) -> ResultType<()> {
+        let mut w = CONFIG.write().unwrap();
+        w.view_public_key = Config::new_verifier(&w, password)?;
+        w.store();
+        Ok(())
+    }
+    pub fn get_totp_secret() -> Vec<u8> {
+        CONFIG.read().unwrap().totp_secret.clone()
+    }
+    ///为空时不再要求验证码
+    pub fn set_totp_secret(secret: Vec<u8>) {
+        let mut w = CONFIG.write().unwrap();
+        w.totp_secret = secret;
+        w.store();
+    }
+    pub fn get_macros() -> Vec<KeyMacro> {
+        CONFIG.read().unwrap().macros.clone()
+    }
+    ///同名的宏会被覆盖
+    pub fn set_macro(key_macro: KeyMacro) {
+        let mut w = CONFIG.write().unwrap();
+        match w.macros.iter_mut().find(|m| m.name == key_macro.name) {
+            Some(m) => *m = key_macro,
+            None => w.macros.push(key_macro),
+        }
+        w.store();
+    }
+    pub fn remove_macro(name: &str) {
+        let mut w = CONFIG.write().unwrap();
+        w.macros.retain(|m| m.name != name);
+        w.store();
+    }
+    ///第一个目录为默认的接收目录
+    pub fn get_file_transfer_dirs() -> Vec<PathBuf> {
+        let dirs = CONFIG.read().unwrap().file_transfer_dirs.clone();
+        if !dirs.is_empty() {
+            return dirs.into_iter().map(PathBuf::from).collect();
+        }
+        UserDirs::new()
+            .and_then(|dirs| dirs.download_dir().map(|dir| dir.to_path_buf()))
+            .into_iter()
+            .collect()
+    }
+    pub fn set_file_transfer_dirs(dirs: Vec<String>) {
+        let mut w = CONFIG.write().unwrap();
+        w.file_transfer_dirs = dirs;
+        w.store();
+    }
+    pub fn get_file_browser_roots() -> Vec<PathBuf> {
+        let roots = CONFIG.read().unwrap().file_browser_roots.clone();
+        if roots.is_empty() {
+            return Config::get_file_transfer_dirs();
+        }
+        roots.into_iter().map(PathBuf::from).collect()
+    }
+    pub fn set_file_browser_roots(roots: Vec<String>) {
+        let mut w = CONFIG.write().unwrap();
+        w.file_browser_roots = roots;
+        w.store();
+    }
+    pub fn is_file_browser_read_only() -> bool {
+        CONFIG.read().unwrap().file_browser_read_only
+    }
+    pub fn set_file_browser_read_only(read_only: bool) {
+        let mut w = CONFIG.write().unwrap();
+        w.file_browser_read_only = read_only;
+        w.store();
+    }
+    pub fn is_terminal_enabled() -> bool {
+        CONFIG.read().unwrap().terminal_enabled
+    }
+    pub fn set_terminal_enabled(enabled: bool) {
+        let mut w = CONFIG.write().unwrap();
+        w.terminal_enabled = enabled;
+        w.store();
+    }
+    pub fn get_terminal_shell() -> String {
+        CONFIG.read().unwrap().terminal_shell.clone()
+    }
+    pub fn set_terminal_shell(shell: String) {
+        let mut w = CONFIG.write().unwrap();
+        w.terminal_shell = shell;
+        w.store();
+    }
+    pub fn get_port_forward_targets() -> Vec<String> {
+        CONFIG.read().unwrap().port_forward_targets.clone()
+    }
+    pub fn set_port_forward_targets(targets: Vec<String>) {
+        let mut w = CONFIG.write().unwrap();
+        w.port_forward_targets = targets;
+        w.store();
+    }
+    pub fn get_port_forward_listen_ports() -> Vec<u32> {
+        CONFIG.read().unwrap().port_forward_listen_ports.clone()
+    }
+    pub fn set_port_forward_listen_ports(ports: Vec<u32>) {
+        let mut w = CONFIG.write().unwrap();
+        w.port_forward_listen_ports = ports;
+        w.store();
+    }
+    pub fn get_audio_source() -> String {
+        CONFIG.read().unwrap().audio_source.clone()
+    }
+    pub fn set_audio_source(source: String) {
         let mut w = CONFIG.write().unwrap();
-        w.password = password;
+        w.audio_source = source;
         w.store();
     }
+    pub fn is_ask_before_accept() -> bool {
+        CONFIG.read().unwrap().ask_before_accept
+    }
+    pub fn set_ask_before_accept(ask: bool) {
+        let mut w = CONFIG.write().unwrap();
+        w.ask_before_accept = ask;
+        w.store();
+    }
+    fn load_priv_key() -> RsaPrivKey {
+        match ProjectDirs::from("", "", APPNAME) {
+            Some(project) => load_or_create_priv_key(&project.data_local_dir().join("host.key")),
+            //没有数据目录时只在本次运行有效
+            None => RsaPrivKey::new().unwrap(),
+        }
+    }
+    fn load_local_key() -> Vec<u8> {
+        let path = ProjectDirs::from("", "", APPNAME).map(|project| {
+            let mut path = project.data_local_dir().to_path_buf();
+            path.push("local.key");
+            path
+        });
+        if let Some(path) = path {
+            match std::fs::read(&path) {
+                Ok(key) if key.len() == 16 => return key,
+                _ => {
+                    let key = stream::rand::thread_rng().gen::<[u8; 16]>().to_vec();
+                    if let Some(dir) = path.parent() {
+                        let _ = std::fs::create_dir_all(dir);
+                    }
+                    if let Err(err) = std::fs::write(&path, &key) {
+                        log::error!("Failed to store local key: {}", err);
+                    }
+                    return key;
+                }
+            }
+        }
+        //没有数据目录时只在本次运行有效
+        stream::rand::thread_rng().gen::<[u8; 16]>().to_vec()
+    }
+    ///按最近连接的时间排序
+    pub fn get_peers() -> Vec<Peer> {
+        let mut peers = CONFIG.read().unwrap().peers.clone();
+        peers.sort_by(|a, b| b.last_connected.cmp(&a.last_connected));
+        peers
+    }
+    pub fn get_recent_peers() -> Vec<Peer> {
+        Config::get_peers()
+            .into_iter()
+            .filter(|peer| peer.last_connected > 0)
+            .take(RECENT_PEERS)
+            .collect()
+    }
+    fn peer_mut<'a>(peers: &'a mut Vec<Peer>, id: &str) -> &'a mut Peer {
+        if let Some(index) = peers.iter().position(|peer| peer.id == id) {
+            return &mut peers[index];
+        }
+        peers.push(Peer {
+            id: id.to_string(),
+            ..Default::default()
+        });
+        peers.last_mut().unwrap()
+    }
+    ///不存在时新建，其他信息不变
+    pub fn save_peer(id: &str, alias: &str, tags: Vec<String>) {
+        let mut w = CONFIG.write().unwrap();
+        let peer = Config::peer_mut(&mut w.peers, id);
+        peer.alias = alias.to_string();
+        peer.tags = tags;
+        w.store();
+    }
+    pub fn remove_peer(id: &str) {
+        let mut w = CONFIG.write().unwrap();
+        w.peers.retain(|peer| peer.id != id);
+        w.store();
+    }
+    ///保存连接该设备的密码，为空时清除
+    pub fn set_peer_password(id: &str, password: &str) -> ResultType<()> {
+        let saved = if password.is_empty() {
+            Vec::new()
+        } else {
+            seal(&LOCAL_KEY, password.as_bytes())?
+        };
+        let mut w = CONFIG.write().unwrap();
+        Config::peer_mut(&mut w.peers, id).saved_password = saved;
+        w.store();
+        Ok(())
+    }
+    ///本机密钥变了时无法解密，按没有保存处理
+    pub fn get_peer_password(id: &str) -> Option<String> {
+        let r = CONFIG.read().unwrap();
+        let peer = r.peers.iter().find(|peer| peer.id == id)?;
+        if !peer.has_password() {
+            return None;
+        }
+        open(&LOCAL_KEY, &peer.saved_password)
+            .ok()
+            .and_then(|password| String::from_utf8(password).ok())
+    }
+    ///连接前核对公钥指纹，没有记录时通过
+    pub fn check_peer_fingerprint(id: &str, fingerprint: &str) -> ResultType<()> {
+        let r = CONFIG.read().unwrap();
+        check_fingerprint(&r.peers, id, fingerprint)
+    }
+    ///连接成功后记录时间，第一次连接时记录指纹
+    pub fn record_connection(id: &str, fingerprint: &str) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or(0);
+        let mut w = CONFIG.write().unwrap();
+        record_connection(&mut w.peers, id, fingerprint, now);
+        w.store();
+    }
+    ///对方重装后公钥会变，确认后清除记录的指纹，下次连接时重新记录
+    pub fn forget_peer_fingerprint(id: &str) {
+        let mut w = CONFIG.write().unwrap();
+        if let Some(peer) = w.peers.iter_mut().find(|peer| peer.id == id) {
+            peer.fingerprint.clear();
+            w.store();
+        }
+    }
+    pub fn is_discoverable() -> bool {
+        CONFIG.read().unwrap().discoverable
+    }
+    pub fn set_discoverable(discoverable: bool) {
+        let mut w = CONFIG.write().unwrap();
+        w.discoverable = discoverable;
+        w.store();
+    }
+    fn permissions(config: &Config) -> Permissions {
+        config
+            .permissions
+            .map(Permissions::from_bits)
+            .unwrap_or(Permissions::FULL)
+    }
+    pub fn get_permissions() -> Permissions {
+        Config::permissions(&CONFIG.read().unwrap())
+    }
+    pub fn set_permissions(permissions: Permissions) {
+        let mut w = CONFIG.write().unwrap();
+        w.permissions = Some(permissions.bits());
+        w.store();
+    }
+}
+
+fn check_fingerprint(peers: &[Peer], id: &str, fingerprint: &str) -> ResultType<()> {
+    match peers.iter().find(|peer| peer.id == id) {
+        Some(peer) if !peer.fingerprint.is_empty() && peer.fingerprint != fingerprint => {
+            Err(RemoteError::Rejected(format!(
+                "对方的公钥指纹和地址簿中记录的不一致:{}",
+                fingerprint
+            ))
+            .into())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn record_connection(peers: &mut Vec<Peer>, id: &str, fingerprint: &str, now: u64) {
+    let peer = Config::peer_mut(peers, id);
+    peer.last_connected = now;
+    if peer.fingerprint.is_empty() {
+        peer.fingerprint = fingerprint.to_string();
+    }
+}
+
+//nonce放在密文前面
+fn seal(key: &[u8], plaintext: &[u8]) -> ResultType<Vec<u8>> {
+    let nonce = stream::rand::thread_rng().gen::<[u8; 12]>();
+    let mut sealed = nonce.to_vec();
+    sealed.extend(Aes128GcmUtil::new(key)?.encrypt(plaintext, &nonce)?);
+    Ok(sealed)
+}
+
+fn open(key: &[u8], sealed: &[u8]) -> ResultType<Vec<u8>> {
+    if sealed.len() < 12 {
+        Err(RemoteError::Decrypt(String::from("密文长度错误")))?
+    }
+    Ok(Aes128GcmUtil::new(key)?.decrypt(&sealed[12..], &sealed[..12])?)
+}
+
+//读取保存的私钥，没有或无法解析时生成新的并保存
+fn load_or_create_priv_key(path: &Path) -> RsaPrivKey {
+    if let Ok(data) = std::fs::read(path) {
+        match RsaPrivKey::from_bytes(&data) {
+            Ok(key) => return key,
+            Err(err) => log::error!("Failed to load host key: {}", err),
+        }
+    }
+    let key = RsaPrivKey::new().unwrap();
+    match key.to_bytes() {
+        Ok(data) => {
+            if let Some(dir) = path.parent() {
+                let _ = std::fs::create_dir_all(dir);
+            }
+            if let Err(err) = write_private_file(path, &data) {
+                log::error!("Failed to store host key: {}", err);
+            }
+        }
+        Err(err) => log::error!("Failed to encode host key: {}", err),
+    }
+    key
+}
+
+//私钥文件只允许本用户读写
+fn write_private_file(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)?.write_all(data)
+}
+
+//当前仍是已比对的临时密码时换成新的，旧的立即失效
+fn consume_one_time(current: &mut String, used: &str) -> bool {
+    if current != used {
+        return false;
+    }
+    *current = Config::random_password();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_book() {
+        let mut peers = Vec::new();
+        record_connection(&mut peers, "123", "AB:CD", 100);
+        assert_eq!(peers[0].fingerprint, "AB:CD");
+        assert!(check_fingerprint(&peers, "123", "AB:CD").is_ok());
+        assert!(check_fingerprint(&peers, "123", "EF:01").is_err());
+        assert!(check_fingerprint(&peers, "456", "EF:01").is_ok());
+        //再次连接只更新时间
+        record_connection(&mut peers, "123", "EF:01", 200);
+        assert_eq!(peers.len(), 1);
+        assert_eq!(peers[0].last_connected, 200);
+        assert_eq!(peers[0].fingerprint, "AB:CD");
+
+        let key = [7u8; 16];
+        let sealed = seal(&key, b"password").unwrap();
+        assert_eq!(open(&key, &sealed).unwrap(), b"password");
+        assert!(open(&[8u8; 16], &sealed).is_err());
+    }
+
+    #[test]
+    fn test_host_key_persisted() {
+        let path = std::env::temp_dir().join(format!("onedesk-{}-host.key", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let first = load_or_create_priv_key(&path).to_public_key().unwrap();
+        //重新读取配置时指纹不变
+        let second = load_or_create_priv_key(&path).to_public_key().unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(
+            stream::utils::fingerprint(&first),
+            stream::utils::fingerprint(&second)
+        );
+    }
+
+    #[test]
+    fn test_one_time_password() {
+        let mut password = String::from("abc123");
+        assert!(!consume_one_time(&mut password, "wrong"));
+        assert_eq!(password, "abc123");
+        //登录成功后同一个密码不能再用
+        assert!(consume_one_time(&mut password, "abc123"));
+        assert_ne!(password, "abc123");
+        assert!(!consume_one_time(&mut password, "abc123"));
+    }
 }
@@ -0,0 +1,619 @@
+//! 文件传输，主控端可以向被控端发送文件，也可以请求被控端发送文件。
+//! 接收方先写入临时文件，断线重连后再次发送同一文件时从临时文件的长度继续，
+//! 每个分块和整个文件都用SHA-256校验
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    path::{Component, Path, PathBuf},
+    sync::{
+        mpsc::{channel, Receiver, RecvTimeoutError, Sender},
+        Arc, Mutex, RwLock,
+    },
+    time::Duration,
+};
+
+use stream::{
+    message::{
+        file_transfer_message, FileAccept, FileChunk, FileDone, FileOffer, FileReject, FileRequest,
+        FileResult, FileTransferMessage,
+    },
+    protobuf::Message,
+    rand::Rng,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    sha2::{Digest, Sha256},
+    RemoteError, ResultType,
+};
+
+const CHUNK_SIZE: usize = 64 * 1024;
+//等待对方接受或返回结果的时间
+const REPLY_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum State {
+    Pending,
+    Running,
+    Done,
+    Failed(String),
+}
+
+///传输进度，供界面显示
+#[derive(Debug, Clone)]
+pub struct Transfer {
+    pub id: u64,
+    pub name: String,
+    pub size: u64,
+    pub transferred: u64,
+    //true为发送，false为接收
+    pub outgoing: bool,
+    pub state: State,
+}
+
+lazy_static::lazy_static! {
+    static ref TRANSFERS:RwLock<Vec<Transfer>> = RwLock::new(Vec::new());
+}
+
+///所有传输的进度
+pub fn transfers() -> Vec<Transfer> {
+    TRANSFERS.read().unwrap().clone()
+}
+///清除已结束的传输
+pub fn clear_finished() {
+    TRANSFERS
+        .write()
+        .unwrap()
+        .retain(|t| t.state == State::Pending || t.state == State::Running);
+}
+fn progress_start(id: u64, name: &str, size: u64, outgoing: bool) {
+    let mut transfers = TRANSFERS.write().unwrap();
+    transfers.retain(|t| t.id != id);
+    transfers.push(Transfer {
+        id,
+        name: name.to_string(),
+        size,
+        transferred: 0,
+        outgoing,
+        state: State::Pending,
+    });
+}
+fn progress_update(id: u64, transferred: u64, state: State) {
+    if let Some(t) = TRANSFERS.write().unwrap().iter_mut().find(|t| t.id == id) {
+        t.transferred = transferred;
+        t.state = state;
+    }
+}
+fn progress_fail(id: u64, error: String) {
+    if let Some(t) = TRANSFERS.write().unwrap().iter_mut().find(|t| t.id == id) {
+        log::info!("文件传输失败:{},{}", t.name, error);
+        t.state = State::Failed(error);
+    }
+}
+
+///接收文件的目录限制
+#[derive(Debug, Clone)]
+pub struct Policy {
+    //允许写入和读取的目录，None为不限制
+    pub allowed_dirs: Option<Vec<PathBuf>>,
+    //对方没有指定目录时写入的目录
+    pub default_dir: PathBuf,
+    //是否接受对方主动发送的文件，主控端只接收自己请求的文件
+    pub accept_offers: bool,
+    //是否发送对方请求的文件，主控端不允许被控端读取本机文件
+    pub serve_requests: bool,
+}
+
+impl Policy {
+    ///目录必须在允许的目录内，返回规范化后的路径
+    pub fn check_dir(&self, dir: &str) -> Result<PathBuf, String> {
+        let dir = if dir.is_empty() {
+            self.default_dir.clone()
+        } else {
+            PathBuf::from(dir)
+        };
+        let dir = dir
+            .canonicalize()
+            .map_err(|e| format!("目录不存在:{:?},{}", dir, e))?;
+        self.check_allowed(&dir)?;
+        Ok(dir)
+    }
+    ///对方请求的文件，不允许时直接拒绝
+    pub fn check_request(&self, path: &str) -> Result<PathBuf, String> {
+        if !self.serve_requests {
+            return Err(String::from("不允许读取本机文件"));
+        }
+        self.check_file(path)
+    }
+    pub fn check_file(&self, path: &str) -> Result<PathBuf, String> {
+        let path = PathBuf::from(path)
+            .canonicalize()
+            .map_err(|e| format!("文件不存在:{},{}", path, e))?;
+        if !path.is_file() {
+            return Err(format!("不是文件:{:?}", path));
+        }
+        self.check_allowed(&path)?;
+        Ok(path)
+    }
+    fn check_allowed(&self, path: &Path) -> Result<(), String> {
+        if let Some(allowed_dirs) = &self.allowed_dirs {
+            let allowed = allowed_dirs.iter().any(|allowed| {
+                allowed
+                    .canonicalize()
+                    .map_or(false, |allowed| path.starts_with(allowed))
+            });
+            if !allowed {
+                return Err(format!("不允许访问该目录:{:?}", path));
+            }
+        }
+        Ok(())
+    }
+}
+
+///文件名不能包含目录
+pub fn check_name(name: &str) -> Result<(), String> {
+    let mut components = Path::new(name).components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(_)), None) => Ok(()),
+        _ => Err(format!("非法的文件名:{}", name)),
+    }
+}
+
+fn to_hex(data: &[u8]) -> String {
+    data.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn sha256(data: &[u8]) -> Vec<u8> {
+    Sha256::digest(data)[..].into()
+}
+
+fn file_sha256(path: &Path) -> std::io::Result<Vec<u8>> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0; CHUNK_SIZE];
+    loop {
+        let len = file.read(&mut buf)?;
+        if len == 0 {
+            break;
+        }
+        hasher.update(&buf[..len]);
+    }
+    Ok(hasher.finalize()[..].into())
+}
+
+///临时文件名包含文件的哈希，内容不同的同名文件不会误续传
+fn part_path(dir: &Path, name: &str, sha: &[u8]) -> PathBuf {
+    let hex = to_hex(sha);
+    dir.join(format!(".{}.{}.part", name, &hex[..hex.len().min(16)]))
+}
+
+//发送线程等待的回复
+enum Reply {
+    Accept(u64),
+    Reject(String),
+    Result(bool, String),
+}
+
+struct Incoming {
+    file: File,
+    part: PathBuf,
+    path: PathBuf,
+    name: String,
+    size: u64,
+    sha256: Vec<u8>,
+    written: u64,
+}
+
+///一次会话的文件传输，可以在多个线程中克隆使用
+#[derive(Clone)]
+pub struct Session {
+    sender: ChannelSender,
+    policy: Arc<Policy>,
+    replies: Arc<Mutex<HashMap<u64, Sender<Reply>>>>,
+    //自己请求、等待对方发送的文件
+    requested: Arc<Mutex<HashSet<u64>>>,
+}
+
+impl Session {
+    pub fn new(sender: ChannelSender, policy: Policy) -> Self {
+        Self {
+            sender,
+            policy: Arc::new(policy),
+            replies: Arc::new(Mutex::new(HashMap::new())),
+            requested: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    fn send(&self, msg: FileTransferMessage) -> ResultType<()> {
+        self.sender.clone().send(msg.write_to_bytes()?)
+    }
+
+    ///发送本机文件，dir为对方的目标目录，为空时使用对方的默认目录
+    pub fn send_file(&self, path: &str, dir: &str) -> ResultType<u64> {
+        let id: u64 = stream::rand::thread_rng().gen();
+        self.send_file_with_id(id, PathBuf::from(path), dir.to_string())?;
+        Ok(id)
+    }
+
+    ///请求对方发送文件
+    pub fn request_file(&self, path: &str) -> ResultType<u64> {
+        let id: u64 = stream::rand::thread_rng().gen();
+        self.requested.lock().unwrap().insert(id);
+        let name = Path::new(path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.to_string());
+        progress_start(id, &name, 0, false);
+        let mut msg = FileTransferMessage::new();
+        msg.set_request(FileRequest {
+            id,
+            path: path.to_string(),
+            ..Default::default()
+        });
+        self.send(msg)?;
+        Ok(id)
+    }
+
+    fn send_file_with_id(&self, id: u64, path: PathBuf, dir: String) -> ResultType<()> {
+        let name = match path.file_name() {
+            Some(name) => name.to_string_lossy().to_string(),
+            None => Err(RemoteError::InvalidData(format!("不是文件:{:?}", path)))?,
+        };
+        let size = fs::metadata(&path)?.len();
+        progress_start(id, &name, size, true);
+        let (reply_sender, reply_receiver) = channel();
+        self.replies.lock().unwrap().insert(id, reply_sender);
+        let session = self.clone();
+        std::thread::spawn(move || {
+            let rs = session.upload(id, &path, name, size, dir, reply_receiver);
+            session.replies.lock().unwrap().remove(&id);
+            if let Err(e) = rs {
+                progress_fail(id, e.to_string());
+            }
+        });
+        Ok(())
+    }
+
+    fn upload(
+        &self,
+        id: u64,
+        path: &Path,
+        name: String,
+        size: u64,
+        dir: String,
+        replies: Receiver<Reply>,
+    ) -> ResultType<()> {
+        let file_sha = file_sha256(path)?;
+        let mut msg = FileTransferMessage::new();
+        msg.set_offer(FileOffer {
+            id,
+            name,
+            size,
+            sha256: file_sha,
+            dir,
+            ..Default::default()
+        });
+        self.send(msg)?;
+        let mut offset = match wait_reply(&replies)? {
+            Reply::Accept(offset) => offset,
+            Reply::Reject(reason) => Err(RemoteError::Peer(reason))?,
+            Reply::Result(_, error) => Err(RemoteError::Peer(error))?,
+        };
+        let mut file = File::open(path)?;
+        let mut buf = vec![0; CHUNK_SIZE];
+        progress_update(id, offset, State::Running);
+        loop {
+            //接收方校验失败时要求从指定位置重新发送
+            match replies.try_recv() {
+                Ok(Reply::Accept(resend)) => offset = resend,
+                Ok(Reply::Reject(reason)) => Err(RemoteError::Peer(reason))?,
+                Ok(Reply::Result(_, error)) => Err(RemoteError::Peer(error))?,
+                Err(_) => {}
+            }
+            if offset >= size {
+                break;
+            }
+            file.seek(SeekFrom::Start(offset))?;
+            let len = file.read(&mut buf)?;
+            if len == 0 {
+                Err(RemoteError::InvalidData(String::from(
+                    "文件在发送过程中被修改",
+                )))?;
+            }
+            let data = buf[..len].to_vec();
+            let mut msg = FileTransferMessage::new();
+            msg.set_chunk(FileChunk {
+                id,
+                offset,
+                sha256: sha256(&data),
+                data,
+                ..Default::default()
+            });
+            self.send(msg)?;
+            offset += len as u64;
+            progress_update(id, offset, State::Running);
+        }
+        let mut msg = FileTransferMessage::new();
+        msg.set_done(FileDone {
+            id,
+            ..Default::default()
+        });
+        self.send(msg)?;
+        loop {
+            match wait_reply(&replies)? {
+                Reply::Result(true, _) => break,
+                Reply::Result(false, error) => Err(RemoteError::Peer(error))?,
+                Reply::Reject(reason) => Err(RemoteError::Peer(reason))?,
+                //结束前收到的重发请求已经处理不了，由对方的整体校验失败返回
+                Reply::Accept(_) => {}
+            }
+        }
+        progress_update(id, size, State::Done);
+        Ok(())
+    }
+
+    ///处理对方的消息直到通道断开
+    pub fn run(&self, receiver: ChannelReceiver) -> ResultType<()> {
+        let mut incoming: HashMap<u64, Incoming> = HashMap::new();
+        while let Ok(data) = receiver.recv() {
+            let msg = FileTransferMessage::parse_from_bytes(&data)?;
+            let union = match msg.union {
+                Some(union) => union,
+                None => continue,
+            };
+            match union {
+                file_transfer_message::Union::request(request) => {
+                    let rs = match self.policy.check_request(&request.path) {
+                        Ok(path) => self
+                            .send_file_with_id(request.id, path, String::new())
+                            .map_err(|e| e.to_string()),
+                        Err(e) => Err(e),
+                    };
+                    if let Err(e) = rs {
+                        self.reject(request.id, e)?;
+                    }
+                }
+                file_transfer_message::Union::offer(offer) => {
+                    let id = offer.id;
+                    match self.open_incoming(offer) {
+                        Ok(file) => {
+                            let offset = file.written;
+                            progress_update(id, offset, State::Running);
+                            incoming.insert(id, file);
+                            self.accept(id, offset)?;
+                        }
+                        Err(e) => {
+                            progress_fail(id, e.clone());
+                            self.reject(id, e)?;
+                        }
+                    }
+                }
+                file_transfer_message::Union::chunk(chunk) => {
+                    let id = chunk.id;
+                    if let Some(file) = incoming.get_mut(&id) {
+                        //位置不对或校验失败时从已写入的位置重新发送
+                        if chunk.offset != file.written || sha256(&chunk.data) != chunk.sha256 {
+                            log::info!("文件分块校验失败:{},{}", file.name, chunk.offset);
+                            let written = file.written;
+                            self.accept(id, written)?;
+                            continue;
+                        }
+                        if let Err(e) = file.file.write_all(&chunk.data) {
+                            incoming.remove(&id);
+                            progress_fail(id, e.to_string());
+                            self.reject(id, e.to_string())?;
+                            continue;
+                        }
+                        file.written += chunk.data.len() as u64;
+                        progress_update(id, file.written, State::Running);
+                    }
+                }
+                file_transfer_message::Union::done(done) => {
+                    if let Some(file) = incoming.remove(&done.id) {
+                        let rs = finish(file);
+                        match &rs {
+                            Ok(size) => progress_update(done.id, *size, State::Done),
+                            Err(e) => progress_fail(done.id, e.clone()),
+                        }
+                        let mut msg = FileTransferMessage::new();
+                        msg.set_result(FileResult {
+                            id: done.id,
+                            ok: rs.is_ok(),
+                            error: rs.err().unwrap_or_default(),
+                            ..Default::default()
+                        });
+                        self.send(msg)?;
+                    }
+                }
+                file_transfer_message::Union::accept(accept) => {
+                    self.reply(accept.id, Reply::Accept(accept.offset));
+                }
+                file_transfer_message::Union::reject(reject) => {
+                    //请求的文件被拒绝时没有发送线程
+                    if self.requested.lock().unwrap().remove(&reject.id) {
+                        progress_fail(reject.id, reject.reason.clone());
+                    }
+                    self.reply(reject.id, Reply::Reject(reject.reason));
+                }
+                file_transfer_message::Union::result(result) => {
+                    self.reply(result.id, Reply::Result(result.ok, result.error));
+                }
+            }
+        }
+        //未完成的临时文件保留，用于续传
+        for (id, _) in incoming {
+            progress_fail(id, String::from("连接断开"));
+        }
+        Ok(())
+    }
+
+    fn open_incoming(&self, offer: FileOffer) -> Result<Incoming, String> {
+        let requested = self.requested.lock().unwrap().remove(&offer.id);
+        if !requested && !self.policy.accept_offers {
+            return Err(String::from("不接收文件"));
+        }
+        check_name(&offer.name)?;
+        //请求的文件保存到默认目录，不使用对方指定的目录
+        let dir = if requested {
+            self.policy.check_dir("")?
+        } else {
+            self.policy.check_dir(&offer.dir)?
+        };
+        progress_start(offer.id, &offer.name, offer.size, false);
+        let part = part_path(&dir, &offer.name, &offer.sha256);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .open(&part)
+            .map_err(|e| format!("无法创建文件:{:?},{}", part, e))?;
+        //已有的部分按分块对齐后续传
+        let len = file.metadata().map_err(|e| e.to_string())?.len();
+        let written = resume_offset(len, offer.size);
+        file.set_len(written).map_err(|e| e.to_string())?;
+        file.seek(SeekFrom::Start(written))
+            .map_err(|e| e.to_string())?;
+        Ok(Incoming {
+            file,
+            part,
+            path: dir.join(&offer.name),
+            name: offer.name,
+            size: offer.size,
+            sha256: offer.sha256,
+            written,
+        })
+    }
+
+    fn accept(&self, id: u64, offset: u64) -> ResultType<()> {
+        let mut msg = FileTransferMessage::new();
+        msg.set_accept(FileAccept {
+            id,
+            offset,
+            ..Default::default()
+        });
+        self.send(msg)
+    }
+
+    fn reject(&self, id: u64, reason: String) -> ResultType<()> {
+        let mut msg = FileTransferMessage::new();
+        msg.set_reject(FileReject {
+            id,
+            reason,
+            ..Default::default()
+        });
+        self.send(msg)
+    }
+
+    fn reply(&self, id: u64, reply: Reply) {
+        if let Some(sender) = self.replies.lock().unwrap().get(&id) {
+            let _ = sender.send(reply);
+        }
+    }
+}
+
+fn wait_reply(replies: &Receiver<Reply>) -> ResultType<Reply> {
+    match replies.recv_timeout(REPLY_TIMEOUT) {
+        Ok(reply) => Ok(reply),
+        Err(RecvTimeoutError::Timeout) => Err(RemoteError::Peer(String::from("等待对方回复超时")))?,
+        Err(RecvTimeoutError::Disconnected) => Err(RemoteError::Disconnection)?,
+    }
+}
+
+//续传位置，按分块对齐，超过文件大小时重新开始
+fn resume_offset(len: u64, size: u64) -> u64 {
+    if len > size {
+        return 0;
+    }
+    len - len % CHUNK_SIZE as u64
+}
+
+//校验整个文件后改成正式的文件名，返回文件大小
+fn finish(mut file: Incoming) -> Result<u64, String> {
+    file.file.flush().map_err(|e| e.to_string())?;
+    drop(file.file);
+    if file.written != file.size {
+        return Err(format!("文件大小不一致:{}/{}", file.written, file.size));
+    }
+    let sha = file_sha256(&file.part).map_err(|e| e.to_string())?;
+    if sha != file.sha256 {
+        let _ = fs::remove_file(&file.part);
+        return Err(String::from("文件校验失败"));
+    }
+    if file.path.exists() {
+        return Err(format!("文件已存在:{:?}", file.path));
+    }
+    fs::rename(&file.part, &file.path).map_err(|e| e.to_string())?;
+    log::info!("文件接收完成:{:?}", file.path);
+    Ok(file.size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_name() {
+        assert!(check_name("a.txt").is_ok());
+        assert!(check_name("../a.txt").is_err());
+        assert!(check_name("dir/a.txt").is_err());
+        assert!(check_name("").is_err());
+        assert!(check_name("/etc/passwd").is_err());
+    }
+
+    #[test]
+    fn test_resume_offset() {
+        let chunk = CHUNK_SIZE as u64;
+        assert_eq!(resume_offset(0, 10 * chunk), 0);
+        assert_eq!(resume_offset(chunk * 3 + 100, 10 * chunk), chunk * 3);
+        assert_eq!(resume_offset(11 * chunk, 10 * chunk), 0);
+    }
+
+    #[test]
+    fn test_policy() {
+        let root = std::env::temp_dir().join(format!("onedesk-ft-{}", std::process::id()));
+        let allowed = root.join("allowed");
+        let other = root.join("other");
+        fs::create_dir_all(&allowed).unwrap();
+        fs::create_dir_all(&other).unwrap();
+        let policy = Policy {
+            allowed_dirs: Some(vec![allowed.clone()]),
+            default_dir: allowed.clone(),
+            accept_offers: true,
+            serve_requests: true,
+        };
+        assert!(policy.check_dir("").is_ok());
+        assert!(policy.check_dir(allowed.to_str().unwrap()).is_ok());
+        assert!(policy.check_dir(other.to_str().unwrap()).is_err());
+        let escape = allowed.join("..").join("other");
+        assert!(policy.check_dir(escape.to_str().unwrap()).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_viewer_rejects_request() {
+        let (sender, sent, inbound, receiver) = stream::remote_channel::local_channel(1);
+        let policy = Policy {
+            allowed_dirs: None,
+            default_dir: std::env::temp_dir(),
+            accept_offers: false,
+            serve_requests: false,
+        };
+        //被控端请求主控端本机的文件
+        let path = std::env::current_exe().unwrap();
+        let mut msg = FileTransferMessage::new();
+        msg.set_request(FileRequest {
+            id: 7,
+            path: path.to_string_lossy().to_string(),
+            ..Default::default()
+        });
+        inbound.send(msg.write_to_bytes().unwrap()).unwrap();
+        drop(inbound);
+        Session::new(sender, policy).run(receiver).unwrap();
+        let mover = stream::message::ChannelMover::parse_from_bytes(&sent.recv().unwrap()).unwrap();
+        let reply = FileTransferMessage::parse_from_bytes(&mover.data).unwrap();
+        match reply.union {
+            Some(file_transfer_message::Union::reject(reject)) => assert_eq!(reject.id, 7),
+            other => panic!("请求没有被拒绝:{:?}", other),
+        }
+        //没有发送文件
+        assert!(sent.recv().is_err());
+    }
+}
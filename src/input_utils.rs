@@ -1,13 +1,38 @@
-use enigo::MouseButton;
+use enigo::Key;
+use stream::message::KeyEvent;
 
-pub fn to_mouse(key: u32) -> MouseButton {
+use crate::{keycode, server::input_injector::Button};
+
+pub fn to_mouse(key: u32) -> Option<Button> {
     match key {
-        1 => MouseButton::Left,
-        2 => MouseButton::Middle,
-        3 => MouseButton::Right,
-        _ => MouseButton::Left,
+        1 => Some(Button::Left),
+        2 => Some(Button::Middle),
+        3 => Some(Button::Right),
+        4 => Some(Button::Back),
+        5 => Some(Button::Forward),
+        _ => None,
+    }
+}
+
+///优先使用跨平台键码，旧版本只带Windows虚拟键码
+pub fn to_code(key_data: &KeyEvent) -> Option<&str> {
+    if key_data.code.is_empty() {
+        keycode::from_vk(key_data.key as u16)
+    } else {
+        Some(key_data.code.as_str())
+    }
+}
+
+#[cfg(windows)]
+pub fn code_to_key(code: &str) -> Option<Key> {
+    keycode::to_vk(code).map(Key::Raw)
+}
+
+//xdo把一位数字当成keysym解析，Escape的键码9需要单独处理
+#[cfg(not(windows))]
+pub fn code_to_key(code: &str) -> Option<Key> {
+    match code {
+        "Escape" => Some(Key::Escape),
+        code => keycode::to_x11_keycode(code).map(|keycode| Key::Raw(keycode as u16)),
     }
 }
-// fn to_key(_key: u32) -> Key {
-//     let k = Key::from(1);
-// }
@@ -0,0 +1,249 @@
+//! 跨平台键码，使用W3C KeyboardEvent.code作为传输格式，
+//! 两端分别和Windows虚拟键码、X11 keysym、Linux evdev键码互相转换
+
+pub const MODIFIER_SHIFT: u32 = 1;
+pub const MODIFIER_CONTROL: u32 = 2;
+pub const MODIFIER_ALT: u32 = 4;
+pub const MODIFIER_META: u32 = 8;
+pub const MODIFIER_CAPS_LOCK: u32 = 16;
+pub const MODIFIER_NUM_LOCK: u32 = 32;
+
+struct KeyCode {
+    code: &'static str,
+    vk: u16,
+    keysym: u32,
+    evdev: u16,
+}
+
+macro_rules! key_codes {
+    ($(($code:expr, $vk:expr, $keysym:expr, $evdev:expr)),* $(,)?) => {
+        &[$(KeyCode { code: $code, vk: $vk, keysym: $keysym, evdev: $evdev }),*]
+    };
+}
+
+#[rustfmt::skip]
+static KEY_CODES: &[KeyCode] = key_codes![
+    ("Escape", 0x1B, 0xff1b, 1),
+    ("Digit1", 0x31, 0x31, 2),
+    ("Digit2", 0x32, 0x32, 3),
+    ("Digit3", 0x33, 0x33, 4),
+    ("Digit4", 0x34, 0x34, 5),
+    ("Digit5", 0x35, 0x35, 6),
+    ("Digit6", 0x36, 0x36, 7),
+    ("Digit7", 0x37, 0x37, 8),
+    ("Digit8", 0x38, 0x38, 9),
+    ("Digit9", 0x39, 0x39, 10),
+    ("Digit0", 0x30, 0x30, 11),
+    ("Minus", 0xBD, 0x2d, 12),
+    ("Equal", 0xBB, 0x3d, 13),
+    ("Backspace", 0x08, 0xff08, 14),
+    ("Tab", 0x09, 0xff09, 15),
+    ("KeyQ", 0x51, 0x71, 16),
+    ("KeyW", 0x57, 0x77, 17),
+    ("KeyE", 0x45, 0x65, 18),
+    ("KeyR", 0x52, 0x72, 19),
+    ("KeyT", 0x54, 0x74, 20),
+    ("KeyY", 0x59, 0x79, 21),
+    ("KeyU", 0x55, 0x75, 22),
+    ("KeyI", 0x49, 0x69, 23),
+    ("KeyO", 0x4F, 0x6f, 24),
+    ("KeyP", 0x50, 0x70, 25),
+    ("BracketLeft", 0xDB, 0x5b, 26),
+    ("BracketRight", 0xDD, 0x5d, 27),
+    ("Enter", 0x0D, 0xff0d, 28),
+    ("ControlLeft", 0xA2, 0xffe3, 29),
+    ("KeyA", 0x41, 0x61, 30),
+    ("KeyS", 0x53, 0x73, 31),
+    ("KeyD", 0x44, 0x64, 32),
+    ("KeyF", 0x46, 0x66, 33),
+    ("KeyG", 0x47, 0x67, 34),
+    ("KeyH", 0x48, 0x68, 35),
+    ("KeyJ", 0x4A, 0x6a, 36),
+    ("KeyK", 0x4B, 0x6b, 37),
+    ("KeyL", 0x4C, 0x6c, 38),
+    ("Semicolon", 0xBA, 0x3b, 39),
+    ("Quote", 0xDE, 0x27, 40),
+    ("Backquote", 0xC0, 0x60, 41),
+    ("ShiftLeft", 0xA0, 0xffe1, 42),
+    ("Backslash", 0xDC, 0x5c, 43),
+    ("KeyZ", 0x5A, 0x7a, 44),
+    ("KeyX", 0x58, 0x78, 45),
+    ("KeyC", 0x43, 0x63, 46),
+    ("KeyV", 0x56, 0x76, 47),
+    ("KeyB", 0x42, 0x62, 48),
+    ("KeyN", 0x4E, 0x6e, 49),
+    ("KeyM", 0x4D, 0x6d, 50),
+    ("Comma", 0xBC, 0x2c, 51),
+    ("Period", 0xBE, 0x2e, 52),
+    ("Slash", 0xBF, 0x2f, 53),
+    ("ShiftRight", 0xA1, 0xffe2, 54),
+    ("NumpadMultiply", 0x6A, 0xffaa, 55),
+    ("AltLeft", 0xA4, 0xffe9, 56),
+    ("Space", 0x20, 0x20, 57),
+    ("CapsLock", 0x14, 0xffe5, 58),
+    ("F1", 0x70, 0xffbe, 59),
+    ("F2", 0x71, 0xffbf, 60),
+    ("F3", 0x72, 0xffc0, 61),
+    ("F4", 0x73, 0xffc1, 62),
+    ("F5", 0x74, 0xffc2, 63),
+    ("F6", 0x75, 0xffc3, 64),
+    ("F7", 0x76, 0xffc4, 65),
+    ("F8", 0x77, 0xffc5, 66),
+    ("F9", 0x78, 0xffc6, 67),
+    ("F10", 0x79, 0xffc7, 68),
+    ("NumLock", 0x90, 0xff7f, 69),
+    ("ScrollLock", 0x91, 0xff14, 70),
+    ("Numpad7", 0x67, 0xffb7, 71),
+    ("Numpad8", 0x68, 0xffb8, 72),
+    ("Numpad9", 0x69, 0xffb9, 73),
+    ("NumpadSubtract", 0x6D, 0xffad, 74),
+    ("Numpad4", 0x64, 0xffb4, 75),
+    ("Numpad5", 0x65, 0xffb5, 76),
+    ("Numpad6", 0x66, 0xffb6, 77),
+    ("NumpadAdd", 0x6B, 0xffab, 78),
+    ("Numpad1", 0x61, 0xffb1, 79),
+    ("Numpad2", 0x62, 0xffb2, 80),
+    ("Numpad3", 0x63, 0xffb3, 81),
+    ("Numpad0", 0x60, 0xffb0, 82),
+    ("NumpadDecimal", 0x6E, 0xffae, 83),
+    ("IntlBackslash", 0xE2, 0x3c, 86),
+    ("F11", 0x7A, 0xffc8, 87),
+    ("F12", 0x7B, 0xffc9, 88),
+    ("NumpadEnter", 0x0D, 0xff8d, 96),
+    ("ControlRight", 0xA3, 0xffe4, 97),
+    ("NumpadDivide", 0x6F, 0xffaf, 98),
+    ("PrintScreen", 0x2C, 0xff61, 99),
+    ("AltRight", 0xA5, 0xffea, 100),
+    ("Home", 0x24, 0xff50, 102),
+    ("ArrowUp", 0x26, 0xff52, 103),
+    ("PageUp", 0x21, 0xff55, 104),
+    ("ArrowLeft", 0x25, 0xff51, 105),
+    ("ArrowRight", 0x27, 0xff53, 106),
+    ("End", 0x23, 0xff57, 107),
+    ("ArrowDown", 0x28, 0xff54, 108),
+    ("PageDown", 0x22, 0xff56, 109),
+    ("Insert", 0x2D, 0xff63, 110),
+    ("Delete", 0x2E, 0xffff, 111),
+    ("Pause", 0x13, 0xff13, 119),
+    ("MetaLeft", 0x5B, 0xffeb, 125),
+    ("MetaRight", 0x5C, 0xffec, 126),
+    ("ContextMenu", 0x5D, 0xff67, 127),
+];
+
+fn find<F: Fn(&KeyCode) -> bool>(f: F) -> Option<&'static KeyCode> {
+    KEY_CODES.iter().find(|key| f(key))
+}
+
+///输入字符的按键，文本模式下由输入法处理
+pub fn is_printable(code: &str) -> bool {
+    match code {
+        "Minus" | "Equal" | "BracketLeft" | "BracketRight" | "Backslash" | "Semicolon"
+        | "Quote" | "Backquote" | "Comma" | "Period" | "Slash" | "Space" | "IntlBackslash"
+        | "NumpadMultiply" | "NumpadSubtract" | "NumpadAdd" | "NumpadDecimal" | "NumpadDivide" => {
+            true
+        }
+        code => code.starts_with("Key") || code.starts_with("Digit") || is_numpad_digit(code),
+    }
+}
+fn is_numpad_digit(code: &str) -> bool {
+    code.len() == 7 && code.starts_with("Numpad") && code.as_bytes()[6].is_ascii_digit()
+}
+
+///解析组合键，如ControlLeft+KeyC，有未知的键时返回None
+pub fn parse_combo(combo: &str) -> Option<Vec<String>> {
+    let codes: Vec<String> = combo
+        .split('+')
+        .map(|code| code.trim())
+        .filter(|code| !code.is_empty())
+        .map(|code| find(|key| key.code == code).map(|key| key.code.to_string()))
+        .collect::<Option<_>>()?;
+    if codes.is_empty() {
+        return None;
+    }
+    Some(codes)
+}
+
+pub fn from_vk(vk: u16) -> Option<&'static str> {
+    //不区分左右的虚拟键码按左边处理
+    let vk = match vk {
+        0x10 => 0xA0,
+        0x11 => 0xA2,
+        0x12 => 0xA4,
+        vk => vk,
+    };
+    find(|key| key.vk == vk).map(|key| key.code)
+}
+pub fn to_vk(code: &str) -> Option<u16> {
+    find(|key| key.code == code).map(|key| key.vk)
+}
+pub fn from_keysym(keysym: u32) -> Option<&'static str> {
+    //大写字母和小写字母是同一个键
+    let keysym = match keysym {
+        0x41..=0x5a => keysym + 0x20,
+        //AltGr
+        0xfe03 => 0xffea,
+        keysym => keysym,
+    };
+    find(|key| key.keysym == keysym).map(|key| key.code)
+}
+pub fn to_keysym(code: &str) -> Option<u32> {
+    find(|key| key.code == code).map(|key| key.keysym)
+}
+pub fn from_evdev(evdev: u16) -> Option<&'static str> {
+    find(|key| key.evdev == evdev).map(|key| key.code)
+}
+pub fn to_evdev(code: &str) -> Option<u16> {
+    find(|key| key.code == code).map(|key| key.evdev)
+}
+///X11键码是evdev键码加8
+pub fn from_x11_keycode(keycode: u32) -> Option<&'static str> {
+    if keycode < 8 {
+        return None;
+    }
+    from_evdev((keycode - 8) as u16)
+}
+pub fn to_x11_keycode(code: &str) -> Option<u32> {
+    to_evdev(code).map(|evdev| evdev as u32 + 8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for key in KEY_CODES {
+            assert_eq!(to_evdev(key.code), Some(key.evdev));
+            assert_eq!(from_evdev(key.evdev), Some(key.code));
+            assert_eq!(from_keysym(key.keysym), Some(key.code));
+            assert_eq!(to_keysym(key.code), Some(key.keysym));
+            assert_eq!(to_vk(key.code), Some(key.vk));
+        }
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_eq!(from_vk(0x41), Some("KeyA"));
+        assert_eq!(from_vk(0x10), Some("ShiftLeft"));
+        assert_eq!(from_vk(0x0D), Some("Enter"));
+        assert_eq!(from_keysym(0x41), Some("KeyA"));
+        assert_eq!(to_x11_keycode("KeyA"), Some(38));
+        assert_eq!(from_x11_keycode(9), Some("Escape"));
+        assert_eq!(to_vk("Unknown"), None);
+        assert!(is_printable("KeyA"));
+        assert!(is_printable("Numpad5"));
+        assert!(!is_printable("NumpadEnter"));
+        assert!(!is_printable("ShiftLeft"));
+    }
+
+    #[test]
+    fn test_parse_combo() {
+        assert_eq!(
+            parse_combo("ControlLeft + KeyC"),
+            Some(vec![String::from("ControlLeft"), String::from("KeyC")])
+        );
+        assert_eq!(parse_combo("Enter"), Some(vec![String::from("Enter")]));
+        assert_eq!(parse_combo("ControlLeft+Ctrl"), None);
+        assert_eq!(parse_combo(" "), None);
+    }
+}
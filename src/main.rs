@@ -15,9 +15,15 @@ use crate::{config::Config, ui::{
 }};
 
 // pub mod x264_utils;
+pub mod audit;
+pub mod chat;
 pub mod client;
+pub mod clipboard;
 pub mod config;
+pub mod file_transfer;
 pub mod input_utils;
+pub mod keycode;
+pub mod port_forward;
 pub mod public;
 pub mod server;
 pub mod ui;
@@ -38,7 +44,9 @@ async fn main() {
         server_key,
         cert,
         pub_key,
+        Config::is_discoverable(),
         error_callback,
+        client::presence::update,
         server::remote_event_server::control_server_callback,
         client::remote_event_client::control_client_callback,
     );
@@ -0,0 +1,292 @@
+//! 端口转发，主控端和被控端共用。
+//! 每个TCP连接建立一个通道，第一个消息说明转发的目标，之后双向转发数据，
+//! 每条隧道记录活动连接数和收发的字节数
+
+use std::{
+    io::{ErrorKind, Read, Write},
+    net::{Shutdown, TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+    time::Duration,
+};
+
+use stream::{
+    message::{port_forward_message, ForwardResult, PortForwardMessage},
+    protobuf::Message,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    RemoteError, ResultType,
+};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+//监听时检查隧道是否关闭的间隔
+const ACCEPT_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    //主控端监听，被控端连接目标
+    Local,
+    //被控端监听，主控端连接目标
+    Remote,
+}
+
+pub struct Tunnel {
+    pub id: u32,
+    pub kind: Kind,
+    //监听的端口
+    pub listen: String,
+    //转发的目标
+    pub target: String,
+    connections: AtomicU32,
+    //本端TCP连接发给对方的字节数
+    sent: AtomicU64,
+    //对方发来、写入本端TCP连接的字节数
+    received: AtomicU64,
+    closed: AtomicBool,
+}
+
+///隧道状态，供界面显示
+#[derive(Debug, Clone)]
+pub struct TunnelInfo {
+    pub id: u32,
+    pub kind: Kind,
+    pub listen: String,
+    pub target: String,
+    pub connections: u32,
+    pub sent: u64,
+    pub received: u64,
+}
+
+lazy_static::lazy_static! {
+    static ref TUNNELS:RwLock<Vec<Arc<Tunnel>>> = RwLock::new(Vec::new());
+    static ref NEXT_ID:AtomicU32 = AtomicU32::new(1);
+}
+
+impl Tunnel {
+    pub fn new(kind: Kind, listen: String, target: String) -> Arc<Tunnel> {
+        Arc::new(Tunnel {
+            id: NEXT_ID.fetch_add(1, Ordering::SeqCst),
+            kind,
+            listen,
+            target,
+            connections: AtomicU32::new(0),
+            sent: AtomicU64::new(0),
+            received: AtomicU64::new(0),
+            closed: AtomicBool::new(false),
+        })
+    }
+    ///停止监听，已建立的连接继续到结束
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+    }
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::SeqCst)
+    }
+    pub fn info(&self) -> TunnelInfo {
+        TunnelInfo {
+            id: self.id,
+            kind: self.kind,
+            listen: self.listen.clone(),
+            target: self.target.clone(),
+            connections: self.connections.load(Ordering::SeqCst),
+            sent: self.sent.load(Ordering::SeqCst),
+            received: self.received.load(Ordering::SeqCst),
+        }
+    }
+}
+
+///加入隧道列表，列表中只有主控端建立的隧道
+pub fn register(tunnel: Arc<Tunnel>) {
+    TUNNELS.write().unwrap().push(tunnel);
+}
+pub fn tunnels() -> Vec<TunnelInfo> {
+    TUNNELS.read().unwrap().iter().map(|t| t.info()).collect()
+}
+pub fn find(id: u32) -> Option<Arc<Tunnel>> {
+    TUNNELS.read().unwrap().iter().find(|t| t.id == id).cloned()
+}
+///关闭隧道并移出列表
+pub fn close(id: u32) {
+    let mut tunnels = TUNNELS.write().unwrap();
+    if let Some(tunnel) = tunnels.iter().find(|t| t.id == id) {
+        tunnel.close();
+    }
+    tunnels.retain(|t| t.id != id);
+}
+///会话结束时关闭所有隧道
+pub fn close_all() {
+    let mut tunnels = TUNNELS.write().unwrap();
+    for tunnel in tunnels.iter() {
+        tunnel.close();
+    }
+    tunnels.clear();
+}
+
+///目标是否在允许列表中，列表项为 host:port，端口可以为*
+pub fn is_allowed(allowed: &[String], host: &str, port: u32) -> bool {
+    allowed.iter().any(|item| match item.rsplit_once(':') {
+        Some((allowed_host, allowed_port)) => {
+            allowed_host.eq_ignore_ascii_case(host)
+                && (allowed_port == "*" || allowed_port.parse::<u32>().ok() == Some(port))
+        }
+        None => false,
+    })
+}
+
+pub fn send(sender: &mut ChannelSender, msg: PortForwardMessage) -> ResultType<()> {
+    sender.send(msg.write_to_bytes()?)
+}
+pub fn send_result(sender: &mut ChannelSender, rs: Result<(), String>) -> ResultType<()> {
+    let mut msg = PortForwardMessage::new();
+    msg.set_result(ForwardResult {
+        ok: rs.is_ok(),
+        error: rs.err().unwrap_or_default(),
+        ..Default::default()
+    });
+    send(sender, msg)
+}
+pub fn recv(receiver: &ChannelReceiver) -> ResultType<port_forward_message::Union> {
+    let data = receiver.recv()?;
+    match PortForwardMessage::parse_from_bytes(&data)?.union {
+        Some(union) => Ok(union),
+        None => Err(RemoteError::InvalidData(String::from("空的端口转发消息")))?,
+    }
+}
+///等待对方返回的结果
+pub fn wait_result(receiver: &ChannelReceiver) -> ResultType<()> {
+    match recv(receiver)? {
+        port_forward_message::Union::result(result) if result.ok => Ok(()),
+        port_forward_message::Union::result(result) => Err(RemoteError::Peer(result.error))?,
+        _ => Err(RemoteError::InvalidData(String::from(
+            "端口转发需要先返回结果",
+        )))?,
+    }
+}
+
+pub fn connect(host: &str, port: u32) -> Result<TcpStream, String> {
+    if port == 0 || port > u16::MAX as u32 {
+        return Err(format!("非法的端口:{}", port));
+    }
+    let addrs = (host, port as u16)
+        .to_socket_addrs()
+        .map_err(|e| format!("无法解析地址:{},{}", host, e))?;
+    let mut error = format!("无法解析地址:{}", host);
+    for addr in addrs {
+        match TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => error = format!("连接{}失败:{}", addr, e),
+        }
+    }
+    Err(error)
+}
+
+///接受连接直到隧道关闭，每个连接在新线程中处理
+pub fn accept_loop<F>(listener: TcpListener, tunnel: Arc<Tunnel>, handle: F) -> ResultType<()>
+where
+    F: Fn(TcpStream) + Send + Sync + 'static,
+{
+    listener.set_nonblocking(true)?;
+    let handle = Arc::new(handle);
+    while !tunnel.is_closed() {
+        match listener.accept() {
+            Ok((stream, addr)) => {
+                log::info!("端口转发新连接:{},{}", tunnel.listen, addr);
+                stream.set_nonblocking(false)?;
+                let handle = handle.clone();
+                std::thread::spawn(move || handle(stream));
+            }
+            Err(e) if e.kind() == ErrorKind::WouldBlock => std::thread::sleep(ACCEPT_INTERVAL),
+            Err(e) => Err(e)?,
+        }
+    }
+    Ok(())
+}
+
+///双向转发直到两端都关闭
+pub fn pump(
+    stream: TcpStream,
+    sender: ChannelSender,
+    receiver: ChannelReceiver,
+    tunnel: Arc<Tunnel>,
+) -> ResultType<()> {
+    tunnel.connections.fetch_add(1, Ordering::SeqCst);
+    let rs = pump_(stream, sender, receiver, &tunnel);
+    tunnel.connections.fetch_sub(1, Ordering::SeqCst);
+    rs
+}
+
+fn pump_(
+    mut stream: TcpStream,
+    sender: ChannelSender,
+    receiver: ChannelReceiver,
+    tunnel: &Arc<Tunnel>,
+) -> ResultType<()> {
+    let mut reader = stream.try_clone()?;
+    let reader_tunnel = tunnel.clone();
+    let mut reader_sender = sender;
+    let read_thread = std::thread::spawn(move || {
+        let mut buf = vec![0; 32 * 1024];
+        loop {
+            let len = match reader.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(len) => len,
+            };
+            let mut msg = PortForwardMessage::new();
+            msg.set_data(buf[..len].to_vec());
+            if send(&mut reader_sender, msg).is_err() {
+                break;
+            }
+            reader_tunnel.sent.fetch_add(len as u64, Ordering::SeqCst);
+        }
+        let mut msg = PortForwardMessage::new();
+        msg.set_close(true);
+        let _ = send(&mut reader_sender, msg);
+    });
+    let mut shutdown = Shutdown::Both;
+    while let Ok(data) = receiver.recv() {
+        let msg = match PortForwardMessage::parse_from_bytes(&data) {
+            Ok(msg) => msg,
+            Err(_) => break,
+        };
+        match msg.union {
+            Some(port_forward_message::Union::data(data)) => {
+                if stream.write_all(&data).is_err() {
+                    break;
+                }
+                tunnel
+                    .received
+                    .fetch_add(data.len() as u64, Ordering::SeqCst);
+            }
+            //对方不会再发送，本端还可以继续发送到本端连接关闭
+            Some(port_forward_message::Union::close(_)) => {
+                shutdown = Shutdown::Write;
+                break;
+            }
+            _ => {}
+        }
+    }
+    let _ = stream.shutdown(shutdown);
+    let _ = read_thread.join();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_allowed() {
+        let allowed = vec![
+            String::from("127.0.0.1:8080"),
+            String::from("localhost:*"),
+            String::from("db.local:5432"),
+        ];
+        assert!(is_allowed(&allowed, "127.0.0.1", 8080));
+        assert!(!is_allowed(&allowed, "127.0.0.1", 8081));
+        assert!(is_allowed(&allowed, "LOCALHOST", 22));
+        assert!(is_allowed(&allowed, "db.local", 5432));
+        assert!(!is_allowed(&allowed, "10.0.0.1", 5432));
+        assert!(!is_allowed(&[], "127.0.0.1", 8080));
+    }
+}
@@ -1,4 +1,18 @@
+pub mod approval;
+pub mod audio_server;
+pub mod audio_source;
+pub mod chat_server;
+pub mod clipboard_server;
+pub mod display;
+pub mod file_browser_server;
+pub mod file_transfer_server;
+pub mod input_injector;
+pub mod media_clock;
 pub mod video_server;
 pub mod key_event_server;
+pub mod login_guard;
 pub mod remote_event_server;
+pub mod second_factor;
+pub mod terminal_server;
 pub mod mouse_event_server;
+pub mod port_forward_server;
@@ -0,0 +1,141 @@
+//! 被控端确认连接。主控端密码正确后，在被控端窗口显示对方的代号、公钥指纹和
+//! 密码对应的权限，用户可以去掉部分权限后接受，超时未确认按拒绝处理
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::{channel, Sender},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use stream::permission::Permissions;
+
+use crate::audit;
+
+//等待用户确认的时间
+pub const TIMEOUT: Duration = Duration::from_secs(30);
+
+///等待确认的连接，供界面显示
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub id: u64,
+    pub peer_id: String,
+    pub fingerprint: String,
+    //密码对应的权限，用户最多授予这些
+    pub permissions: Permissions,
+    //剩余的秒数
+    pub remaining: u64,
+}
+
+struct Pending {
+    id: u64,
+    peer_id: String,
+    fingerprint: String,
+    permissions: Permissions,
+    deadline: Instant,
+    sender: Sender<Option<Permissions>>,
+}
+
+lazy_static::lazy_static! {
+    static ref PENDING:Mutex<Vec<Pending>> = Mutex::new(Vec::new());
+    static ref NEXT_ID:AtomicU64 = AtomicU64::new(1);
+}
+
+///阻塞到用户确认或超时，返回授予的权限，拒绝或超时返回None
+pub fn ask(peer_id: &str, fingerprint: &str, permissions: Permissions) -> Option<Permissions> {
+    let answer = ask_timeout(peer_id, fingerprint, permissions, TIMEOUT);
+    let result = match answer {
+        Some(Some(granted)) => format!("accepted {}", granted.describe()),
+        Some(None) => String::from("denied"),
+        None => String::from("timeout"),
+    };
+    audit::record(
+        peer_id,
+        "approval",
+        &format!("fingerprint:{} {}", fingerprint, result),
+    );
+    answer.flatten()
+}
+
+//超时返回None
+fn ask_timeout(
+    peer_id: &str,
+    fingerprint: &str,
+    permissions: Permissions,
+    timeout: Duration,
+) -> Option<Option<Permissions>> {
+    let (sender, receiver) = channel();
+    let id = NEXT_ID.fetch_add(1, Ordering::SeqCst);
+    PENDING.lock().unwrap().push(Pending {
+        id,
+        peer_id: peer_id.to_string(),
+        fingerprint: fingerprint.to_string(),
+        permissions,
+        deadline: Instant::now() + timeout,
+        sender,
+    });
+    log::info!("等待确认连接:{},{}", peer_id, fingerprint);
+    let rs = receiver.recv_timeout(timeout).ok();
+    PENDING.lock().unwrap().retain(|p| p.id != id);
+    rs
+}
+
+///最早的一个等待确认的连接
+pub fn pending() -> Option<Request> {
+    PENDING.lock().unwrap().first().map(|p| Request {
+        id: p.id,
+        peer_id: p.peer_id.clone(),
+        fingerprint: p.fingerprint.clone(),
+        permissions: p.permissions,
+        remaining: p
+            .deadline
+            .saturating_duration_since(Instant::now())
+            .as_secs(),
+    })
+}
+
+///用户的选择，None为拒绝，已超时的请求忽略
+pub fn answer(id: u64, granted: Option<Permissions>) {
+    if let Some(p) = PENDING.lock().unwrap().iter().find(|p| p.id == id) {
+        //不能超出密码对应的权限，一项都没有时按拒绝处理
+        let granted = granted
+            .map(|granted| granted & p.permissions)
+            .filter(|granted| *granted != Permissions::NONE);
+        let _ = p.sender.send(granted);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer() {
+        let handle = std::thread::spawn(|| {
+            ask_timeout(
+                "123",
+                "AB:CD",
+                Permissions::VIEW_ONLY,
+                Duration::from_secs(5),
+            )
+        });
+        let request = loop {
+            if let Some(request) = pending().filter(|r| r.peer_id == "123") {
+                break request;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        };
+        assert_eq!(request.fingerprint, "AB:CD");
+        //超出密码对应的权限的部分不授予
+        answer(request.id, Some(Permissions::FULL));
+        assert_eq!(handle.join().unwrap(), Some(Some(Permissions::VIEW_ONLY)));
+        //超时
+        assert_eq!(
+            ask_timeout("456", "EF", Permissions::FULL, Duration::from_millis(50)),
+            None
+        );
+        assert!(pending().is_none());
+    }
+}
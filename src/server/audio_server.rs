@@ -0,0 +1,90 @@
+//! 采集被控端的声音，按20ms一帧Opus编码后发送
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use stream::{
+    message::{audio_message, AudioFormat, AudioFrame, AudioMessage},
+    protobuf::Message,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    RemoteError, ResultType,
+};
+
+use super::{
+    audio_source::{self, CHANNELS, SAMPLE_RATE},
+    media_clock,
+};
+
+//每帧每声道的采样数，20ms
+pub const FRAME_SAMPLES: usize = SAMPLE_RATE as usize / 50;
+const BITRATE: i32 = 96_000;
+
+fn opus_error(e: opus::Error) -> RemoteError {
+    RemoteError::InvalidData(format!("Opus编码失败:{}", e))
+}
+
+pub fn start(mut sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+    let mut source = audio_source::create()?;
+    log::info!("音频源:{}", source.name());
+    let mut encoder = opus::Encoder::new(
+        SAMPLE_RATE,
+        opus::Channels::Stereo,
+        opus::Application::Audio,
+    )
+    .map_err(opus_error)?;
+    encoder
+        .set_bitrate(opus::Bitrate::Bits(BITRATE))
+        .map_err(opus_error)?;
+    let mut msg = AudioMessage::new();
+    msg.set_format(AudioFormat {
+        sample_rate: SAMPLE_RATE,
+        channels: CHANNELS as u32,
+        ..Default::default()
+    });
+    sender.send(msg.write_to_bytes()?)?;
+
+    let enabled = Arc::new(AtomicBool::new(true));
+    let closed = Arc::new(AtomicBool::new(false));
+    {
+        let enabled = enabled.clone();
+        let closed = closed.clone();
+        std::thread::spawn(move || {
+            while let Ok(data) = receiver.recv() {
+                if let Ok(AudioMessage {
+                    union: Some(audio_message::Union::enabled(value)),
+                    ..
+                }) = AudioMessage::parse_from_bytes(&data)
+                {
+                    log::info!("主控端音频:{}", value);
+                    enabled.store(value, Ordering::SeqCst);
+                }
+            }
+            closed.store(true, Ordering::SeqCst);
+        });
+    }
+    let mut pcm = vec![0i16; FRAME_SAMPLES * CHANNELS];
+    let mut out = vec![0u8; 4000];
+    let mut seq = 0u32;
+    while !closed.load(Ordering::SeqCst) {
+        //关闭时也继续读取，避免恢复后发送积压的声音
+        source.read(&mut pcm)?;
+        //时间戳为这一帧开始的时间
+        let timestamp = media_clock::now().saturating_sub(20);
+        if !enabled.load(Ordering::SeqCst) {
+            continue;
+        }
+        let len = encoder.encode(&pcm, &mut out).map_err(opus_error)?;
+        let mut msg = AudioMessage::new();
+        msg.set_frame(AudioFrame {
+            data: out[..len].to_vec(),
+            timestamp,
+            seq,
+            ..Default::default()
+        });
+        sender.send(msg.write_to_bytes()?)?;
+        seq = seq.wrapping_add(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,223 @@
+//! 音频采集源，统一输出48kHz双声道交错的16位PCM
+
+use std::{
+    fs::File,
+    io::Read,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use stream::{RemoteError, ResultType};
+
+use crate::config::Config;
+
+pub const SAMPLE_RATE: u32 = 48000;
+pub const CHANNELS: usize = 2;
+
+pub trait AudioSource: Send {
+    fn name(&self) -> &'static str;
+    ///读满buf，阻塞到采集到足够的数据
+    fn read(&mut self, buf: &mut [i16]) -> ResultType<()>;
+}
+
+///按配置创建音频源
+pub fn create() -> ResultType<Box<dyn AudioSource>> {
+    let source = Config::get_audio_source();
+    if source == "tone" {
+        return Ok(Box::new(ToneSource::new(440.0)));
+    }
+    if let Some(path) = source.strip_prefix("file:") {
+        return Ok(Box::new(FileSource::new(PathBuf::from(path))?));
+    }
+    default_source()
+}
+
+#[cfg(target_os = "linux")]
+fn default_source() -> ResultType<Box<dyn AudioSource>> {
+    Ok(Box::new(PulseSource::new()?))
+}
+#[cfg(not(target_os = "linux"))]
+fn default_source() -> ResultType<Box<dyn AudioSource>> {
+    Err(RemoteError::InvalidData(String::from(
+        "当前平台没有默认的音频源",
+    )))?
+}
+
+//非实时的音频源按采样数等待，模拟实时采集
+struct Pacer {
+    start: Instant,
+    samples: u64,
+}
+
+impl Pacer {
+    fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            samples: 0,
+        }
+    }
+    fn wait(&mut self, samples: usize) {
+        self.samples += (samples / CHANNELS) as u64;
+        let target = Duration::from_micros(self.samples * 1_000_000 / SAMPLE_RATE as u64);
+        if let Some(wait) = target.checked_sub(self.start.elapsed()) {
+            std::thread::sleep(wait);
+        }
+    }
+}
+
+///正弦波测试音
+pub struct ToneSource {
+    freq: f32,
+    phase: f32,
+    pacer: Pacer,
+}
+
+impl ToneSource {
+    pub fn new(freq: f32) -> Self {
+        Self {
+            freq,
+            phase: 0.0,
+            pacer: Pacer::new(),
+        }
+    }
+    fn fill(&mut self, buf: &mut [i16]) {
+        let step = 2.0 * std::f32::consts::PI * self.freq / SAMPLE_RATE as f32;
+        for frame in buf.chunks_mut(CHANNELS) {
+            let value = (self.phase.sin() * i16::MAX as f32 * 0.3) as i16;
+            for sample in frame.iter_mut() {
+                *sample = value;
+            }
+            self.phase = (self.phase + step) % (2.0 * std::f32::consts::PI);
+        }
+    }
+}
+
+impl AudioSource for ToneSource {
+    fn name(&self) -> &'static str {
+        "tone"
+    }
+    fn read(&mut self, buf: &mut [i16]) -> ResultType<()> {
+        self.fill(buf);
+        self.pacer.wait(buf.len());
+        Ok(())
+    }
+}
+
+///循环播放48kHz双声道16位小端的原始PCM文件
+pub struct FileSource {
+    path: PathBuf,
+    file: File,
+    pacer: Pacer,
+}
+
+impl FileSource {
+    pub fn new(path: PathBuf) -> ResultType<Self> {
+        let file = File::open(&path)?;
+        Ok(Self {
+            path,
+            file,
+            pacer: Pacer::new(),
+        })
+    }
+}
+
+impl AudioSource for FileSource {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+    fn read(&mut self, buf: &mut [i16]) -> ResultType<()> {
+        let mut bytes = vec![0u8; buf.len() * 2];
+        let mut filled = 0;
+        let mut rewound = false;
+        while filled < bytes.len() {
+            let len = self.file.read(&mut bytes[filled..])?;
+            if len == 0 {
+                //空文件不能无限重来
+                if rewound {
+                    Err(RemoteError::InvalidData(format!(
+                        "音频文件为空:{:?}",
+                        self.path
+                    )))?;
+                }
+                self.file = File::open(&self.path)?;
+                rewound = true;
+                continue;
+            }
+            rewound = false;
+            filled += len;
+        }
+        for (sample, pair) in buf.iter_mut().zip(bytes.chunks_exact(2)) {
+            *sample = i16::from_le_bytes([pair[0], pair[1]]);
+        }
+        self.pacer.wait(buf.len());
+        Ok(())
+    }
+}
+
+///PulseAudio默认输出的监听源，PipeWire通过pipewire-pulse提供同样的接口
+#[cfg(target_os = "linux")]
+pub struct PulseSource {
+    simple: libpulse_simple_binding::Simple,
+}
+
+#[cfg(target_os = "linux")]
+impl PulseSource {
+    pub fn new() -> ResultType<Self> {
+        use libpulse_binding::{
+            sample::{Format, Spec},
+            stream::Direction,
+        };
+        let spec = Spec {
+            format: Format::S16le,
+            channels: CHANNELS as u8,
+            rate: SAMPLE_RATE,
+        };
+        let simple = libpulse_simple_binding::Simple::new(
+            None,
+            "onedesk",
+            Direction::Record,
+            Some("@DEFAULT_MONITOR@"),
+            "远程音频",
+            &spec,
+            None,
+            None,
+        )
+        .map_err(|e| RemoteError::InvalidData(format!("连接PulseAudio失败:{}", e)))?;
+        Ok(Self { simple })
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl AudioSource for PulseSource {
+    fn name(&self) -> &'static str {
+        "pulse"
+    }
+    fn read(&mut self, buf: &mut [i16]) -> ResultType<()> {
+        let mut bytes = vec![0u8; buf.len() * 2];
+        self.simple
+            .read(&mut bytes)
+            .map_err(|e| RemoteError::InvalidData(format!("采集音频失败:{}", e)))?;
+        for (sample, pair) in buf.iter_mut().zip(bytes.chunks_exact(2)) {
+            *sample = i16::from_le_bytes([pair[0], pair[1]]);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tone_source() {
+        let mut source = ToneSource::new(1000.0);
+        let mut buf = vec![0i16; 960 * CHANNELS];
+        let begin = Instant::now();
+        source.read(&mut buf).unwrap();
+        //20ms的数据按实时速度产生
+        assert!(begin.elapsed() >= Duration::from_millis(15));
+        assert!(buf.iter().any(|s| *s != 0));
+        //左右声道相同
+        assert!(buf.chunks(2).all(|frame| frame[0] == frame[1]));
+    }
+}
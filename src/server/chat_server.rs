@@ -0,0 +1,136 @@
+//! 被控端的聊天，无界面运行时可以通过set_listener把聊天写入审计日志。
+//! 每个会话的聊天各自独立，界面显示最近建立的会话
+
+use std::sync::{Arc, RwLock};
+
+use stream::{
+    remote_channel::{ChannelReceiver, ChannelSender},
+    RemoteError, ResultType,
+};
+
+use crate::{
+    audit,
+    chat::{Chat, Entry},
+};
+
+lazy_static::lazy_static! {
+    //按建立的顺序排列，bool为通道是否还在，结束的会话保留到下一个会话建立
+    static ref CHATS:RwLock<Vec<(Arc<Chat>, bool)>> = RwLock::new(Vec::new());
+    static ref LISTENER:RwLock<Option<fn(&str, &Entry)>> = RwLock::new(None);
+}
+
+///peer_id为建立该通道的会话登录成功的主控端
+pub fn start(sender: ChannelSender, receiver: ChannelReceiver, peer_id: &str) -> ResultType<()> {
+    let chat = Arc::new(Chat::with_peer(peer_id));
+    chat.set_listener(*LISTENER.read().unwrap());
+    {
+        let mut chats = CHATS.write().unwrap();
+        chats.retain(|(_, running)| *running);
+        chats.push((chat.clone(), true));
+    }
+    let rs = chat.run(sender, receiver);
+    let mut chats = CHATS.write().unwrap();
+    if let Some(item) = chats.iter_mut().find(|(c, _)| Arc::ptr_eq(c, &chat)) {
+        item.1 = false;
+    }
+    rs
+}
+///收发消息时调用，None为取消
+pub fn set_listener(listener: Option<fn(&str, &Entry)>) {
+    *LISTENER.write().unwrap() = listener;
+    for (chat, _) in CHATS.read().unwrap().iter() {
+        chat.set_listener(listener);
+    }
+}
+///把聊天写入审计日志，可以作为set_listener的参数
+pub fn audit_listener(peer_id: &str, entry: &Entry) {
+    let event = if entry.outgoing {
+        "chat_sent"
+    } else {
+        "chat_received"
+    };
+    audit::record(peer_id, event, &entry.text);
+}
+//最近建立的还在进行的会话，都已结束时为最后一个
+fn current() -> Option<Arc<Chat>> {
+    let chats = CHATS.read().unwrap();
+    chats
+        .iter()
+        .rev()
+        .find(|(_, running)| *running)
+        .or_else(|| chats.last())
+        .map(|(chat, _)| chat.clone())
+}
+pub fn send(text: &str) -> ResultType<u64> {
+    match current() {
+        Some(chat) => chat.send(text),
+        None => Err(RemoteError::Channel(String::from("聊天通道未建立")))?,
+    }
+}
+pub fn history() -> Vec<Entry> {
+    current().map(|chat| chat.history()).unwrap_or_default()
+}
+pub fn is_connected() -> bool {
+    current().map(|chat| chat.is_connected()).unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use stream::{
+        message::{ChatMessage, ChatText},
+        protobuf::Message,
+        remote_channel::local_channel,
+    };
+
+    use super::*;
+
+    fn text(id: u64, text: &str) -> Vec<u8> {
+        let mut msg = ChatMessage::new();
+        msg.set_text(ChatText {
+            id,
+            text: text.to_string(),
+            ..Default::default()
+        });
+        msg.write_to_bytes().unwrap()
+    }
+
+    fn wait_for(check: impl Fn() -> bool) {
+        for _ in 0..200 {
+            if check() {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        panic!("等待超时");
+    }
+
+    #[test]
+    fn test_sessions() {
+        let (sender_a, _sent_a, inbound_a, receiver_a) = local_channel(1);
+        let a = std::thread::spawn(move || start(sender_a, receiver_a, "111"));
+        inbound_a.send(text(1, "来自A")).unwrap();
+        wait_for(|| history().len() == 1);
+
+        //第二个会话不影响第一个会话的记录
+        let (sender_b, _sent_b, inbound_b, receiver_b) = local_channel(2);
+        let b = std::thread::spawn(move || start(sender_b, receiver_b, "222"));
+        wait_for(|| CHATS.read().unwrap().len() == 2);
+        inbound_b.send(text(1, "来自B")).unwrap();
+        wait_for(|| history().len() == 1 && history()[0].text == "来自B");
+        let first = CHATS.read().unwrap()[0].0.history();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].text, "来自A");
+
+        //先结束的会话不断开另一个会话
+        drop(inbound_a);
+        a.join().unwrap().unwrap();
+        assert!(is_connected());
+        assert_eq!(history()[0].text, "来自B");
+        drop(inbound_b);
+        b.join().unwrap().unwrap();
+        assert!(!is_connected());
+        assert_eq!(history()[0].text, "来自B");
+    }
+}
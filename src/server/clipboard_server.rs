@@ -0,0 +1,13 @@
+use std::sync::{atomic::AtomicBool, Arc};
+
+use stream::{
+    remote_channel::{ChannelReceiver, ChannelSender},
+    ResultType,
+};
+
+use crate::clipboard;
+
+///被控端始终开启，是否同步由主控端的开关决定
+pub fn start(sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+    clipboard::start(sender, receiver, Arc::new(AtomicBool::new(true)))
+}
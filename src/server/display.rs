@@ -0,0 +1,382 @@
+use std::sync::RwLock;
+
+use stream::{
+    message::{DisplayInfo, DisplayList},
+    ResultType,
+};
+
+///所有显示器合成画面
+pub const ALL_DISPLAYS: i32 = -1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    //包含两个区域的最小区域
+    pub fn union(&self, other: &Rect) -> Rect {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = (self.x + self.width as i32).max(other.x + other.width as i32);
+        let bottom = (self.y + self.height as i32).max(other.y + other.height as i32);
+        Rect {
+            x,
+            y,
+            width: (right - x) as u32,
+            height: (bottom - y) as u32,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DisplayGeometry {
+    pub id: i32,
+    pub name: String,
+    pub rect: Rect,
+    pub primary: bool,
+    //所在的scrap::Display下标，X11下一个屏幕可能包含多个显示器
+    pub screen: usize,
+    //在该屏幕采集画面中的区域，None为整个画面
+    pub crop: Option<Rect>,
+}
+
+///当前正在采集的显示器
+#[derive(Debug, Clone, Copy)]
+pub struct Capture {
+    pub id: i32,
+    //采集区域（虚拟桌面坐标）
+    pub rect: Rect,
+    //编码后的画面大小，缩放时和采集区域不同
+    pub frame_width: u32,
+    pub frame_height: u32,
+}
+
+lazy_static::lazy_static! {
+    //鼠标事件据此换算坐标
+    static ref CURRENT: RwLock<Option<Capture>> = RwLock::new(None);
+}
+
+pub fn set_current(id: i32, rect: Rect, frame_width: u32, frame_height: u32) {
+    *CURRENT.write().unwrap() = Some(Capture {
+        id,
+        rect,
+        frame_width,
+        frame_height,
+    });
+}
+pub fn clear_current() {
+    *CURRENT.write().unwrap() = None;
+}
+pub fn current() -> Option<Capture> {
+    *CURRENT.read().unwrap()
+}
+
+///把相对于当前视频画面的坐标换算成虚拟桌面坐标
+pub fn to_screen(x: i32, y: i32) -> (i32, i32) {
+    match current() {
+        Some(capture) if capture.frame_width > 0 && capture.frame_height > 0 => (
+            capture.rect.x
+                + (x as i64 * capture.rect.width as i64 / capture.frame_width as i64) as i32,
+            capture.rect.y
+                + (y as i64 * capture.rect.height as i64 / capture.frame_height as i64) as i32,
+        ),
+        _ => (x, y),
+    }
+}
+
+///把归一化坐标换算成虚拟桌面坐标。
+///显示器区域和注入输入使用同一坐标系，缩放比例（HiDPI）不影响结果
+pub fn normalized_to_screen(display_id: i32, x: f32, y: f32) -> (i32, i32) {
+    map_normalized(&display_rect(display_id), x, y)
+}
+
+fn map_normalized(rect: &Rect, x: f32, y: f32) -> (i32, i32) {
+    let x = x.max(0.0).min(1.0);
+    let y = y.max(0.0).min(1.0);
+    (
+        rect.x + (rect.width.saturating_sub(1) as f32 * x).round() as i32,
+        rect.y + (rect.height.saturating_sub(1) as f32 * y).round() as i32,
+    )
+}
+
+//主控端的画面可能还是切换前的显示器，按id重新查找
+fn display_rect(display_id: i32) -> Rect {
+    let capture = current();
+    if let Some(capture) = capture {
+        if capture.id == display_id {
+            return capture.rect;
+        }
+    }
+    let displays = all().unwrap_or_default();
+    if display_id == ALL_DISPLAYS {
+        return bounding_rect(&displays);
+    }
+    displays
+        .iter()
+        .find(|display| display.id == display_id)
+        .map(|display| display.rect)
+        .or_else(|| capture.map(|capture| capture.rect))
+        .unwrap_or_default()
+}
+
+///把视频画面上的位移换算成桌面上的位移
+pub fn to_screen_delta(dx: i32, dy: i32) -> (i32, i32) {
+    match current() {
+        Some(capture) if capture.frame_width > 0 && capture.frame_height > 0 => (
+            (dx as i64 * capture.rect.width as i64 / capture.frame_width as i64) as i32,
+            (dy as i64 * capture.rect.height as i64 / capture.frame_height as i64) as i32,
+        ),
+        _ => (dx, dy),
+    }
+}
+
+///枚举显示器，按设备名称(Windows)或所在屏幕(X11)和scrap::Display对应，id即为下标
+pub fn all() -> ResultType<Vec<DisplayGeometry>> {
+    let displays = scrap::Display::all()?;
+    let mut list = monitors(displays.len());
+    for (screen, display) in displays.iter().enumerate() {
+        if list.iter().any(|monitor| monitor.screen == screen) {
+            continue;
+        }
+        //取不到系统信息时按单个显示器处理
+        list.push(DisplayGeometry {
+            id: 0,
+            name: format!("display{}", screen),
+            rect: Rect {
+                x: 0,
+                y: 0,
+                width: display.width() as u32,
+                height: display.height() as u32,
+            },
+            primary: screen == 0,
+            screen,
+            crop: None,
+        });
+    }
+    list.sort_by_key(|display| display.screen);
+    for (index, display) in list.iter_mut().enumerate() {
+        display.id = index as i32;
+    }
+    Ok(list)
+}
+
+///所有显示器合成后的区域
+pub fn bounding_rect(displays: &[DisplayGeometry]) -> Rect {
+    let mut iter = displays.iter();
+    let first = match iter.next() {
+        Some(display) => display.rect,
+        None => return Rect::default(),
+    };
+    iter.fold(first, |rect, display| rect.union(&display.rect))
+}
+
+pub fn to_display_list(displays: &[DisplayGeometry], current: i32) -> DisplayList {
+    DisplayList {
+        displays: displays
+            .iter()
+            .map(|display| DisplayInfo {
+                id: display.id,
+                name: display.name.clone(),
+                x: display.rect.x,
+                y: display.rect.y,
+                width: display.rect.width,
+                height: display.rect.height,
+                primary: display.primary,
+                ..Default::default()
+            })
+            .collect(),
+        current,
+        ..Default::default()
+    }
+}
+
+//scrap按DXGI输出采集，用设备名称找到对应的输出
+#[cfg(windows)]
+fn monitors(_screens: usize) -> Vec<DisplayGeometry> {
+    use winapi::shared::minwindef::{BOOL, LPARAM, TRUE};
+    use winapi::shared::windef::{HDC, HMONITOR, LPRECT};
+    use winapi::um::winuser;
+
+    unsafe extern "system" fn callback(
+        monitor: HMONITOR,
+        _hdc: HDC,
+        _rect: LPRECT,
+        data: LPARAM,
+    ) -> BOOL {
+        let list = &mut *(data as *mut Vec<DisplayGeometry>);
+        let mut info: winuser::MONITORINFOEXW = std::mem::zeroed();
+        info.cbSize = std::mem::size_of::<winuser::MONITORINFOEXW>() as u32;
+        if winuser::GetMonitorInfoW(monitor, &mut info as *mut _ as *mut _) != 0 {
+            let rc = info.rcMonitor;
+            let len = info
+                .szDevice
+                .iter()
+                .position(|c| *c == 0)
+                .unwrap_or(info.szDevice.len());
+            list.push(DisplayGeometry {
+                id: list.len() as i32,
+                name: String::from_utf16_lossy(&info.szDevice[..len]),
+                rect: Rect {
+                    x: rc.left,
+                    y: rc.top,
+                    width: (rc.right - rc.left) as u32,
+                    height: (rc.bottom - rc.top) as u32,
+                },
+                primary: info.dwFlags & winuser::MONITORINFOF_PRIMARY != 0,
+                screen: 0,
+                crop: None,
+            });
+        }
+        TRUE
+    }
+    let mut list: Vec<DisplayGeometry> = Vec::new();
+    unsafe {
+        winuser::EnumDisplayMonitors(
+            std::ptr::null_mut(),
+            std::ptr::null(),
+            Some(callback),
+            &mut list as *mut _ as LPARAM,
+        );
+    }
+    //和scrap::Display::all()的枚举顺序相同
+    let names: Vec<String> = match scrap::dxgi::Displays::new() {
+        Ok(outputs) => outputs
+            .map(|output| String::from_utf16_lossy(output.name()))
+            .collect(),
+        Err(_) => return Vec::new(),
+    };
+    list.into_iter()
+        .filter_map(|mut monitor| {
+            monitor.screen = names.iter().position(|name| *name == monitor.name)?;
+            Some(monitor)
+        })
+        .collect()
+}
+
+//X11下scrap按屏幕采集，用XRandR取每个屏幕上的显示器，采集时从屏幕画面中裁出
+#[cfg(target_os = "linux")]
+fn monitors(screens: usize) -> Vec<DisplayGeometry> {
+    use std::{ffi::CStr, os::raw::c_int, ptr::null};
+    use x11::{xlib, xrandr};
+
+    let mut list = Vec::new();
+    unsafe {
+        let dpy = xlib::XOpenDisplay(null());
+        if dpy.is_null() {
+            return list;
+        }
+        //XRRGetMonitors需要RandR 1.5，服务器不支持时调用会导致X错误
+        let (mut major, mut minor): (c_int, c_int) = (0, 0);
+        if xrandr::XRRQueryVersion(dpy, &mut major, &mut minor) == 0 || (major, minor) < (1, 5) {
+            xlib::XCloseDisplay(dpy);
+            return list;
+        }
+        for screen in 0..screens.min(xlib::XScreenCount(dpy) as usize) {
+            let root = xlib::XRootWindow(dpy, screen as c_int);
+            let mut count = 0;
+            let monitors = xrandr::XRRGetMonitors(dpy, root, xlib::True, &mut count);
+            if monitors.is_null() {
+                continue;
+            }
+            for monitor in std::slice::from_raw_parts(monitors, count as usize) {
+                let atom = xlib::XGetAtomName(dpy, monitor.name);
+                let name = if atom.is_null() {
+                    format!("monitor{}", list.len())
+                } else {
+                    let name = CStr::from_ptr(atom).to_string_lossy().into_owned();
+                    xlib::XFree(atom as *mut _);
+                    name
+                };
+                //屏幕画面的坐标和桌面坐标相同
+                let rect = Rect {
+                    x: monitor.x,
+                    y: monitor.y,
+                    width: monitor.width as u32,
+                    height: monitor.height as u32,
+                };
+                list.push(DisplayGeometry {
+                    id: list.len() as i32,
+                    name,
+                    rect,
+                    primary: monitor.primary != 0,
+                    screen,
+                    crop: Some(rect),
+                });
+            }
+            xrandr::XRRFreeMonitors(monitors);
+        }
+        xlib::XCloseDisplay(dpy);
+    }
+    list
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+fn monitors(_screens: usize) -> Vec<DisplayGeometry> {
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounding_rect() {
+        let displays = vec![
+            DisplayGeometry {
+                id: 0,
+                name: String::from("a"),
+                rect: Rect {
+                    x: 0,
+                    y: 0,
+                    width: 1920,
+                    height: 1080,
+                },
+                primary: true,
+                screen: 0,
+                crop: None,
+            },
+            DisplayGeometry {
+                id: 1,
+                name: String::from("b"),
+                rect: Rect {
+                    x: -1280,
+                    y: 200,
+                    width: 1280,
+                    height: 1024,
+                },
+                primary: false,
+                screen: 1,
+                crop: None,
+            },
+        ];
+        assert_eq!(
+            bounding_rect(&displays),
+            Rect {
+                x: -1280,
+                y: 0,
+                width: 3200,
+                height: 1224,
+            }
+        );
+        assert_eq!(bounding_rect(&[]), Rect::default());
+    }
+
+    #[test]
+    fn test_map_normalized() {
+        //150%缩放的副屏，画面是物理像素，区域是逻辑坐标
+        let rect = Rect {
+            x: -1280,
+            y: 200,
+            width: 1280,
+            height: 720,
+        };
+        assert_eq!(map_normalized(&rect, 0.0, 0.0), (-1280, 200));
+        assert_eq!(map_normalized(&rect, 1.0, 1.0), (-1, 919));
+        assert_eq!(map_normalized(&rect, 0.5, 0.5), (-640, 560));
+        assert_eq!(map_normalized(&rect, -0.2, 1.5), (-1280, 919));
+    }
+}
@@ -0,0 +1,316 @@
+//! 文件浏览，处理主控端的列目录、stat、新建目录、重命名和删除请求。
+//! 所有路径必须在允许的根目录内，只读模式下拒绝修改
+
+use std::{
+    fs::{self, Metadata},
+    path::{Component, Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use stream::{
+    message::{
+        file_browser_request, file_entry, FileBrowserRequest, FileBrowserResponse, FileEntry,
+    },
+    protobuf::Message,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    ResultType,
+};
+
+use crate::config::Config;
+
+pub struct Browser {
+    roots: Vec<PathBuf>,
+    read_only: bool,
+}
+
+impl Browser {
+    pub fn new(roots: Vec<PathBuf>, read_only: bool) -> Self {
+        //不存在的根目录忽略
+        let roots = roots
+            .into_iter()
+            .filter_map(|root| root.canonicalize().ok())
+            .collect();
+        Self { roots, read_only }
+    }
+
+    pub fn handle(&self, request: FileBrowserRequest) -> FileBrowserResponse {
+        let mut response = FileBrowserResponse {
+            id: request.id,
+            read_only: self.read_only,
+            ..Default::default()
+        };
+        let rs = match request.union {
+            Some(file_browser_request::Union::roots(_)) => Ok(self.roots()),
+            Some(file_browser_request::Union::list_dir(path)) => self.list_dir(&path),
+            Some(file_browser_request::Union::stat(path)) => self.stat(&path),
+            Some(file_browser_request::Union::mkdir(path)) => self.mkdir(&path),
+            Some(file_browser_request::Union::rename(rename)) => {
+                self.rename(&rename.from, &rename.to)
+            }
+            Some(file_browser_request::Union::remove(remove)) => {
+                self.remove(&remove.path, remove.recursive)
+            }
+            None => Err(String::from("未知的请求")),
+        };
+        match rs {
+            Ok(entries) => response.entries = entries,
+            Err(e) => response.error = e,
+        }
+        response
+    }
+
+    fn roots(&self) -> Vec<FileEntry> {
+        self.roots
+            .iter()
+            .filter_map(|root| {
+                let metadata = fs::metadata(root).ok()?;
+                Some(entry(root.to_string_lossy().to_string(), &metadata))
+            })
+            .collect()
+    }
+
+    fn list_dir(&self, path: &str) -> Result<Vec<FileEntry>, String> {
+        let dir = self.resolve(path)?;
+        let mut entries = Vec::new();
+        for item in fs::read_dir(&dir).map_err(|e| e.to_string())? {
+            let item = item.map_err(|e| e.to_string())?;
+            //符号链接本身的信息，不跟随
+            if let Ok(metadata) = item.path().symlink_metadata() {
+                entries.push(entry(
+                    item.file_name().to_string_lossy().to_string(),
+                    &metadata,
+                ));
+            }
+        }
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(entries)
+    }
+
+    fn stat(&self, path: &str) -> Result<Vec<FileEntry>, String> {
+        let path = self.resolve(path)?;
+        let metadata = fs::metadata(&path).map_err(|e| e.to_string())?;
+        Ok(vec![entry(path.to_string_lossy().to_string(), &metadata)])
+    }
+
+    fn mkdir(&self, path: &str) -> Result<Vec<FileEntry>, String> {
+        self.check_writable()?;
+        let path = self.resolve_new(path)?;
+        fs::create_dir(&path).map_err(|e| e.to_string())?;
+        log::info!("文件浏览新建目录:{:?}", path);
+        Ok(Vec::new())
+    }
+
+    fn rename(&self, from: &str, to: &str) -> Result<Vec<FileEntry>, String> {
+        self.check_writable()?;
+        let from = self.resolve_child(from)?;
+        let to = self.resolve_new(to)?;
+        //目标是失效的符号链接时也不能覆盖
+        if fs::symlink_metadata(&to).is_ok() {
+            return Err(format!("目标已存在:{:?}", to));
+        }
+        fs::rename(&from, &to).map_err(|e| e.to_string())?;
+        log::info!("文件浏览重命名:{:?} -> {:?}", from, to);
+        Ok(Vec::new())
+    }
+
+    fn remove(&self, path: &str, recursive: bool) -> Result<Vec<FileEntry>, String> {
+        self.check_writable()?;
+        let path = self.resolve_child(path)?;
+        //指向目录的符号链接只删除链接
+        let is_dir = fs::symlink_metadata(&path)
+            .map(|metadata| metadata.is_dir())
+            .unwrap_or(false);
+        let rs = if !is_dir {
+            fs::remove_file(&path)
+        } else if recursive {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_dir(&path)
+        };
+        rs.map_err(|e| e.to_string())?;
+        log::info!("文件浏览删除:{:?}", path);
+        Ok(Vec::new())
+    }
+
+    fn check_writable(&self) -> Result<(), String> {
+        if self.read_only {
+            return Err(String::from("被控端为只读模式"));
+        }
+        Ok(())
+    }
+
+    fn is_allowed(&self, path: &Path) -> bool {
+        self.roots.iter().any(|root| path.starts_with(root))
+    }
+
+    ///已存在的路径，解析符号链接后必须在根目录内
+    fn resolve(&self, path: &str) -> Result<PathBuf, String> {
+        let resolved = Path::new(path)
+            .canonicalize()
+            .map_err(|e| format!("路径不存在:{},{}", path, e))?;
+        if !self.is_allowed(&resolved) {
+            return Err(format!("不允许访问:{}", path));
+        }
+        Ok(resolved)
+    }
+
+    ///根目录以内、不能是根目录本身的已存在路径，用于重命名和删除。
+    ///只解析上级目录，最后一级是符号链接时操作链接本身
+    fn resolve_child(&self, path: &str) -> Result<PathBuf, String> {
+        let resolved = self.resolve_new(path)?;
+        if self.roots.contains(&resolved) {
+            return Err(format!("不能修改根目录:{}", path));
+        }
+        fs::symlink_metadata(&resolved).map_err(|e| format!("路径不存在:{},{}", path, e))?;
+        Ok(resolved)
+    }
+
+    ///将要创建的路径，上级目录必须在根目录内
+    fn resolve_new(&self, path: &str) -> Result<PathBuf, String> {
+        let path = Path::new(path);
+        let name = match path.components().last() {
+            Some(Component::Normal(name)) => name.to_owned(),
+            _ => return Err(format!("非法的路径:{:?}", path)),
+        };
+        let parent = path
+            .parent()
+            .ok_or_else(|| format!("非法的路径:{:?}", path))?;
+        let parent = self.resolve(&parent.to_string_lossy())?;
+        Ok(parent.join(name))
+    }
+}
+
+fn entry(name: String, metadata: &Metadata) -> FileEntry {
+    let file_type = metadata.file_type();
+    let entry_type = if file_type.is_symlink() {
+        file_entry::Type::Symlink
+    } else if file_type.is_dir() {
+        file_entry::Type::Dir
+    } else if file_type.is_file() {
+        file_entry::Type::File
+    } else {
+        file_entry::Type::Other
+    };
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |time| time.as_secs());
+    FileEntry {
+        name,
+        size: metadata.len(),
+        modified,
+        entry_type: stream::protobuf::ProtobufEnumOrUnknown::new(entry_type),
+        ..Default::default()
+    }
+}
+
+pub fn start(mut sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+    let browser = Browser::new(
+        Config::get_file_browser_roots(),
+        Config::is_file_browser_read_only(),
+    );
+    while let Ok(data) = receiver.recv() {
+        let request = FileBrowserRequest::parse_from_bytes(&data)?;
+        let response = browser.handle(request);
+        sender.send(response.write_to_bytes()?)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(path: &Path) -> FileBrowserRequest {
+        let mut request = FileBrowserRequest::new();
+        request.set_list_dir(path.to_string_lossy().to_string());
+        request
+    }
+
+    fn mkdir(path: &Path) -> FileBrowserRequest {
+        let mut request = FileBrowserRequest::new();
+        request.set_mkdir(path.to_string_lossy().to_string());
+        request
+    }
+
+    #[test]
+    fn test_browser() {
+        let root = std::env::temp_dir().join(format!("onedesk-fb-{}", std::process::id()));
+        let allowed = root.join("allowed");
+        let other = root.join("other");
+        fs::create_dir_all(&allowed).unwrap();
+        fs::create_dir_all(&other).unwrap();
+        fs::write(allowed.join("a.txt"), b"abc").unwrap();
+
+        let browser = Browser::new(vec![allowed.clone()], false);
+        let response = browser.handle(list(&allowed));
+        assert!(response.error.is_empty());
+        assert_eq!(response.entries.len(), 1);
+        assert_eq!(response.entries[0].name, "a.txt");
+        assert_eq!(response.entries[0].size, 3);
+        //根目录以外的路径
+        assert!(!browser.handle(list(&other)).error.is_empty());
+        assert!(!browser
+            .handle(list(&allowed.join("..").join("other")))
+            .error
+            .is_empty());
+        assert!(!browser.handle(mkdir(&other.join("new"))).error.is_empty());
+        assert!(browser.handle(mkdir(&allowed.join("new"))).error.is_empty());
+        assert!(allowed.join("new").is_dir());
+        //根目录本身不能删除
+        let mut request = FileBrowserRequest::new();
+        request.set_remove(stream::message::RemoveFile {
+            path: allowed.to_string_lossy().to_string(),
+            recursive: true,
+            ..Default::default()
+        });
+        assert!(!browser.handle(request).error.is_empty());
+
+        let read_only = Browser::new(vec![allowed.clone()], true);
+        assert!(read_only.handle(list(&allowed)).error.is_empty());
+        assert!(!read_only
+            .handle(mkdir(&allowed.join("new2")))
+            .error
+            .is_empty());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink() {
+        let root = std::env::temp_dir().join(format!("onedesk-fb-link-{}", std::process::id()));
+        let allowed = root.join("allowed");
+        let other = root.join("other");
+        fs::create_dir_all(&allowed).unwrap();
+        fs::create_dir_all(&other).unwrap();
+        fs::write(other.join("secret.txt"), b"abc").unwrap();
+        std::os::unix::fs::symlink(&other, allowed.join("dir-link")).unwrap();
+        std::os::unix::fs::symlink(other.join("secret.txt"), allowed.join("file-link")).unwrap();
+        let browser = Browser::new(vec![allowed.clone()], false);
+
+        //重命名的是链接本身，目标不动
+        let mut request = FileBrowserRequest::new();
+        request.set_rename(stream::message::RenameFile {
+            from: allowed.join("file-link").to_string_lossy().to_string(),
+            to: allowed.join("renamed").to_string_lossy().to_string(),
+            ..Default::default()
+        });
+        assert!(browser.handle(request).error.is_empty());
+        assert!(fs::symlink_metadata(allowed.join("renamed")).is_ok());
+        assert!(other.join("secret.txt").exists());
+        //删除链接不影响根目录以外的文件
+        for name in &["renamed", "dir-link"] {
+            let mut request = FileBrowserRequest::new();
+            request.set_remove(stream::message::RemoveFile {
+                path: allowed.join(name).to_string_lossy().to_string(),
+                recursive: true,
+                ..Default::default()
+            });
+            assert!(browser.handle(request).error.is_empty());
+            assert!(fs::symlink_metadata(allowed.join(name)).is_err());
+        }
+        assert_eq!(fs::read(other.join("secret.txt")).unwrap(), b"abc");
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
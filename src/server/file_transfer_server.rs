@@ -0,0 +1,24 @@
+use std::path::PathBuf;
+
+use stream::{
+    remote_channel::{ChannelReceiver, ChannelSender},
+    ResultType,
+};
+
+use crate::{
+    config::Config,
+    file_transfer::{Policy, Session},
+};
+
+///被控端只在配置的目录内接收和发送文件
+pub fn start(sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+    let dirs = Config::get_file_transfer_dirs();
+    let default_dir = dirs.first().cloned().unwrap_or_else(|| PathBuf::from("."));
+    let policy = Policy {
+        allowed_dirs: Some(dirs),
+        default_dir,
+        accept_offers: true,
+        serve_requests: true,
+    };
+    Session::new(sender, policy).run(receiver)
+}
@@ -0,0 +1,249 @@
+use std::{collections::HashSet, error::Error, fmt};
+
+use enigo::{KeyboardControllable, MouseControllable};
+use stream::{
+    message::{input_status, InputStatus},
+    protobuf::Message,
+    remote_channel::ChannelSender,
+    ResultType,
+};
+
+use crate::input_utils;
+
+#[cfg(target_os = "linux")]
+mod uinput;
+#[cfg(target_os = "linux")]
+mod xtest;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Button {
+    Left,
+    Middle,
+    Right,
+    Back,
+    Forward,
+}
+
+#[derive(Debug)]
+pub enum InjectError {
+    Unsupported(String),
+    PermissionDenied(String),
+    Failed(String),
+}
+
+impl fmt::Display for InjectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InjectError::Unsupported(e) => write!(f, "Unsupported {}", e),
+            InjectError::PermissionDenied(e) => write!(f, "PermissionDenied {}", e),
+            InjectError::Failed(e) => write!(f, "Failed {}", e),
+        }
+    }
+}
+impl Error for InjectError {}
+
+impl InjectError {
+    pub fn to_status(&self, backend: &str) -> InputStatus {
+        let (code, message) = match self {
+            InjectError::Unsupported(e) => (input_status::Code::Unsupported, e),
+            InjectError::PermissionDenied(e) => (input_status::Code::PermissionDenied, e),
+            InjectError::Failed(e) => (input_status::Code::Error, e),
+        };
+        InputStatus {
+            code: stream::protobuf::ProtobufEnumOrUnknown::new(code),
+            backend: backend.to_string(),
+            message: message.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+pub type InjectResult = Result<(), InjectError>;
+
+///被控端输入注入，键盘使用跨平台键码（W3C KeyboardEvent.code）
+pub trait InputInjector {
+    fn name(&self) -> &'static str;
+    fn key(&mut self, code: &str, down: bool) -> InjectResult;
+    ///输入Unicode文本，不依赖被控端的键盘布局
+    fn text(&mut self, text: &str) -> InjectResult;
+    ///坐标为虚拟桌面坐标
+    fn mouse_move_to(&mut self, x: i32, y: i32) -> InjectResult;
+    fn mouse_move_relative(&mut self, dx: i32, dy: i32) -> InjectResult;
+    fn mouse_button(&mut self, button: Button, down: bool) -> InjectResult;
+    ///滚动格数，正数向下、向右
+    fn scroll(&mut self, dx: i32, dy: i32) -> InjectResult;
+
+    fn key_click(&mut self, code: &str) -> InjectResult {
+        self.key(code, true)?;
+        self.key(code, false)
+    }
+    fn mouse_click(&mut self, button: Button) -> InjectResult {
+        self.mouse_button(button, true)?;
+        self.mouse_button(button, false)
+    }
+}
+
+///选择注入方式，返回失败的方式供上报给主控端。
+///Linux下X11会话优先使用XTest，否则使用/dev/uinput，都不可用时退回enigo
+pub fn create() -> (Box<dyn InputInjector>, Vec<InputStatus>) {
+    #[allow(unused_mut)]
+    let mut errors = Vec::new();
+    #[cfg(target_os = "linux")]
+    {
+        if std::env::var_os("DISPLAY").is_some() {
+            match xtest::XTestInjector::new() {
+                Ok(injector) => return (Box::new(injector), errors),
+                Err(e) => {
+                    log::info!("XTest不可用:{}", e);
+                    errors.push(e.to_status("xtest"));
+                }
+            }
+        }
+        match uinput::UinputInjector::new() {
+            Ok(injector) => return (Box::new(injector), errors),
+            Err(e) => {
+                log::info!("uinput不可用:{}", e);
+                errors.push(e.to_status("uinput"));
+            }
+        }
+    }
+    (Box::new(EnigoInjector::new()), errors)
+}
+
+///把注入状态发回主控端，相同的错误只发送一次
+pub struct StatusReporter {
+    sender: Option<ChannelSender>,
+    reported: HashSet<String>,
+}
+
+impl StatusReporter {
+    ///旧版本主控端建立的是只写通道，没有回传
+    pub fn new(sender: Option<ChannelSender>) -> Self {
+        Self {
+            sender,
+            reported: HashSet::new(),
+        }
+    }
+    pub fn report(&mut self, status: InputStatus) {
+        if !self
+            .reported
+            .insert(format!("{}:{}", status.backend, status.message))
+        {
+            return;
+        }
+        if let Some(sender) = &mut self.sender {
+            if let Err(e) = send_status(sender, &status) {
+                log::info!("输入状态发送失败:{:?}", e);
+                self.sender = None;
+            }
+        }
+    }
+    pub fn ready(&mut self, backend: &str) {
+        self.report(InputStatus {
+            code: stream::protobuf::ProtobufEnumOrUnknown::new(input_status::Code::Ok),
+            backend: backend.to_string(),
+            ..Default::default()
+        });
+    }
+    pub fn error(&mut self, e: &InjectError, backend: &str) {
+        log::info!("输入注入失败:{}", e);
+        self.report(e.to_status(backend));
+    }
+}
+
+fn send_status(sender: &mut ChannelSender, status: &InputStatus) -> ResultType<()> {
+    sender.send(status.write_to_bytes()?)
+}
+
+pub struct EnigoInjector(enigo::Enigo);
+
+impl EnigoInjector {
+    pub fn new() -> Self {
+        Self(enigo::Enigo::new())
+    }
+}
+
+impl InputInjector for EnigoInjector {
+    fn name(&self) -> &'static str {
+        "enigo"
+    }
+    fn key(&mut self, code: &str, down: bool) -> InjectResult {
+        let key = input_utils::code_to_key(code)
+            .ok_or_else(|| InjectError::Unsupported(format!("不支持的按键:{}", code)))?;
+        if down {
+            self.0.key_down(key);
+        } else {
+            self.0.key_up(key);
+        }
+        Ok(())
+    }
+    fn text(&mut self, text: &str) -> InjectResult {
+        self.0.key_sequence(text);
+        Ok(())
+    }
+    fn mouse_move_to(&mut self, x: i32, y: i32) -> InjectResult {
+        self.0.mouse_move_to(x, y);
+        Ok(())
+    }
+    fn mouse_move_relative(&mut self, dx: i32, dy: i32) -> InjectResult {
+        self.0.mouse_move_relative(dx, dy);
+        Ok(())
+    }
+    fn mouse_button(&mut self, button: Button, down: bool) -> InjectResult {
+        let button = match button {
+            Button::Left => enigo::MouseButton::Left,
+            Button::Middle => enigo::MouseButton::Middle,
+            Button::Right => enigo::MouseButton::Right,
+            Button::Back | Button::Forward => return x_button(button, down),
+        };
+        if down {
+            self.0.mouse_down(button);
+        } else {
+            self.0.mouse_up(button);
+        }
+        Ok(())
+    }
+    fn scroll(&mut self, dx: i32, dy: i32) -> InjectResult {
+        if dx != 0 {
+            self.0.mouse_scroll_x(dx);
+        }
+        if dy != 0 {
+            self.0.mouse_scroll_y(dy);
+        }
+        Ok(())
+    }
+}
+
+//enigo不支持侧键
+#[cfg(windows)]
+fn x_button(button: Button, down: bool) -> InjectResult {
+    use winapi::um::winuser;
+
+    let data = match button {
+        Button::Back => winuser::XBUTTON1,
+        _ => winuser::XBUTTON2,
+    };
+    unsafe {
+        let mut input: winuser::INPUT = std::mem::zeroed();
+        input.type_ = winuser::INPUT_MOUSE;
+        let mi = input.u.mi_mut();
+        mi.dwFlags = if down {
+            winuser::MOUSEEVENTF_XDOWN
+        } else {
+            winuser::MOUSEEVENTF_XUP
+        };
+        mi.mouseData = data as u32;
+        if winuser::SendInput(1, &mut input, std::mem::size_of::<winuser::INPUT>() as i32) != 1 {
+            return Err(InjectError::Failed(format!("侧键注入失败:{:?}", button)));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(windows))]
+fn x_button(button: Button, _down: bool) -> InjectResult {
+    Err(InjectError::Unsupported(format!(
+        "不支持的鼠标按键:{:?}",
+        button
+    )))
+}
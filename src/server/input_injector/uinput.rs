@@ -0,0 +1,241 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Write},
+    os::unix::{fs::OpenOptionsExt, io::AsRawFd},
+};
+
+use super::{Button, InjectError, InjectResult, InputInjector};
+use crate::{keycode, server::display};
+
+const UI_DEV_CREATE: libc::c_ulong = 0x5501;
+const UI_DEV_DESTROY: libc::c_ulong = 0x5502;
+const UI_SET_EVBIT: libc::c_ulong = 0x4004_5564;
+const UI_SET_KEYBIT: libc::c_ulong = 0x4004_5565;
+const UI_SET_RELBIT: libc::c_ulong = 0x4004_5566;
+const UI_SET_ABSBIT: libc::c_ulong = 0x4004_5567;
+
+const EV_SYN: u16 = 0x00;
+const EV_KEY: u16 = 0x01;
+const EV_REL: u16 = 0x02;
+const EV_ABS: u16 = 0x03;
+const SYN_REPORT: u16 = 0;
+const REL_X: u16 = 0x00;
+const REL_Y: u16 = 0x01;
+const REL_HWHEEL: u16 = 0x06;
+const REL_WHEEL: u16 = 0x08;
+const ABS_X: u16 = 0x00;
+const ABS_Y: u16 = 0x01;
+const BTN_LEFT: u16 = 0x110;
+const BTN_RIGHT: u16 = 0x111;
+const BTN_MIDDLE: u16 = 0x112;
+const BTN_SIDE: u16 = 0x113;
+const BTN_EXTRA: u16 = 0x114;
+//evdev键盘键码上限
+const KEY_MAX: u16 = 0xff;
+
+const ABS_SIZE: usize = 64;
+//绝对坐标范围，按虚拟桌面大小换算
+const ABS_MAX: i32 = 0xffff;
+
+#[repr(C)]
+struct InputId {
+    bustype: u16,
+    vendor: u16,
+    product: u16,
+    version: u16,
+}
+
+#[repr(C)]
+struct UinputUserDev {
+    name: [u8; 80],
+    id: InputId,
+    ff_effects_max: u32,
+    absmax: [i32; ABS_SIZE],
+    absmin: [i32; ABS_SIZE],
+    absfuzz: [i32; ABS_SIZE],
+    absflat: [i32; ABS_SIZE],
+}
+
+///通过/dev/uinput创建虚拟键盘和绝对坐标指针，不依赖图形会话
+pub struct UinputInjector {
+    file: File,
+    //虚拟桌面区域，绝对坐标据此换算
+    desktop: display::Rect,
+}
+
+impl UinputInjector {
+    pub fn new() -> Result<Self, InjectError> {
+        let file = OpenOptions::new()
+            .write(true)
+            .custom_flags(libc::O_NONBLOCK)
+            .open("/dev/uinput")
+            .map_err(|e| match e.kind() {
+                io::ErrorKind::PermissionDenied => {
+                    InjectError::PermissionDenied(String::from("没有/dev/uinput的写权限"))
+                }
+                _ => InjectError::Unsupported(format!("无法打开/dev/uinput:{}", e)),
+            })?;
+        //没有图形会话时只能注入键盘
+        let desktop = display::all()
+            .map(|displays| display::bounding_rect(&displays))
+            .unwrap_or_default();
+        let injector = Self { file, desktop };
+        injector.setup()?;
+        Ok(injector)
+    }
+    fn ioctl(&self, request: libc::c_ulong, value: libc::c_int) -> InjectResult {
+        let rs = unsafe { libc::ioctl(self.file.as_raw_fd(), request, value) };
+        if rs < 0 {
+            return Err(InjectError::Failed(format!(
+                "uinput ioctl {:#x} 失败:{}",
+                request,
+                io::Error::last_os_error()
+            )));
+        }
+        Ok(())
+    }
+    fn setup(&self) -> InjectResult {
+        for ev in &[EV_SYN, EV_KEY, EV_REL, EV_ABS] {
+            self.ioctl(UI_SET_EVBIT, *ev as _)?;
+        }
+        for key in 1..=KEY_MAX {
+            self.ioctl(UI_SET_KEYBIT, key as _)?;
+        }
+        for button in &[BTN_LEFT, BTN_RIGHT, BTN_MIDDLE, BTN_SIDE, BTN_EXTRA] {
+            self.ioctl(UI_SET_KEYBIT, *button as _)?;
+        }
+        for rel in &[REL_X, REL_Y, REL_WHEEL, REL_HWHEEL] {
+            self.ioctl(UI_SET_RELBIT, *rel as _)?;
+        }
+        for abs in &[ABS_X, ABS_Y] {
+            self.ioctl(UI_SET_ABSBIT, *abs as _)?;
+        }
+        let mut dev = UinputUserDev {
+            name: [0; 80],
+            id: InputId {
+                bustype: 0x03, //BUS_USB
+                vendor: 0x1,
+                product: 0x1,
+                version: 1,
+            },
+            ff_effects_max: 0,
+            absmax: [0; ABS_SIZE],
+            absmin: [0; ABS_SIZE],
+            absfuzz: [0; ABS_SIZE],
+            absflat: [0; ABS_SIZE],
+        };
+        let name = b"onedesk virtual input";
+        dev.name[..name.len()].copy_from_slice(name);
+        dev.absmax[ABS_X as usize] = ABS_MAX;
+        dev.absmax[ABS_Y as usize] = ABS_MAX;
+        let bytes = unsafe {
+            std::slice::from_raw_parts(
+                &dev as *const UinputUserDev as *const u8,
+                std::mem::size_of::<UinputUserDev>(),
+            )
+        };
+        (&self.file)
+            .write_all(bytes)
+            .map_err(|e| InjectError::Failed(format!("uinput设备配置失败:{}", e)))?;
+        self.ioctl(UI_DEV_CREATE, 0)
+    }
+    fn emit(&mut self, kind: u16, code: u16, value: i32) -> InjectResult {
+        let event = libc::input_event {
+            time: libc::timeval {
+                tv_sec: 0,
+                tv_usec: 0,
+            },
+            type_: kind,
+            code,
+            value,
+        };
+        let bytes = unsafe {
+            std::slice::from_raw_parts(
+                &event as *const libc::input_event as *const u8,
+                std::mem::size_of::<libc::input_event>(),
+            )
+        };
+        self.file
+            .write_all(bytes)
+            .map_err(|e| InjectError::Failed(format!("uinput写入失败:{}", e)))
+    }
+    fn sync(&mut self) -> InjectResult {
+        self.emit(EV_SYN, SYN_REPORT, 0)
+    }
+}
+
+impl Drop for UinputInjector {
+    fn drop(&mut self) {
+        let _ = self.ioctl(UI_DEV_DESTROY, 0);
+    }
+}
+
+impl InputInjector for UinputInjector {
+    fn name(&self) -> &'static str {
+        "uinput"
+    }
+    fn key(&mut self, code: &str, down: bool) -> InjectResult {
+        let key = keycode::to_evdev(code)
+            .ok_or_else(|| InjectError::Unsupported(format!("不支持的按键:{}", code)))?;
+        self.emit(EV_KEY, key, down as i32)?;
+        self.sync()
+    }
+    fn text(&mut self, _text: &str) -> InjectResult {
+        //虚拟键盘只有键码，不知道被控端的键盘布局
+        Err(InjectError::Unsupported(String::from(
+            "uinput不支持文本输入",
+        )))
+    }
+    fn mouse_move_to(&mut self, x: i32, y: i32) -> InjectResult {
+        //绝对坐标设备覆盖整个虚拟桌面
+        let rect = self.desktop;
+        if rect.width == 0 || rect.height == 0 {
+            return Err(InjectError::Unsupported(String::from("没有可用的显示器")));
+        }
+        let abs_x = (x - rect.x) as i64 * ABS_MAX as i64 / (rect.width as i64 - 1).max(1);
+        let abs_y = (y - rect.y) as i64 * ABS_MAX as i64 / (rect.height as i64 - 1).max(1);
+        self.emit(EV_ABS, ABS_X, abs_x.max(0).min(ABS_MAX as i64) as i32)?;
+        self.emit(EV_ABS, ABS_Y, abs_y.max(0).min(ABS_MAX as i64) as i32)?;
+        self.sync()
+    }
+    fn mouse_move_relative(&mut self, dx: i32, dy: i32) -> InjectResult {
+        self.emit(EV_REL, REL_X, dx)?;
+        self.emit(EV_REL, REL_Y, dy)?;
+        self.sync()
+    }
+    fn mouse_button(&mut self, button: Button, down: bool) -> InjectResult {
+        let button = match button {
+            Button::Left => BTN_LEFT,
+            Button::Middle => BTN_MIDDLE,
+            Button::Right => BTN_RIGHT,
+            Button::Back => BTN_SIDE,
+            Button::Forward => BTN_EXTRA,
+        };
+        self.emit(EV_KEY, button, down as i32)?;
+        self.sync()
+    }
+    fn scroll(&mut self, dx: i32, dy: i32) -> InjectResult {
+        //REL_WHEEL正数向上，和约定的方向相反
+        if dy != 0 {
+            self.emit(EV_REL, REL_WHEEL, -dy)?;
+        }
+        if dx != 0 {
+            self.emit(EV_REL, REL_HWHEEL, dx)?;
+        }
+        self.sync()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //需要/dev/uinput的写权限，例如以--device /dev/uinput运行的容器
+    #[test]
+    #[ignore]
+    fn test_uinput_key() {
+        let mut injector = UinputInjector::new().unwrap();
+        injector.key_click("KeyA").unwrap();
+        injector.scroll(0, 1).unwrap();
+    }
+}
@@ -0,0 +1,247 @@
+use std::{os::raw::c_int, ptr::null};
+
+use x11::{xlib, xtest};
+
+use super::{Button, InjectError, InjectResult, InputInjector};
+use crate::keycode;
+
+///X11会话下通过XTest扩展注入输入
+pub struct XTestInjector {
+    display: *mut xlib::Display,
+}
+
+impl XTestInjector {
+    pub fn new() -> Result<Self, InjectError> {
+        unsafe {
+            let display = xlib::XOpenDisplay(null());
+            if display.is_null() {
+                return Err(InjectError::Unsupported(String::from("无法连接X11")));
+            }
+            let (mut event_base, mut error_base, mut major, mut minor) = (0, 0, 0, 0);
+            if xtest::XTestQueryExtension(
+                display,
+                &mut event_base,
+                &mut error_base,
+                &mut major,
+                &mut minor,
+            ) == 0
+            {
+                xlib::XCloseDisplay(display);
+                return Err(InjectError::Unsupported(String::from("X11不支持XTest扩展")));
+            }
+            Ok(Self { display })
+        }
+    }
+    fn flush(&mut self) -> InjectResult {
+        unsafe {
+            xlib::XFlush(self.display);
+        }
+        Ok(())
+    }
+    //键盘映射中没有该字符时，临时映射到一个空闲键码上输入
+    fn unicode(&mut self, c: char) -> InjectResult {
+        let keysym = unicode_keysym(c);
+        unsafe {
+            let mut keycode = xlib::XKeysymToKeycode(self.display, keysym);
+            let remapped = keycode == 0;
+            if remapped {
+                keycode = self.spare_keycode()?;
+                let mut keysyms = [keysym, keysym];
+                xlib::XChangeKeyboardMapping(
+                    self.display,
+                    keycode as c_int,
+                    2,
+                    keysyms.as_mut_ptr(),
+                    1,
+                );
+                xlib::XSync(self.display, xlib::False);
+            }
+            //已有映射的字符可能需要Shift，这里统一按映射的第一个keysym判断
+            let shift = !remapped && xlib::XKeycodeToKeysym(self.display, keycode, 0) != keysym;
+            let shift_code = xlib::XKeysymToKeycode(self.display, x11::keysym::XK_Shift_L as _);
+            if shift {
+                xtest::XTestFakeKeyEvent(self.display, shift_code as u32, 1, 0);
+            }
+            xtest::XTestFakeKeyEvent(self.display, keycode as u32, 1, 0);
+            xtest::XTestFakeKeyEvent(self.display, keycode as u32, 0, 0);
+            if shift {
+                xtest::XTestFakeKeyEvent(self.display, shift_code as u32, 0, 0);
+            }
+            if remapped {
+                xlib::XSync(self.display, xlib::False);
+                let mut keysyms = [0, 0];
+                xlib::XChangeKeyboardMapping(
+                    self.display,
+                    keycode as c_int,
+                    2,
+                    keysyms.as_mut_ptr(),
+                    1,
+                );
+                xlib::XSync(self.display, xlib::False);
+            }
+        }
+        Ok(())
+    }
+    //没有映射任何keysym的键码
+    fn spare_keycode(&mut self) -> Result<u8, InjectError> {
+        unsafe {
+            let (mut min, mut max) = (0, 0);
+            xlib::XDisplayKeycodes(self.display, &mut min, &mut max);
+            let mut per_keycode = 0;
+            let mapping =
+                xlib::XGetKeyboardMapping(self.display, min as u8, max - min + 1, &mut per_keycode);
+            if mapping.is_null() {
+                return Err(InjectError::Failed(String::from("读取键盘映射失败")));
+            }
+            let per_keycode = per_keycode as usize;
+            let mut spare = None;
+            for keycode in (min..=max).rev() {
+                let offset = (keycode - min) as usize * per_keycode;
+                let used = (0..per_keycode).any(|i| *mapping.add(offset + i) != 0);
+                if !used {
+                    spare = Some(keycode as u8);
+                    break;
+                }
+            }
+            xlib::XFree(mapping as *mut _);
+            spare.ok_or_else(|| InjectError::Failed(String::from("没有空闲的键码")))
+        }
+    }
+    fn button_event(&mut self, button: u32, down: bool) -> InjectResult {
+        unsafe {
+            if xtest::XTestFakeButtonEvent(self.display, button, down as c_int, 0) == 0 {
+                return Err(InjectError::Failed(format!("按键注入失败:{}", button)));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for XTestInjector {
+    fn drop(&mut self) {
+        unsafe {
+            xlib::XCloseDisplay(self.display);
+        }
+    }
+}
+
+impl InputInjector for XTestInjector {
+    fn name(&self) -> &'static str {
+        "xtest"
+    }
+    fn key(&mut self, code: &str, down: bool) -> InjectResult {
+        let keysym = keycode::to_keysym(code)
+            .ok_or_else(|| InjectError::Unsupported(format!("不支持的按键:{}", code)))?;
+        unsafe {
+            //用服务器当前的键盘映射，而不是假定evdev键码
+            let keycode = xlib::XKeysymToKeycode(self.display, keysym as xlib::KeySym);
+            if keycode == 0 {
+                return Err(InjectError::Unsupported(format!(
+                    "键盘布局中没有该按键:{}",
+                    code
+                )));
+            }
+            if xtest::XTestFakeKeyEvent(self.display, keycode as u32, down as c_int, 0) == 0 {
+                return Err(InjectError::Failed(format!("按键注入失败:{}", code)));
+            }
+        }
+        self.flush()
+    }
+    fn text(&mut self, text: &str) -> InjectResult {
+        for c in text.chars() {
+            self.unicode(c)?;
+        }
+        self.flush()
+    }
+    fn mouse_move_to(&mut self, x: i32, y: i32) -> InjectResult {
+        unsafe {
+            if xtest::XTestFakeMotionEvent(self.display, -1, x, y, 0) == 0 {
+                return Err(InjectError::Failed(String::from("鼠标移动注入失败")));
+            }
+        }
+        self.flush()
+    }
+    fn mouse_move_relative(&mut self, dx: i32, dy: i32) -> InjectResult {
+        unsafe {
+            if xtest::XTestFakeRelativeMotionEvent(self.display, dx, dy, 0) == 0 {
+                return Err(InjectError::Failed(String::from("鼠标移动注入失败")));
+            }
+        }
+        self.flush()
+    }
+    fn mouse_button(&mut self, button: Button, down: bool) -> InjectResult {
+        let button = match button {
+            Button::Left => 1,
+            Button::Middle => 2,
+            Button::Right => 3,
+            Button::Back => 8,
+            Button::Forward => 9,
+        };
+        self.button_event(button, down)?;
+        self.flush()
+    }
+    fn scroll(&mut self, dx: i32, dy: i32) -> InjectResult {
+        //X11用按键4、5、6、7表示上下左右滚动
+        let vertical = if dy < 0 { 4 } else { 5 };
+        for _ in 0..dy.abs() {
+            self.button_event(vertical, true)?;
+            self.button_event(vertical, false)?;
+        }
+        let horizontal = if dx < 0 { 6 } else { 7 };
+        for _ in 0..dx.abs() {
+            self.button_event(horizontal, true)?;
+            self.button_event(horizontal, false)?;
+        }
+        self.flush()
+    }
+}
+
+//Latin-1直接对应keysym，其他字符为0x01000000加码位
+fn unicode_keysym(c: char) -> xlib::KeySym {
+    let code = c as u32;
+    match code {
+        0x20..=0x7e | 0xa0..=0xff => code as xlib::KeySym,
+        _ => (0x0100_0000 + code) as xlib::KeySym,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //需要X11环境，例如：Xvfb :99 & DISPLAY=:99 cargo test -- --ignored
+    #[test]
+    #[ignore]
+    fn test_xtest_move() {
+        let mut injector = XTestInjector::new().unwrap();
+        injector.mouse_move_to(10, 20).unwrap();
+        unsafe {
+            let root = xlib::XDefaultRootWindow(injector.display);
+            let (mut root_ret, mut child) = (0, 0);
+            let (mut x, mut y, mut win_x, mut win_y, mut mask) = (0, 0, 0, 0, 0);
+            xlib::XQueryPointer(
+                injector.display,
+                root,
+                &mut root_ret,
+                &mut child,
+                &mut x,
+                &mut y,
+                &mut win_x,
+                &mut win_y,
+                &mut mask,
+            );
+            assert_eq!((x, y), (10, 20));
+        }
+        injector.key_click("KeyA").unwrap();
+        injector.mouse_click(Button::Left).unwrap();
+        injector.scroll(1, -1).unwrap();
+        injector.text("a中😀").unwrap();
+    }
+
+    #[test]
+    fn test_unicode_keysym() {
+        assert_eq!(unicode_keysym('a'), 0x61);
+        assert_eq!(unicode_keysym('é'), 0xe9);
+        assert_eq!(unicode_keysym('中'), 0x0100_4e2d);
+    }
+}
@@ -1,23 +1,309 @@
-use enigo::{Key, KeyboardControllable};
-use stream::{message::KeyEvent, protobuf::Message, remote_channel::ChannelReceiver, ResultType};
+use std::collections::HashSet;
 
-pub  fn start(receiver: ChannelReceiver) -> ResultType<()> {
-    let mut enigo = enigo::Enigo::new();
+use stream::{
+    message::{key_event, keyboard_message, special_key, text_input, KeyEvent, KeyboardMessage},
+    protobuf::Message,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    ResultType,
+};
+
+use super::input_injector::{self, InjectError, InjectResult, InputInjector, StatusReporter};
+use crate::input_utils::to_code;
+
+pub fn start(sender: Option<ChannelSender>, receiver: ChannelReceiver) -> ResultType<()> {
+    let mut reporter = StatusReporter::new(sender);
+    let (mut injector, errors) = input_injector::create();
+    for status in errors {
+        reporter.report(status);
+    }
+    reporter.ready(injector.name());
+    let mut pressed = Pressed::default();
     while let Ok(data) = receiver.recv() {
-        let key_data = KeyEvent::parse_from_bytes(&data)?;
-        match key_data.active.enum_value_or_default() {
-            //键盘事件用钩子获取到的值能直接用，不需要额外转换
-            stream::message::key_event::Active::Click => {
-                enigo.key_click(Key::Raw(key_data.key as u16));
+        let msg = match parse(&data) {
+            Ok(msg) => msg,
+            Err(e) => {
+                log::info!("键盘数据解析失败:{:?}", e);
+                break;
+            }
+        };
+        let rs = match msg {
+            keyboard_message::Union::key_event(key_data) => {
+                key(injector.as_mut(), &mut pressed, &key_data)
             }
-            stream::message::key_event::Active::Down => {
-                enigo.key_down(Key::Raw(key_data.key as u16));
+            keyboard_message::Union::special_key(special) => {
+                special_steps(special.name.enum_value_or_default())
+                    .and_then(|steps| replay(injector.as_mut(), &steps))
             }
-            stream::message::key_event::Active::Up => {
-                enigo.key_up(Key::Raw(key_data.key as u16));
+            keyboard_message::Union::key_macro(key_macro) => {
+                log::info!("执行按键宏:{}", key_macro.name);
+                let steps: Vec<Vec<String>> = key_macro
+                    .steps
+                    .into_iter()
+                    .map(|combo| combo.codes)
+                    .collect();
+                replay(injector.as_mut(), &steps)
             }
+            keyboard_message::Union::snapshot(snapshot) => {
+                //主控端已经抬起但这边没收到的键
+                let stale = pressed.stale(&snapshot.pressed);
+                pressed.release(injector.as_mut(), stale)
+            }
+            keyboard_message::Union::text_input(text) => {
+                //输入法组字过程在主控端显示，只输入提交的文本
+                if text.composition.enum_value_or_default() == text_input::Composition::Commit {
+                    injector.text(&text.text)
+                } else {
+                    Ok(())
+                }
+            }
+        };
+        if let Err(e) = rs {
+            reporter.error(&e, injector.name());
         }
     }
+    //通道断开时不会再收到抬起事件
+    let all = pressed.0.iter().cloned().collect();
+    if let Err(e) = pressed.release(injector.as_mut(), all) {
+        reporter.error(&e, injector.name());
+    }
     log::info!("键盘通道断开");
     Ok(())
 }
+
+fn special_steps(name: special_key::Name) -> Result<Vec<Vec<String>>, InjectError> {
+    let combo: &[&str] = match name {
+        special_key::Name::CtrlAltDel => {
+            //Windows的安全桌面不接受模拟输入，只有服务进程能调用SendSAS，模拟按键不会有任何效果
+            if cfg!(windows) {
+                return Err(InjectError::Unsupported(String::from(
+                    "Windows不以服务运行时无法发送Ctrl+Alt+Del",
+                )));
+            }
+            &["ControlLeft", "AltLeft", "Delete"]
+        }
+        special_key::Name::LockScreen => &["MetaLeft", "KeyL"],
+        special_key::Name::AltTab => &["AltLeft", "Tab"],
+        special_key::Name::PrintScreen => &["PrintScreen"],
+        special_key::Name::TaskManager => &["ControlLeft", "ShiftLeft", "Escape"],
+        special_key::Name::AltF4 => &["AltLeft", "F4"],
+        special_key::Name::StartMenu => &["MetaLeft"],
+    };
+    Ok(vec![combo.iter().map(|code| code.to_string()).collect()])
+}
+
+///依次执行组合键，中途失败时抬起已按下的键，执行期间不处理其他键盘消息
+fn replay(injector: &mut dyn InputInjector, steps: &[Vec<String>]) -> InjectResult {
+    for combo in steps {
+        let mut down = Vec::with_capacity(combo.len());
+        let mut rs = Ok(());
+        for code in combo {
+            rs = injector.key(code, true);
+            if rs.is_err() {
+                break;
+            }
+            down.push(code);
+        }
+        for code in down.iter().rev() {
+            let up = injector.key(code, false);
+            if rs.is_ok() {
+                rs = up;
+            }
+        }
+        rs?;
+    }
+    Ok(())
+}
+
+///被控端已按下的键
+#[derive(Debug, Default)]
+struct Pressed(HashSet<String>);
+
+impl Pressed {
+    fn update(&mut self, code: &str, down: bool) {
+        if down {
+            self.0.insert(code.to_string());
+        } else {
+            self.0.remove(code);
+        }
+    }
+    fn stale(&self, snapshot: &[String]) -> Vec<String> {
+        self.0
+            .iter()
+            .filter(|code| !snapshot.contains(code))
+            .cloned()
+            .collect()
+    }
+    ///逐个抬起，某个键失败时继续抬起其余的键，返回第一个错误
+    fn release(&mut self, injector: &mut dyn InputInjector, codes: Vec<String>) -> InjectResult {
+        let mut rs = Ok(());
+        for code in codes {
+            log::info!("抬起按键:{}", code);
+            self.0.remove(&code);
+            let up = injector.key(&code, false);
+            if rs.is_ok() {
+                rs = up;
+            }
+        }
+        rs
+    }
+}
+
+//旧版本主控端直接发送KeyEvent
+fn parse(data: &[u8]) -> ResultType<keyboard_message::Union> {
+    if let Ok(msg) = KeyboardMessage::parse_from_bytes(data) {
+        if let Some(union) = msg.union {
+            return Ok(union);
+        }
+    }
+    Ok(keyboard_message::Union::key_event(
+        KeyEvent::parse_from_bytes(data)?,
+    ))
+}
+
+fn key(
+    injector: &mut dyn InputInjector,
+    pressed: &mut Pressed,
+    key_data: &KeyEvent,
+) -> InjectResult {
+    //跨平台键码，由注入方式转换成本机键码
+    let code = to_code(key_data)
+        .ok_or_else(|| InjectError::Unsupported(format!("未知按键:{}", key_data.key)))?;
+    match key_data.active.enum_value_or_default() {
+        key_event::Active::Click => injector.key_click(code),
+        key_event::Active::Down => {
+            //按下失败的键不记录，断开时不会再去抬起
+            injector.key(code, true)?;
+            pressed.update(code, true);
+            Ok(())
+        }
+        key_event::Active::Up => {
+            pressed.update(code, false);
+            injector.key(code, false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::input_injector::Button;
+
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl InputInjector for Recorder {
+        fn name(&self) -> &'static str {
+            "recorder"
+        }
+        fn key(&mut self, code: &str, down: bool) -> InjectResult {
+            if code == "F13" {
+                return Err(InjectError::Unsupported(code.to_string()));
+            }
+            self.0
+                .push(format!("{}{}", if down { "+" } else { "-" }, code));
+            Ok(())
+        }
+        fn text(&mut self, _text: &str) -> InjectResult {
+            Ok(())
+        }
+        fn mouse_move_to(&mut self, _x: i32, _y: i32) -> InjectResult {
+            Ok(())
+        }
+        fn mouse_move_relative(&mut self, _dx: i32, _dy: i32) -> InjectResult {
+            Ok(())
+        }
+        fn mouse_button(&mut self, _button: Button, _down: bool) -> InjectResult {
+            Ok(())
+        }
+        fn scroll(&mut self, _dx: i32, _dy: i32) -> InjectResult {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_replay() {
+        let mut recorder = Recorder::default();
+        replay(
+            &mut recorder,
+            &special_steps(special_key::Name::TaskManager).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            recorder.0,
+            vec![
+                "+ControlLeft",
+                "+ShiftLeft",
+                "+Escape",
+                "-Escape",
+                "-ShiftLeft",
+                "-ControlLeft"
+            ]
+        );
+        //失败时已按下的键也要抬起
+        let mut recorder = Recorder::default();
+        let steps = vec![vec![String::from("ControlLeft"), String::from("F13")]];
+        assert!(replay(&mut recorder, &steps).is_err());
+        assert_eq!(recorder.0, vec!["+ControlLeft", "-ControlLeft"]);
+        //Windows上返回错误，由主控端显示原因
+        assert_eq!(
+            special_steps(special_key::Name::CtrlAltDel).is_err(),
+            cfg!(windows)
+        );
+    }
+
+    #[test]
+    fn test_stale() {
+        let mut pressed = Pressed::default();
+        pressed.update("ShiftLeft", true);
+        pressed.update("AltLeft", true);
+        pressed.update("KeyA", true);
+        pressed.update("KeyA", false);
+        let stale = pressed.stale(&[String::from("ShiftLeft")]);
+        assert_eq!(stale, vec![String::from("AltLeft")]);
+    }
+
+    #[test]
+    fn test_release() {
+        let mut recorder = Recorder::default();
+        let mut pressed = Pressed::default();
+        let down = |code: &str| KeyEvent {
+            code: code.to_string(),
+            active: key_event::Active::Down.into(),
+            ..Default::default()
+        };
+        key(&mut recorder, &mut pressed, &down("ShiftLeft")).unwrap();
+        assert!(key(&mut recorder, &mut pressed, &down("F13")).is_err());
+        assert!(!pressed.0.contains("F13"));
+        //中间有抬起失败的键时其余的键也要抬起
+        pressed.update("F13", true);
+        pressed.update("AltLeft", true);
+        let all = vec![
+            String::from("F13"),
+            String::from("ShiftLeft"),
+            String::from("AltLeft"),
+        ];
+        assert!(pressed.release(&mut recorder, all).is_err());
+        assert!(pressed.0.is_empty());
+        assert_eq!(recorder.0, vec!["+ShiftLeft", "-ShiftLeft", "-AltLeft"]);
+    }
+
+    #[test]
+    fn test_parse() {
+        let key_data = KeyEvent {
+            key: 0x41,
+            code: String::from("KeyA"),
+            ..Default::default()
+        };
+        let old = key_data.write_to_bytes().unwrap();
+        assert_eq!(
+            parse(&old).unwrap(),
+            keyboard_message::Union::key_event(key_data.clone())
+        );
+        let mut msg = KeyboardMessage::new();
+        msg.set_key_event(key_data.clone());
+        assert_eq!(
+            parse(&msg.write_to_bytes().unwrap()).unwrap(),
+            keyboard_message::Union::key_event(key_data)
+        );
+    }
+}
@@ -0,0 +1,261 @@
+//! 登录失败的记录。按主控端代号和ID服务器看到的主控端地址分别计数，超过允许的次数后按指数增加锁定时间，
+//! 记录保存在文件中，重启后仍然有效。代号由主控端自己声明，来源锁定对所有代号都有效，
+//! 登录成功后该代号的失败次数减半，不直接清除
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use directories_next::ProjectDirs;
+use serde_derive::{Deserialize, Serialize};
+use stream::relay::LoginGuard;
+
+use crate::audit;
+
+const APPNAME: &str = "onedesk";
+//每个代号允许连续失败的次数，之后开始锁定
+const PEER_FREE_FAILURES: u32 = 3;
+//同一个来源地址的所有代号合计，换代号猜密码时起作用
+const SOURCE_FREE_FAILURES: u32 = 20;
+//第一次锁定的秒数，之后每次失败加倍
+const BASE_LOCKOUT: u64 = 30;
+const MAX_LOCKOUT: u64 = 24 * 3600;
+//超过该时间没有失败时重新计数
+const FORGET_AFTER: u64 = 24 * 3600;
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+struct Record {
+    failures: u32,
+    last_failure: u64,
+    locked_until: u64,
+}
+
+impl Record {
+    fn remaining(&self, now: u64) -> u64 {
+        self.locked_until.saturating_sub(now)
+    }
+    fn fail(&mut self, now: u64, free: u32) {
+        if now.saturating_sub(self.last_failure) > FORGET_AFTER {
+            self.failures = 0;
+        }
+        self.failures += 1;
+        self.last_failure = now;
+        if self.failures > free {
+            let exp = (self.failures - free - 1).min(32);
+            let lockout = BASE_LOCKOUT.saturating_mul(1 << exp).min(MAX_LOCKOUT);
+            self.locked_until = now + lockout;
+        }
+    }
+    //保留最后失败的时间，到期后照常遗忘
+    fn succeed(&mut self) {
+        self.failures /= 2;
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+struct Lockouts {
+    #[serde(default)]
+    peers: HashMap<String, Record>,
+    #[serde(default)]
+    sources: HashMap<String, Record>,
+}
+
+///界面显示的锁定项
+#[derive(Debug, Clone)]
+pub struct Lockout {
+    //peer或source
+    pub kind: &'static str,
+    pub key: String,
+    pub failures: u32,
+    pub remaining: u64,
+}
+
+lazy_static::lazy_static! {
+    static ref LOCKOUTS:Mutex<Lockouts> = Mutex::new(load());
+}
+
+fn path() -> Option<std::path::PathBuf> {
+    ProjectDirs::from("", "", APPNAME).map(|project| {
+        let mut path = project.data_local_dir().to_path_buf();
+        path.push("lockout.yaml");
+        path
+    })
+}
+
+fn load() -> Lockouts {
+    if let Some(path) = path() {
+        if let Ok(lockouts) = confy::load_path(path) {
+            return lockouts;
+        }
+    }
+    Default::default()
+}
+
+fn store(lockouts: &Lockouts) {
+    if let Some(path) = path() {
+        if let Err(err) = confy::store_path(path, lockouts) {
+            log::error!("Failed to store lockouts: {}", err);
+        }
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or(0)
+}
+
+///传给RelayServer，登录时检查和记录失败
+pub fn guard() -> LoginGuard {
+    LoginGuard {
+        locked,
+        failed,
+        succeeded,
+    }
+}
+
+///剩余的锁定秒数，代号和来源取较长的
+pub fn locked(peer_id: &str, source: &str) -> u64 {
+    let lockouts = LOCKOUTS.lock().unwrap();
+    remaining(&lockouts, peer_id, source, unix_now())
+}
+
+fn remaining(lockouts: &Lockouts, peer_id: &str, source: &str, now: u64) -> u64 {
+    let peer = lockouts.peers.get(peer_id).map(|r| r.remaining(now));
+    let source = lockouts.sources.get(source).map(|r| r.remaining(now));
+    peer.unwrap_or(0).max(source.unwrap_or(0))
+}
+
+///密码或验证码错误，返回之后的锁定秒数
+pub fn failed(peer_id: &str, source: &str) -> u64 {
+    let now = unix_now();
+    let mut lockouts = LOCKOUTS.lock().unwrap();
+    fail(&mut lockouts, peer_id, source, now);
+    store(&lockouts);
+    let secs = remaining(&lockouts, peer_id, source, now);
+    audit::record(
+        peer_id,
+        "login_failed",
+        &format!("source:{} lockout:{}s", source, secs),
+    );
+    secs
+}
+
+fn fail(lockouts: &mut Lockouts, peer_id: &str, source: &str, now: u64) {
+    lockouts
+        .peers
+        .entry(peer_id.to_string())
+        .or_default()
+        .fail(now, PEER_FREE_FAILURES);
+    //旧版本的ID服务器不提供主控端地址
+    if !source.is_empty() {
+        lockouts
+            .sources
+            .entry(source.to_string())
+            .or_default()
+            .fail(now, SOURCE_FREE_FAILURES);
+    }
+}
+
+fn succeed(lockouts: &mut Lockouts, peer_id: &str) {
+    if let Some(record) = lockouts.peers.get_mut(peer_id) {
+        record.succeed();
+    }
+}
+
+///登录成功后该代号的失败次数减半，来源的记录保留到自然过期
+pub fn succeeded(peer_id: &str, _source: &str) {
+    let mut lockouts = LOCKOUTS.lock().unwrap();
+    succeed(&mut lockouts, peer_id);
+    store(&lockouts);
+}
+
+///有失败记录的代号和来源
+pub fn list() -> Vec<Lockout> {
+    let now = unix_now();
+    let lockouts = LOCKOUTS.lock().unwrap();
+    let peers = lockouts.peers.iter().map(|(key, r)| ("peer", key, r));
+    let sources = lockouts.sources.iter().map(|(key, r)| ("source", key, r));
+    peers
+        .chain(sources)
+        .filter(|(_, _, r)| now.saturating_sub(r.last_failure) <= FORGET_AFTER)
+        .map(|(kind, key, r)| Lockout {
+            kind,
+            key: key.clone(),
+            failures: r.failures,
+            remaining: r.remaining(now),
+        })
+        .collect()
+}
+
+///管理员解除所有锁定
+pub fn clear() {
+    let mut lockouts = LOCKOUTS.lock().unwrap();
+    lockouts.peers.clear();
+    lockouts.sources.clear();
+    store(&lockouts);
+    audit::record("local", "lockout", "cleared");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fail() {
+        let mut lockouts = Lockouts::default();
+        let now = 1_000_000;
+        for _ in 0..PEER_FREE_FAILURES {
+            fail(&mut lockouts, "123", "1.2.3.4", now);
+        }
+        assert_eq!(remaining(&lockouts, "123", "1.2.3.4", now), 0);
+        //之后每次失败锁定时间加倍
+        fail(&mut lockouts, "123", "1.2.3.4", now);
+        assert_eq!(remaining(&lockouts, "123", "1.2.3.4", now), BASE_LOCKOUT);
+        fail(&mut lockouts, "123", "1.2.3.4", now);
+        assert_eq!(
+            remaining(&lockouts, "123", "1.2.3.4", now),
+            BASE_LOCKOUT * 2
+        );
+        //其他代号不受影响，来源还没有超过次数
+        assert_eq!(remaining(&lockouts, "456", "1.2.3.4", now), 0);
+        for i in 0..SOURCE_FREE_FAILURES {
+            fail(&mut lockouts, &i.to_string(), "1.2.3.4", now);
+        }
+        assert!(remaining(&lockouts, "456", "1.2.3.4", now) > 0);
+        assert_eq!(remaining(&lockouts, "456", "other", now), 0);
+        //长时间没有失败后重新计数
+        let later = now + FORGET_AFTER + 1;
+        fail(&mut lockouts, "123", "other", later);
+        assert_eq!(lockouts.peers["123"].failures, 1);
+        assert_eq!(remaining(&lockouts, "123", "other", later), 0);
+    }
+
+    #[test]
+    fn test_succeed() {
+        let mut lockouts = Lockouts::default();
+        let now = 1_000_000;
+        for _ in 0..PEER_FREE_FAILURES {
+            fail(&mut lockouts, "123", "", now);
+        }
+        //登录成功后失败次数减半，不清除记录
+        succeed(&mut lockouts, "123");
+        assert_eq!(lockouts.peers["123"].failures, PEER_FREE_FAILURES / 2);
+        assert_eq!(lockouts.peers["123"].last_failure, now);
+        //换代号猜密码锁定了来源，登录成功过的代号同样受限
+        for i in 0..=SOURCE_FREE_FAILURES {
+            fail(&mut lockouts, &format!("9{}", i), "1.2.3.4", now);
+        }
+        assert!(remaining(&lockouts, "456", "1.2.3.4", now) > 0);
+        assert!(remaining(&lockouts, "123", "1.2.3.4", now) > 0);
+        //没有来源地址时只按代号计数
+        for _ in 0..=PEER_FREE_FAILURES {
+            fail(&mut lockouts, "789", "", now);
+        }
+        assert!(!lockouts.sources.contains_key(""));
+        assert_eq!(remaining(&lockouts, "789", "", now), BASE_LOCKOUT);
+    }
+}
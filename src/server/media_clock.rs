@@ -0,0 +1,12 @@
+//! 被控端的媒体时钟，视频帧和音频帧的时间戳都以此为准，主控端据此同步音画
+
+use std::time::Instant;
+
+lazy_static::lazy_static! {
+    static ref START:Instant = Instant::now();
+}
+
+///从进程启动开始的毫秒数
+pub fn now() -> u64 {
+    START.elapsed().as_millis() as u64
+}
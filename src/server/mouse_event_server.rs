@@ -1,30 +1,162 @@
-use enigo::MouseControllable;
-use stream::{message::MouseEvent, protobuf::Message, remote_channel::ChannelReceiver, ResultType};
+use stream::{
+    message::{mouse_event, InputBatch, MouseEvent},
+    protobuf::Message,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    ResultType,
+};
 
 use crate::input_utils::to_mouse;
 
-pub fn start(receiver: ChannelReceiver) -> ResultType<()> {
-    let mut enigo = enigo::Enigo::new();
+use super::{
+    display,
+    input_injector::{self, InjectError, InjectResult, InputInjector, StatusReporter},
+};
+
+//一格滚动的高精度滚动量
+const WHEEL_DELTA: i32 = 120;
+
+pub fn start(sender: Option<ChannelSender>, receiver: ChannelReceiver) -> ResultType<()> {
+    let mut reporter = StatusReporter::new(sender);
+    let (mut injector, errors) = input_injector::create();
+    for status in errors {
+        reporter.report(status);
+    }
+    reporter.ready(injector.name());
+    let mut wheel = Wheel::default();
     while let Ok(data) = receiver.recv() {
-        let mouse = MouseEvent::parse_from_bytes(&data)?;
-        match mouse.active.enum_value_or_default() {
-            stream::message::mouse_event::Active::Click => {
-                enigo.mouse_click(to_mouse(mouse.key));
-            }
-            stream::message::mouse_event::Active::Down => {
-                enigo.mouse_down(to_mouse(mouse.key));
-            }
-            stream::message::mouse_event::Active::Up => {
-                enigo.mouse_up(to_mouse(mouse.key));
-            }
-            stream::message::mouse_event::Active::Move => {
-                enigo.mouse_move_to(mouse.move_x, mouse.move_y);
-            }
-            stream::message::mouse_event::Active::ScrollY => {
-                enigo.mouse_scroll_y(mouse.scroll_len);
+        for mouse in parse(&data)? {
+            if let Err(e) = handle(injector.as_mut(), &mut wheel, &mouse) {
+                reporter.error(&e, injector.name());
             }
         }
     }
     log::info!("鼠标通道断开");
     Ok(())
 }
+
+//旧版本主控端每帧只发送一个MouseEvent
+fn parse(data: &[u8]) -> ResultType<Vec<MouseEvent>> {
+    if let Ok(batch) = InputBatch::parse_from_bytes(data) {
+        if !batch.events.is_empty() {
+            return Ok(batch.events);
+        }
+    }
+    Ok(vec![MouseEvent::parse_from_bytes(data)?])
+}
+
+fn handle(injector: &mut dyn InputInjector, wheel: &mut Wheel, mouse: &MouseEvent) -> InjectResult {
+    let button = || {
+        to_mouse(mouse.key)
+            .ok_or_else(|| InjectError::Unsupported(format!("不支持的鼠标按键:{}", mouse.key)))
+    };
+    match mouse.active.enum_value_or_default() {
+        mouse_event::Active::Click => injector.mouse_click(button()?),
+        mouse_event::Active::Down => injector.mouse_button(button()?, true),
+        mouse_event::Active::Up => injector.mouse_button(button()?, false),
+        mouse_event::Active::Move => {
+            let (x, y) = if mouse.normalized {
+                display::normalized_to_screen(mouse.display_id, mouse.norm_x, mouse.norm_y)
+            } else {
+                //旧版本主控端发送的是画面像素坐标
+                display::to_screen(mouse.move_x, mouse.move_y)
+            };
+            injector.mouse_move_to(x, y)
+        }
+        mouse_event::Active::MoveRelative => {
+            let (dx, dy) = display::to_screen_delta(mouse.move_x, mouse.move_y);
+            injector.mouse_move_relative(dx, dy)
+        }
+        mouse_event::Active::ScrollY => {
+            let lines = wheel.add_y(mouse);
+            injector.scroll(0, lines)
+        }
+        mouse_event::Active::ScrollX => {
+            let lines = wheel.add_x(mouse);
+            injector.scroll(lines, 0)
+        }
+    }
+}
+
+///累积高精度滚动量，满一格才滚动，余量留到下次
+#[derive(Debug, Default)]
+struct Wheel {
+    x: i32,
+    y: i32,
+}
+
+impl Wheel {
+    fn add_x(&mut self, mouse: &MouseEvent) -> i32 {
+        Self::add(&mut self.x, mouse)
+    }
+    fn add_y(&mut self, mouse: &MouseEvent) -> i32 {
+        Self::add(&mut self.y, mouse)
+    }
+    fn add(rest: &mut i32, mouse: &MouseEvent) -> i32 {
+        //旧版本只有滚动格数
+        if mouse.wheel_delta == 0 {
+            return mouse.scroll_len;
+        }
+        //换方向时丢弃之前的余量
+        if (*rest > 0 && mouse.wheel_delta < 0) || (*rest < 0 && mouse.wheel_delta > 0) {
+            *rest = 0;
+        }
+        *rest += mouse.wheel_delta;
+        let lines = *rest / WHEEL_DELTA;
+        *rest -= lines * WHEEL_DELTA;
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wheel_event(scroll_len: i32, wheel_delta: i32) -> MouseEvent {
+        MouseEvent {
+            scroll_len,
+            wheel_delta,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let mouse = MouseEvent {
+            key: 1,
+            active: stream::protobuf::ProtobufEnumOrUnknown::new(mouse_event::Active::Down),
+            ..Default::default()
+        };
+        let old = mouse.write_to_bytes().unwrap();
+        assert_eq!(parse(&old).unwrap(), vec![mouse.clone()]);
+        let moved = MouseEvent {
+            active: stream::protobuf::ProtobufEnumOrUnknown::new(mouse_event::Active::Move),
+            move_x: 10,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse(&moved.write_to_bytes().unwrap()).unwrap(),
+            vec![moved.clone()]
+        );
+        let batch = InputBatch {
+            events: vec![moved.clone(), mouse.clone()],
+            ..Default::default()
+        };
+        assert_eq!(
+            parse(&batch.write_to_bytes().unwrap()).unwrap(),
+            vec![moved, mouse]
+        );
+    }
+
+    #[test]
+    fn test_wheel() {
+        let mut wheel = Wheel::default();
+        assert_eq!(wheel.add_y(&wheel_event(3, 0)), 3);
+        assert_eq!(wheel.add_y(&wheel_event(0, 60)), 0);
+        assert_eq!(wheel.add_y(&wheel_event(0, 60)), 1);
+        assert_eq!(wheel.add_y(&wheel_event(0, 300)), 2);
+        //余量60在换方向时丢弃
+        assert_eq!(wheel.add_y(&wheel_event(0, -100)), 0);
+        assert_eq!(wheel.add_y(&wheel_event(0, -20)), -1);
+        assert_eq!(wheel.add_x(&wheel_event(0, -240)), -2);
+    }
+}
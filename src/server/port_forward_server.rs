@@ -0,0 +1,120 @@
+//! 被控端的端口转发：按允许列表连接目标，或监听端口并把连接转发给主控端
+
+use std::{
+    net::{TcpListener, TcpStream},
+    sync::Arc,
+};
+
+use stream::{
+    message::{port_forward_message, ChannelType, ForwardListen, ForwardOpen, PortForwardMessage},
+    remote_channel::{ChannelOpener, ChannelReceiver, ChannelSender},
+    RemoteError, ResultType,
+};
+
+use crate::{
+    config::Config,
+    port_forward::{self, Kind, Tunnel},
+};
+
+///opener为建立该通道的会话，远程转发时用它为每个连接建立通道
+pub fn start(
+    mut sender: ChannelSender,
+    receiver: ChannelReceiver,
+    opener: ChannelOpener,
+) -> ResultType<()> {
+    match port_forward::recv(&receiver)? {
+        port_forward_message::Union::open(open) => {
+            let stream = match open_target(&open) {
+                Ok(stream) => stream,
+                Err(e) => {
+                    log::info!("端口转发拒绝:{}:{},{}", open.host, open.port, e);
+                    return port_forward::send_result(&mut sender, Err(e));
+                }
+            };
+            port_forward::send_result(&mut sender, Ok(()))?;
+            let tunnel = Tunnel::new(
+                Kind::Local,
+                String::new(),
+                format!("{}:{}", open.host, open.port),
+            );
+            port_forward::pump(stream, sender, receiver, tunnel)
+        }
+        port_forward_message::Union::listen(listen) => {
+            let listener = match listen_port(&listen) {
+                Ok(listener) => listener,
+                Err(e) => {
+                    log::info!("远程转发拒绝:{},{}", listen.port, e);
+                    return port_forward::send_result(&mut sender, Err(e));
+                }
+            };
+            port_forward::send_result(&mut sender, Ok(()))?;
+            serve_remote(listen, listener, receiver, opener)
+        }
+        _ => Err(RemoteError::InvalidData(String::from(
+            "端口转发通道的第一个消息不正确",
+        )))?,
+    }
+}
+
+fn open_target(open: &ForwardOpen) -> Result<TcpStream, String> {
+    if !port_forward::is_allowed(&Config::get_port_forward_targets(), &open.host, open.port) {
+        return Err(format!("不允许转发到:{}:{}", open.host, open.port));
+    }
+    port_forward::connect(&open.host, open.port)
+}
+
+fn listen_port(listen: &ForwardListen) -> Result<TcpListener, String> {
+    if !Config::get_port_forward_listen_ports().contains(&listen.port) || listen.port > 65535 {
+        return Err(format!("不允许监听端口:{}", listen.port));
+    }
+    //只监听本机地址
+    TcpListener::bind(("127.0.0.1", listen.port as u16)).map_err(|e| e.to_string())
+}
+
+//监听直到主控端关闭控制通道或者会话断开
+fn serve_remote(
+    listen: ForwardListen,
+    listener: TcpListener,
+    receiver: ChannelReceiver,
+    opener: ChannelOpener,
+) -> ResultType<()> {
+    let tunnel = Tunnel::new(
+        Kind::Remote,
+        format!("127.0.0.1:{}", listen.port),
+        format!("{}:{}", listen.host, listen.target_port),
+    );
+    {
+        let tunnel = tunnel.clone();
+        std::thread::spawn(move || {
+            while receiver.recv().is_ok() {}
+            tunnel.close();
+        });
+    }
+    let accept_tunnel = tunnel.clone();
+    port_forward::accept_loop(listener, accept_tunnel, move |stream| {
+        if let Err(e) = forward(stream, &listen, &opener, tunnel.clone()) {
+            log::info!("远程转发连接结束:{:?}", e);
+        }
+    })
+}
+
+fn forward(
+    stream: TcpStream,
+    listen: &ForwardListen,
+    opener: &ChannelOpener,
+    tunnel: Arc<Tunnel>,
+) -> ResultType<()> {
+    let (mut sender, receiver) = opener.create_channel(ChannelType::PortForward)?;
+    let channel_id = sender.channel_id();
+    let mut msg = PortForwardMessage::new();
+    msg.set_open(ForwardOpen {
+        host: listen.host.clone(),
+        port: listen.target_port,
+        ..Default::default()
+    });
+    let rs = port_forward::send(&mut sender, msg)
+        .and_then(|_| port_forward::wait_result(&receiver))
+        .and_then(|_| port_forward::pump(stream, sender, receiver, tunnel));
+    let _ = opener.destroy_channel(channel_id);
+    rs
+}
@@ -1,57 +1,102 @@
 use stream::{
     message::ChannelType,
+    permission::Permissions,
     relay::RelayServer,
-    remote_channel::{ChannelManager, ChannelReceiver, ChannelSender},
+    remote_channel::{ChannelManager, ChannelOpener, ChannelReceiver, ChannelSender},
+    utils::fingerprint,
     ResultType,
 };
 
 use crate::{
+    audit,
     config::Config,
-    server::{key_event_server, video_server},
+    server::{
+        clipboard_server, file_browser_server, file_transfer_server, key_event_server,
+        terminal_server, video_server,
+    },
 };
 
-use super::mouse_event_server;
+use super::{
+    approval, audio_server, chat_server, login_guard, mouse_event_server, port_forward_server,
+    second_factor,
+};
 
 
 pub fn control_server_callback(
     peer_id: String,
+    peer_pub_key: Vec<u8>,
     relay_id: String,
     relay_addr: String,
     relay_pub_key: Vec<u8>,
+    peer_addr: String,
 ) {
-    log::info!("服务端回调：{:?}", peer_id);
-    match control_server_callback_(peer_id, relay_id, relay_addr, relay_pub_key) {
-        Ok(_) => {}
-        Err(e) => {
-            log::info!("control_server_callback:{:?}", e);
-        }
-    };
+    log::info!("服务端回调：{:?},{}", peer_id, peer_addr);
+    //等待用户确认时不能阻塞和ID服务器的连接
+    std::thread::spawn(move || {
+        match control_server_callback_(
+            peer_id,
+            peer_pub_key,
+            relay_id,
+            relay_addr,
+            relay_pub_key,
+            peer_addr,
+        ) {
+            Ok(_) => {}
+            Err(e) => {
+                log::info!("control_server_callback:{:?}", e);
+            }
+        };
+    });
 }
 
 fn control_server_callback_(
     peer_id: String,
+    peer_pub_key: Vec<u8>,
     relay_id: String,
     relay_addr: String,
     relay_pub_key: Vec<u8>,
+    peer_addr: String,
 ) -> ResultType<()> {
     let my_id = Config::get_id();
-    let my_password = &Config::get_password_hash();
+    let mut credentials = Config::get_credentials();
+    //失败记录跨连接保存，重新连接不能继续猜密码
+    credentials.guard = Some(login_guard::guard());
+    //固定密码登录时还要验证码
+    if second_factor::is_enabled() {
+        credentials.second_factor = Some(second_factor::verify);
+    }
     let my_priv_key = Config::get_priv();
-    let framed = RelayServer::start(
+    //密码正确后按配置询问用户，用户可以减少授予的权限
+    let peer_fingerprint = fingerprint(&peer_pub_key);
+    let approve_peer = peer_id.clone();
+    let session_peer = peer_id.clone();
+    let approve_fn = move |permissions: Permissions| {
+        if Config::is_ask_before_accept() {
+            approval::ask(&approve_peer, &peer_fingerprint, permissions)
+        } else {
+            Some(permissions)
+        }
+    };
+    let (framed, permissions) = RelayServer::start(
         my_id,
-        my_password,
+        &credentials,
         &my_priv_key,
         relay_addr,
         relay_id,
         peer_id,
+        peer_addr,
         relay_pub_key,
+        approve_fn,
     )
     ?;
     log::info!("服务端建立通道");
+    audit::record(&session_peer, "login", &permissions.describe());
     //服务端啥也不管
     let _channel_manager = ChannelManager::new_relay(
         false,
         framed,
+        session_peer,
+        permissions,
         create_channel_callback,
         destroy_channel_callback,
     )?;
@@ -62,26 +107,87 @@ pub fn create_channel_callback(
     sender: Option<ChannelSender>,
     receiver: Option<ChannelReceiver>,
     channel_type: ChannelType,
+    opener: ChannelOpener,
 ) {
     log::info!("创建通道：{:?},sender:{:?}", channel_type, sender);
     match channel_type {
         ChannelType::NoDefine => {}
         ChannelType::Video => {
-            // std::thread::spawn(move || {
-            //     TOKIO_RUNTIME.block_on(async move {
-            //         let r = video_server::start(sender.unwrap()).await ;
-            //         log::info!("视频通道:{:?}",r);
-            //       });
-
-            // });
+            std::thread::spawn(move || {
+                let rs = video_server::start(sender.unwrap(), receiver.unwrap());
+                log::info!("视频通道:{:?}", rs);
+            });
         }
         ChannelType::KeyEvent => {
-            let rs = key_event_server::start(receiver.unwrap());
-            log::info!("创建通道：{:?},rs:{:?}", channel_type, rs);
+            std::thread::spawn(move || {
+                let rs = key_event_server::start(sender, receiver.unwrap());
+                log::info!("键盘通道:{:?}", rs);
+            });
         }
         ChannelType::MouseEvent => {
-            let rs =mouse_event_server::start(receiver.unwrap());
-            log::info!("创建通道：{:?},rs:{:?}", channel_type, rs);
+            std::thread::spawn(move || {
+                let rs = mouse_event_server::start(sender, receiver.unwrap());
+                log::info!("鼠标通道:{:?}", rs);
+            });
+        }
+        ChannelType::Clipboard => {
+            //旧版本主控端不会建立该通道
+            if let (Some(sender), Some(receiver)) = (sender, receiver) {
+                std::thread::spawn(move || {
+                    let rs = clipboard_server::start(sender, receiver);
+                    log::info!("剪贴板通道:{:?}", rs);
+                });
+            }
+        }
+        ChannelType::FileTransfer => {
+            if let (Some(sender), Some(receiver)) = (sender, receiver) {
+                std::thread::spawn(move || {
+                    let rs = file_transfer_server::start(sender, receiver);
+                    log::info!("文件传输通道:{:?}", rs);
+                });
+            }
+        }
+        ChannelType::FileBrowser => {
+            if let (Some(sender), Some(receiver)) = (sender, receiver) {
+                std::thread::spawn(move || {
+                    let rs = file_browser_server::start(sender, receiver);
+                    log::info!("文件浏览通道:{:?}", rs);
+                });
+            }
+        }
+        ChannelType::Terminal => {
+            if let (Some(sender), Some(receiver)) = (sender, receiver) {
+                std::thread::spawn(move || {
+                    let rs = terminal_server::start(sender, receiver);
+                    log::info!("终端通道:{:?}", rs);
+                });
+            }
+        }
+        ChannelType::PortForward => {
+            if let (Some(sender), Some(receiver)) = (sender, receiver) {
+                std::thread::spawn(move || {
+                    //远程转发时被控端用该会话的opener为每个连接建立通道
+                    let rs = port_forward_server::start(sender, receiver, opener);
+                    log::info!("端口转发通道:{:?}", rs);
+                });
+            }
+        }
+        ChannelType::Audio => {
+            if let (Some(sender), Some(receiver)) = (sender, receiver) {
+                std::thread::spawn(move || {
+                    let rs = audio_server::start(sender, receiver);
+                    log::info!("音频通道:{:?}", rs);
+                });
+            }
+        }
+        ChannelType::Chat => {
+            if let (Some(sender), Some(receiver)) = (sender, receiver) {
+                std::thread::spawn(move || {
+                    //聊天记录到建立该通道的会话的主控端
+                    let rs = chat_server::start(sender, receiver, opener.peer_id());
+                    log::info!("聊天通道:{:?}", rs);
+                });
+            }
         }
     }
 }
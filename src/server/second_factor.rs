@@ -0,0 +1,134 @@
+//! 固定密码的第二因素。用户在界面上生成密钥，用验证器应用添加后输入一次验证码启用；
+//! 登录时允许前后一个周期的时钟误差，同一个验证码只能用一次，短时间内错误过多时暂停验证
+
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use stream::{totp, RemoteError, ResultType};
+
+use crate::{audit, config::Config};
+
+//允许的时钟误差，前后各一个周期
+const WINDOW: u64 = 1;
+//统计错误次数的时长和其中允许的错误次数
+const FAILURE_PERIOD: Duration = Duration::from_secs(300);
+const MAX_FAILURES: usize = 5;
+const ISSUER: &str = "onedesk";
+
+#[derive(Default)]
+struct State {
+    //已使用过的最大周期序号，防止验证码被重放
+    last_counter: u64,
+    failures: Vec<Instant>,
+}
+
+lazy_static::lazy_static! {
+    //生成后还没有确认的密钥
+    static ref PENDING_SECRET:Mutex<Vec<u8>> = Mutex::new(Vec::new());
+    static ref STATE:Mutex<State> = Mutex::new(State::default());
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or(0)
+}
+
+pub fn is_enabled() -> bool {
+    !Config::get_totp_secret().is_empty()
+}
+
+///生成新的密钥，返回验证器应用使用的otpauth地址，确认后才生效
+pub fn begin_enroll() -> String {
+    let secret = totp::generate_secret();
+    let uri = totp::otpauth_uri(ISSUER, &Config::get_id(), &secret);
+    *PENDING_SECRET.lock().unwrap() = secret;
+    uri
+}
+
+///用验证器应用显示的验证码确认新密钥
+pub fn confirm_enroll(code: &str) -> ResultType<()> {
+    let mut pending = PENDING_SECRET.lock().unwrap();
+    if pending.is_empty() {
+        Err(RemoteError::InvalidData(String::from("请先生成密钥")))?
+    }
+    if totp::verify(&pending, code, unix_now(), WINDOW).is_none() {
+        Err(RemoteError::InvalidData(String::from("验证码错误")))?
+    }
+    Config::set_totp_secret(std::mem::take(&mut *pending));
+    *STATE.lock().unwrap() = State::default();
+    audit::record("local", "second_factor", "enabled");
+    Ok(())
+}
+
+pub fn disable() {
+    PENDING_SECRET.lock().unwrap().clear();
+    Config::set_totp_secret(Vec::new());
+    audit::record("local", "second_factor", "disabled");
+}
+
+///登录时校验主控端输入的验证码，作为Credentials::second_factor
+pub fn verify(code: &str) -> bool {
+    let secret = Config::get_totp_secret();
+    if secret.is_empty() {
+        return false;
+    }
+    check(
+        &mut STATE.lock().unwrap(),
+        &secret,
+        code,
+        Instant::now(),
+        unix_now(),
+    )
+}
+
+fn check(state: &mut State, secret: &[u8], code: &str, now: Instant, unix_now: u64) -> bool {
+    state
+        .failures
+        .retain(|time| now.saturating_duration_since(*time) < FAILURE_PERIOD);
+    if state.failures.len() >= MAX_FAILURES {
+        log::info!("验证码错误次数过多，暂停验证");
+        return false;
+    }
+    match totp::verify(secret, code, unix_now, WINDOW) {
+        Some(counter) if counter > state.last_counter => {
+            state.last_counter = counter;
+            true
+        }
+        _ => {
+            state.failures.push(now);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let secret = b"12345678901234567890";
+        let now = Instant::now();
+        let mut state = State::default();
+        assert!(check(&mut state, secret, "081804", now, 1111111109));
+        //同一个验证码不能再用
+        assert!(!check(&mut state, secret, "081804", now, 1111111109));
+        for _ in 0..MAX_FAILURES {
+            check(&mut state, secret, "000000", now, 1111111109);
+        }
+        //错误过多时正确的验证码也不通过，过一段时间恢复
+        let code = totp::code(secret, 1111111109 / totp::STEP + 1);
+        assert!(!check(&mut state, secret, &code, now, 1111111109));
+        assert!(check(
+            &mut state,
+            secret,
+            &code,
+            now + FAILURE_PERIOD,
+            1111111109
+        ));
+    }
+}
@@ -0,0 +1,154 @@
+//! 远程终端，在PTY中运行配置的shell，不依赖显示器，无图形界面的服务器也可以使用
+
+use std::{
+    io::{Read, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use portable_pty::{native_pty_system, ChildKiller, CommandBuilder, MasterPty, PtySize};
+use stream::{
+    message::{terminal_message, TerminalExit, TerminalMessage, TerminalSize},
+    protobuf::Message,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    RemoteError, ResultType,
+};
+
+use crate::config::Config;
+
+struct Pty {
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    killer: Box<dyn ChildKiller + Send + Sync>,
+    exited: Arc<AtomicBool>,
+}
+
+fn pty_size(size: &TerminalSize) -> PtySize {
+    PtySize {
+        rows: size.rows.clamp(1, u16::MAX as u32) as u16,
+        cols: size.cols.clamp(1, u16::MAX as u32) as u16,
+        pixel_width: 0,
+        pixel_height: 0,
+    }
+}
+
+fn send(sender: &mut ChannelSender, msg: TerminalMessage) -> ResultType<()> {
+    sender.send(msg.write_to_bytes()?)
+}
+
+fn send_exit(sender: &mut ChannelSender, code: i32, error: String) -> ResultType<()> {
+    let mut msg = TerminalMessage::new();
+    msg.set_exit(TerminalExit {
+        code,
+        error,
+        ..Default::default()
+    });
+    send(sender, msg)
+}
+
+fn to_error<E: std::fmt::Display>(e: E) -> RemoteError {
+    RemoteError::Channel(e.to_string())
+}
+
+fn spawn(sender: ChannelSender, size: &TerminalSize) -> ResultType<Pty> {
+    let pair = native_pty_system()
+        .openpty(pty_size(size))
+        .map_err(to_error)?;
+    let shell = Config::get_terminal_shell();
+    let command = if shell.is_empty() {
+        CommandBuilder::new_default_prog()
+    } else {
+        CommandBuilder::new(shell)
+    };
+    let mut child = pair.slave.spawn_command(command).map_err(to_error)?;
+    //关闭本进程持有的从端，shell退出后读取主端才会结束
+    drop(pair.slave);
+    let mut reader = pair.master.try_clone_reader().map_err(to_error)?;
+    let writer = pair.master.take_writer().map_err(to_error)?;
+    let killer = child.clone_killer();
+    let exited = Arc::new(AtomicBool::new(false));
+    {
+        let exited = exited.clone();
+        let mut sender = sender;
+        std::thread::spawn(move || {
+            let mut buf = vec![0; 16 * 1024];
+            loop {
+                match reader.read(&mut buf) {
+                    //Linux下shell退出后读取返回EIO
+                    Ok(0) | Err(_) => break,
+                    Ok(len) => {
+                        let mut msg = TerminalMessage::new();
+                        msg.set_output(buf[..len].to_vec());
+                        if send(&mut sender, msg).is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+            let rs = match child.wait() {
+                Ok(status) => send_exit(&mut sender, status.exit_code() as i32, String::new()),
+                Err(e) => send_exit(&mut sender, -1, e.to_string()),
+            };
+            exited.store(true, Ordering::SeqCst);
+            log::info!("终端退出:{:?}", rs);
+        });
+    }
+    Ok(Pty {
+        master: pair.master,
+        writer,
+        killer,
+        exited,
+    })
+}
+
+pub fn start(mut sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+    let mut pty: Option<Pty> = None;
+    while let Ok(data) = receiver.recv() {
+        let msg = TerminalMessage::parse_from_bytes(&data)?;
+        match msg.union {
+            Some(terminal_message::Union::open(size)) => {
+                if !Config::is_terminal_enabled() {
+                    send_exit(&mut sender, -1, String::from("被控端未开启远程终端"))?;
+                    continue;
+                }
+                if pty
+                    .as_ref()
+                    .map_or(false, |pty| !pty.exited.load(Ordering::SeqCst))
+                {
+                    continue;
+                }
+                match spawn(sender.clone(), &size) {
+                    Ok(new_pty) => {
+                        log::info!("打开终端:{}x{}", size.cols, size.rows);
+                        pty = Some(new_pty);
+                    }
+                    Err(e) => send_exit(&mut sender, -1, e.to_string())?,
+                }
+            }
+            Some(terminal_message::Union::input(input)) => {
+                if let Some(pty) = pty.as_mut() {
+                    if let Err(e) = pty.writer.write_all(&input) {
+                        log::info!("终端输入失败:{:?}", e);
+                    }
+                }
+            }
+            Some(terminal_message::Union::resize(size)) => {
+                if let Some(pty) = pty.as_ref() {
+                    if let Err(e) = pty.master.resize(pty_size(&size)) {
+                        log::info!("调整终端大小失败:{:?}", e);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    //主控端断开时结束shell
+    if let Some(mut pty) = pty {
+        if !pty.exited.load(Ordering::SeqCst) {
+            let _ = pty.killer.kill();
+        }
+    }
+    Ok(())
+}
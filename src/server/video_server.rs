@@ -1,71 +1,601 @@
 use std::{
+    io::ErrorKind::WouldBlock,
+    ptr::null_mut,
+    sync::mpsc::TryRecvError,
     thread,
     time::{Duration, Instant},
 };
 
+use convert::convert::FilterMode;
+use openh264_sys::{
+    videoFormatI420, videoFrameTypeSkip, ISVCEncoderVtbl, SEncParamBase, SFrameBSInfo,
+    SSourcePicture, WelsCreateSVCEncoder, WelsDestroySVCEncoder, RC_BITRATE_MODE,
+    SCREEN_CONTENT_REAL_TIME,
+};
 use scrap::{Capturer, Display};
-use std::io::ErrorKind::WouldBlock;
 use stream::{
-    message::ChannelType,
-    relay::RelayServer,
-    remote_channel::{ChannelManager, ChannelReceiver, ChannelSender},
-    tokio,
-    utils::RsaPrivKey,
-    ResultType,
+    message::{video_message, VideoFrame, VideoMessage},
+    protobuf::Message,
+    remote_channel::{ChannelReceiver, ChannelSender},
+    RemoteError, ResultType,
+};
+
+use super::{
+    display::{self, DisplayGeometry, Rect, ALL_DISPLAYS},
+    media_clock,
 };
-// use crate::x264_utils::*;
-pub fn start(sender: ChannelSender) -> ResultType<()> {
-
-    // let display = Display::primary().unwrap();
-    // let mut capturer = Capturer::new(display).expect("Couldn't begin capture.");
-    // let (width, height) = (capturer.width(), capturer.height());
-
-    // let mut par = Param::default_preset("superfast", "zerolatency").unwrap();
-
-    // par = par.set_dimension(height, width);
-    // // par = par.param_parse("repeat_headers", "1").unwrap();
-    // // par = par.param_parse("annexb", "1").unwrap();
-    // // par = par.apply_profile("high").unwrap();
-    // let mut pic = Picture::from_param(&par).unwrap();
-    // let mut enc = Encoder::open(&mut par).unwrap();
-
-    // let one_second = Duration::new(1, 0);
-    // let fps = 60;
-    // let one_frame = one_second / fps;
-    // let mut timestamp = 0;
-    // let r = pic.as_mut();
-    // log::info!("开始编码");
-    // loop {
-
-    //     let now = Instant::now();
-    //     let frame = match capturer.frame() {
-    //         Ok(buffer) => buffer,
-    //         Err(error) => {
-    //             if error.kind() == WouldBlock {
-    //                 continue;
-    //             } else {
-    //                 panic!("Error: {}", error);
-    //             }
-    //         }
-    //     };
-    //     convert::convert::bgra_to_i420(width, height, &frame,r[0],r[1],r[2]);
-    //     pic = pic.set_timestamp(timestamp);
-    //     if let Ok(Some((nal, _, _))) = enc.encode(&pic) {
-    //         let buf = nal.vec;
-    //         println!("encode {:?}",buf.len());
-
-    //         sender.send(buf).await?;
-
-    //     }
-    //     println!("encode time:{:?}",now.elapsed());
-    //     let elp = now.elapsed();
-    //     if elp < one_frame {
-    //         let sleep1 = one_frame - now.elapsed();
-    //         thread::sleep(sleep1);
-    //     }
-    //     timestamp += 1;
-    //     // break;
-    // }
-    // log::info!("视频通道停止");
-    Ok(())
+
+const FPS: u32 = 30;
+const BITRATE: i32 = 5_000_000;
+//带宽不足时逐级降低的最大分辨率，第一档为不限制
+const BANDWIDTH_LEVELS: &[(u32, u32)] = &[(0, 0), (1920, 1080), (1280, 720), (960, 540)];
+
+#[derive(Debug, Clone, Copy, Default)]
+struct VideoSettings {
+    //主控端要求的最大分辨率，0表示不限制
+    max_width: u32,
+    max_height: u32,
+    //带宽档位，BANDWIDTH_LEVELS的下标
+    bandwidth_level: usize,
+}
+
+impl VideoSettings {
+    fn limit(&self) -> (u32, u32) {
+        let (level_width, level_height) = BANDWIDTH_LEVELS[self.bandwidth_level];
+        (
+            min_limit(self.max_width, level_width),
+            min_limit(self.max_height, level_height),
+        )
+    }
+}
+
+fn min_limit(a: u32, b: u32) -> u32 {
+    match (a, b) {
+        (0, b) => b,
+        (a, 0) => a,
+        (a, b) => a.min(b),
+    }
+}
+
+///按比例缩小到限制范围内，不放大，宽高取偶数
+fn target_size(width: u32, height: u32, max_width: u32, max_height: u32) -> (usize, usize) {
+    let mut scale = 1f64;
+    if max_width > 0 && width > max_width {
+        scale = scale.min(max_width as f64 / width as f64);
+    }
+    if max_height > 0 && height > max_height {
+        scale = scale.min(max_height as f64 / height as f64);
+    }
+    let w = ((width as f64 * scale).round() as usize & !1).max(2);
+    let h = ((height as f64 * scale).round() as usize & !1).max(2);
+    (w, h)
+}
+
+enum LoopEvent {
+    SwitchDisplay(i32),
+    //分辨率变化，需要重建编码器
+    Rebuild,
+    Closed,
+}
+
+///根据发送阻塞的时间估算带宽是否够用
+struct BandwidthEstimator {
+    window_start: Instant,
+    blocked: Duration,
+    bytes: usize,
+    //最近一次拥塞的时间
+    congested_at: Instant,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum BandwidthAdjust {
+    Down,
+    Up,
+}
+
+impl BandwidthEstimator {
+    const WINDOW: Duration = Duration::from_secs(1);
+    const RECOVER: Duration = Duration::from_secs(10);
+
+    fn new() -> Self {
+        let now = Instant::now();
+        Self {
+            window_start: now,
+            blocked: Duration::default(),
+            bytes: 0,
+            congested_at: now,
+        }
+    }
+    fn on_send(&mut self, bytes: usize, blocked: Duration) {
+        self.bytes += bytes;
+        self.blocked += blocked;
+    }
+    fn check(&mut self) -> Option<BandwidthAdjust> {
+        let elapsed = self.window_start.elapsed();
+        if elapsed < Self::WINDOW {
+            return None;
+        }
+        //超过两成时间阻塞在发送上，说明网络跟不上
+        let congested = self.blocked.as_secs_f64() > elapsed.as_secs_f64() * 0.2;
+        log::debug!(
+            "视频发送 {} B/s, 阻塞 {:?}",
+            self.bytes as f64 / elapsed.as_secs_f64(),
+            self.blocked
+        );
+        self.window_start = Instant::now();
+        self.blocked = Duration::default();
+        self.bytes = 0;
+        if congested {
+            self.congested_at = Instant::now();
+            Some(BandwidthAdjust::Down)
+        } else if self.congested_at.elapsed() > Self::RECOVER {
+            self.congested_at = Instant::now();
+            Some(BandwidthAdjust::Up)
+        } else {
+            None
+        }
+    }
+}
+
+pub fn start(mut sender: ChannelSender, receiver: ChannelReceiver) -> ResultType<()> {
+    let displays = display::all()?;
+    let mut current = displays
+        .iter()
+        .find(|d| d.primary)
+        .map(|d| d.id)
+        .unwrap_or(0);
+    let mut settings = VideoSettings::default();
+    send_display_list(&mut sender, &displays, current)?;
+    let rs = loop {
+        match capture_loop(&mut sender, &receiver, &displays, current, &mut settings) {
+            Ok(LoopEvent::SwitchDisplay(id)) => {
+                log::info!("切换显示器:{}", id);
+                current = id;
+                send_display_list(&mut sender, &displays, current)?;
+            }
+            Ok(LoopEvent::Rebuild) => {
+                log::info!("调整分辨率:{:?}", settings);
+            }
+            Ok(LoopEvent::Closed) => break Ok(()),
+            Err(e) => break Err(e),
+        }
+    };
+    display::clear_current();
+    log::info!("视频通道停止");
+    rs
+}
+
+fn send_display_list(
+    sender: &mut ChannelSender,
+    displays: &[DisplayGeometry],
+    current: i32,
+) -> ResultType<()> {
+    let mut msg = VideoMessage::new();
+    msg.set_display_list(display::to_display_list(displays, current));
+    sender.send(msg.write_to_bytes()?)
+}
+
+///采集编码当前显示器，直到需要切换显示器、调整分辨率或者通道关闭
+fn capture_loop(
+    sender: &mut ChannelSender,
+    receiver: &ChannelReceiver,
+    displays: &[DisplayGeometry],
+    current: i32,
+    settings: &mut VideoSettings,
+) -> ResultType<LoopEvent> {
+    let mut source = CaptureSource::new(displays, current)?;
+    let rect = source.rect();
+    //以采集到的画面大小为准，HiDPI下和显示器区域（逻辑坐标）不同
+    let (frame_width, frame_height) = source.size();
+    //I420要求宽高为偶数
+    let width = frame_width & !1;
+    let height = frame_height & !1;
+    if width == 0 || height == 0 {
+        Err(RemoteError::InvalidData(format!("画面大小无效:{:?}", rect)))?
+    }
+    let (max_width, max_height) = settings.limit();
+    let (dst_width, dst_height) = target_size(width as u32, height as u32, max_width, max_height);
+    display::set_current(current, rect, dst_width as u32, dst_height as u32);
+    let mut encoder = H264Encoder::new(dst_width, dst_height)?;
+    let mut estimator = BandwidthEstimator::new();
+    let one_frame = Duration::from_secs(1) / FPS;
+    loop {
+        let now = Instant::now();
+        match receiver.try_recv() {
+            Ok(data) => {
+                let msg = VideoMessage::parse_from_bytes(&data)?;
+                match msg.union {
+                    Some(video_message::Union::switch_display(switch)) => {
+                        if switch.id == ALL_DISPLAYS
+                            || displays.iter().any(|display| display.id == switch.id)
+                        {
+                            return Ok(LoopEvent::SwitchDisplay(switch.id));
+                        }
+                        log::info!("显示器不存在:{}", switch.id);
+                    }
+                    Some(video_message::Union::set_resolution(resolution)) => {
+                        settings.max_width = resolution.width;
+                        settings.max_height = resolution.height;
+                        return Ok(LoopEvent::Rebuild);
+                    }
+                    _ => {}
+                }
+            }
+            Err(TryRecvError::Empty) => {}
+            Err(TryRecvError::Disconnected) => return Ok(LoopEvent::Closed),
+        }
+        if let Some(frame) = source.frame()? {
+            //按偶数行截取，行宽由数据长度推算
+            let frame = &frame[..frame_width * 4 * height];
+            let (mut y, mut u, mut v) = convert::convert::bgra_to_i420_n(width, height, frame);
+            if (dst_width, dst_height) != (width, height) {
+                match convert::convert::i420_scale(
+                    width,
+                    height,
+                    &y,
+                    &u,
+                    &v,
+                    dst_width,
+                    dst_height,
+                    FilterMode::Bilinear,
+                ) {
+                    Some((sy, su, sv)) => {
+                        y = sy;
+                        u = su;
+                        v = sv;
+                    }
+                    None => Err(RemoteError::InvalidData(String::from("视频缩放失败")))?,
+                }
+            }
+            //和音频使用同一个时钟，主控端据此同步
+            let timestamp = media_clock::now();
+            if let Some(data) = encoder.encode(&y, &u, &v, timestamp)? {
+                let mut msg = VideoMessage::new();
+                msg.set_video_frame(VideoFrame {
+                    data,
+                    timestamp,
+                    ..Default::default()
+                });
+                let bytes = msg.write_to_bytes()?;
+                let len = bytes.len();
+                let send_time = Instant::now();
+                sender.send(bytes)?;
+                estimator.on_send(len, send_time.elapsed());
+            }
+        }
+        match estimator.check() {
+            Some(BandwidthAdjust::Down)
+                if settings.bandwidth_level + 1 < BANDWIDTH_LEVELS.len() =>
+            {
+                settings.bandwidth_level += 1;
+                return Ok(LoopEvent::Rebuild);
+            }
+            Some(BandwidthAdjust::Up) if settings.bandwidth_level > 0 => {
+                settings.bandwidth_level -= 1;
+                return Ok(LoopEvent::Rebuild);
+            }
+            _ => {}
+        }
+        let elapsed = now.elapsed();
+        if elapsed < one_frame {
+            thread::sleep(one_frame - elapsed);
+        }
+    }
+}
+
+enum CaptureSource {
+    //采集器、画面中的裁剪区域和显示器区域
+    Single(Capturer, Option<Rect>, Rect),
+    //所有显示器合成到一张画布上，画布按桌面坐标排列
+    All(Vec<(Capturer, Rect)>, Rect, Vec<u8>),
+}
+
+impl CaptureSource {
+    fn new(displays: &[DisplayGeometry], id: i32) -> ResultType<Self> {
+        let mut screens: Vec<Option<Display>> = Display::all()?.into_iter().map(Some).collect();
+        if id == ALL_DISPLAYS {
+            let mut capturers = Vec::new();
+            for display in displays {
+                //X11下同一屏幕上的多个显示器只采集一次
+                let screen = match screens.get_mut(display.screen).and_then(Option::take) {
+                    Some(screen) => screen,
+                    None => continue,
+                };
+                let capturer = Capturer::new(screen)?;
+                let rect = match display.crop {
+                    //屏幕画面的坐标即桌面坐标
+                    Some(_) => Rect {
+                        x: 0,
+                        y: 0,
+                        width: capturer.width() as u32,
+                        height: capturer.height() as u32,
+                    },
+                    None => display.rect,
+                };
+                capturers.push((capturer, rect));
+            }
+            let rect = display::bounding_rect(displays);
+            let canvas = vec![0; rect.width as usize * rect.height as usize * 4];
+            return Ok(CaptureSource::All(capturers, rect, canvas));
+        }
+        let display = displays.iter().find(|display| display.id == id);
+        let screen = display.and_then(|display| screens.get_mut(display.screen)?.take());
+        match (display, screen) {
+            (Some(display), Some(screen)) => Ok(CaptureSource::Single(
+                Capturer::new(screen)?,
+                display.crop,
+                display.rect,
+            )),
+            _ => Err(RemoteError::InvalidData(format!("显示器不存在:{}", id)))?,
+        }
+    }
+    fn rect(&self) -> Rect {
+        match self {
+            CaptureSource::Single(_, _, rect) => *rect,
+            CaptureSource::All(_, rect, _) => *rect,
+        }
+    }
+    ///frame()返回的画面大小
+    fn size(&self) -> (usize, usize) {
+        match self {
+            CaptureSource::Single(capturer, crop, _) => {
+                let (_, _, width, height) = clip(*crop, capturer.width(), capturer.height());
+                (width, height)
+            }
+            CaptureSource::All(_, rect, _) => (rect.width as usize, rect.height as usize),
+        }
+    }
+    ///返回紧密排列的BGRA数据，没有新画面时返回None
+    fn frame(&mut self) -> ResultType<Option<Vec<u8>>> {
+        match self {
+            CaptureSource::Single(capturer, crop, _) => {
+                let (width, height) = (capturer.width(), capturer.height());
+                match capturer.frame() {
+                    Ok(frame) => Ok(Some(crop_frame(&frame, width, height, *crop))),
+                    Err(e) if e.kind() == WouldBlock => Ok(None),
+                    Err(e) => Err(e)?,
+                }
+            }
+            CaptureSource::All(capturers, bounding, canvas) => {
+                let mut updated = false;
+                for (capturer, rect) in capturers.iter_mut() {
+                    let (width, height) = (capturer.width(), capturer.height());
+                    let frame = match capturer.frame() {
+                        Ok(frame) => crop_frame(&frame, width, height, None),
+                        Err(e) if e.kind() == WouldBlock => continue,
+                        Err(e) => Err(e)?,
+                    };
+                    //缩放比例不同的显示器先缩放到桌面坐标下的大小
+                    let (dst_width, dst_height) = (rect.width as usize, rect.height as usize);
+                    let frame = if (width, height) == (dst_width, dst_height) {
+                        frame
+                    } else {
+                        match convert::convert::argb_scale(
+                            &frame,
+                            width * 4,
+                            width,
+                            height,
+                            dst_width,
+                            dst_height,
+                            FilterMode::Bilinear,
+                        ) {
+                            Some(frame) => frame,
+                            None => Err(RemoteError::InvalidData(String::from("画面缩放失败")))?,
+                        }
+                    };
+                    blit(
+                        canvas,
+                        bounding.width as usize,
+                        &frame,
+                        dst_width,
+                        dst_height,
+                        rect.x as i64 - bounding.x as i64,
+                        rect.y as i64 - bounding.y as i64,
+                    );
+                    updated = true;
+                }
+                if updated {
+                    Ok(Some(canvas.clone()))
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+    }
+}
+
+///裁剪区域限制在画面内，返回(x, y, width, height)
+fn clip(crop: Option<Rect>, width: usize, height: usize) -> (usize, usize, usize, usize) {
+    let crop = match crop {
+        Some(crop) => crop,
+        None => return (0, 0, width, height),
+    };
+    let left = (crop.x.max(0) as usize).min(width);
+    let top = (crop.y.max(0) as usize).min(height);
+    let right = ((crop.x as i64 + crop.width as i64).max(0) as usize).min(width);
+    let bottom = ((crop.y as i64 + crop.height as i64).max(0) as usize).min(height);
+    (
+        left,
+        top,
+        right.saturating_sub(left),
+        bottom.saturating_sub(top),
+    )
+}
+
+///采集的画面每行可能有填充，按行宽推算后取出裁剪区域
+fn crop_frame(frame: &[u8], width: usize, height: usize, crop: Option<Rect>) -> Vec<u8> {
+    let (left, top, crop_width, crop_height) = clip(crop, width, height);
+    let mut rs = Vec::with_capacity(crop_width * crop_height * 4);
+    if height == 0 {
+        return rs;
+    }
+    let stride = frame.len() / height;
+    if stride < width * 4 {
+        return vec![0; crop_width * crop_height * 4];
+    }
+    for row in top..top + crop_height {
+        let start = row * stride + left * 4;
+        rs.extend_from_slice(&frame[start..start + crop_width * 4]);
+    }
+    rs
+}
+
+///把紧密排列的画面复制到画布的(left, top)处，超出画布的部分丢弃
+fn blit(
+    canvas: &mut [u8],
+    canvas_width: usize,
+    src: &[u8],
+    width: usize,
+    height: usize,
+    left: i64,
+    top: i64,
+) {
+    if canvas_width == 0 {
+        return;
+    }
+    let canvas_height = canvas.len() / (canvas_width * 4);
+    let start_x = left.max(0);
+    let end_x = (left + width as i64).min(canvas_width as i64);
+    if start_x >= end_x {
+        return;
+    }
+    let len = (end_x - start_x) as usize * 4;
+    for row in 0..height {
+        let y = top + row as i64;
+        if y < 0 || y >= canvas_height as i64 {
+            continue;
+        }
+        let src_start = (row * width + (start_x - left) as usize) * 4;
+        let dst_start = (y as usize * canvas_width + start_x as usize) * 4;
+        canvas[dst_start..dst_start + len].copy_from_slice(&src[src_start..src_start + len]);
+    }
+}
+
+struct H264Encoder {
+    encoder: *mut *const ISVCEncoderVtbl,
+    width: usize,
+    height: usize,
+}
+
+impl H264Encoder {
+    fn new(width: usize, height: usize) -> ResultType<Self> {
+        let mut encoder = null_mut();
+        unsafe {
+            if WelsCreateSVCEncoder(&mut encoder) != 0 || encoder.is_null() {
+                Err(RemoteError::InvalidData(String::from("创建编码器失败")))?
+            }
+            let param = SEncParamBase {
+                iUsageType: SCREEN_CONTENT_REAL_TIME,
+                iPicWidth: width as i32,
+                iPicHeight: height as i32,
+                iTargetBitrate: BITRATE,
+                iRCMode: RC_BITRATE_MODE,
+                fMaxFrameRate: FPS as f32,
+                ..Default::default()
+            };
+            if (**encoder).Initialize.unwrap()(encoder, &param) != 0 {
+                WelsDestroySVCEncoder(encoder);
+                Err(RemoteError::InvalidData(String::from("初始化编码器失败")))?
+            }
+        }
+        Ok(Self {
+            encoder,
+            width,
+            height,
+        })
+    }
+    fn encode(
+        &mut self,
+        y: &[u8],
+        u: &[u8],
+        v: &[u8],
+        timestamp: u64,
+    ) -> ResultType<Option<Vec<u8>>> {
+        let mut pic = SSourcePicture::default();
+        pic.iColorFormat = videoFormatI420 as i32;
+        pic.iPicWidth = self.width as i32;
+        pic.iPicHeight = self.height as i32;
+        pic.iStride[0] = self.width as i32;
+        pic.iStride[1] = (self.width >> 1) as i32;
+        pic.iStride[2] = (self.width >> 1) as i32;
+        pic.pData[0] = y.as_ptr() as *mut u8;
+        pic.pData[1] = u.as_ptr() as *mut u8;
+        pic.pData[2] = v.as_ptr() as *mut u8;
+        pic.uiTimeStamp = timestamp as i64;
+        let mut info = SFrameBSInfo::default();
+        unsafe {
+            if (**self.encoder).EncodeFrame.unwrap()(self.encoder, &pic, &mut info) != 0 {
+                Err(RemoteError::InvalidData(String::from("视频编码失败")))?
+            }
+            if info.eFrameType == videoFrameTypeSkip {
+                return Ok(None);
+            }
+            let mut out = Vec::new();
+            for layer in &info.sLayerInfo[..info.iLayerNum as usize] {
+                let nal_len: i32 =
+                    std::slice::from_raw_parts(layer.pNalLengthInByte, layer.iNalCount as usize)
+                        .iter()
+                        .sum();
+                out.extend_from_slice(std::slice::from_raw_parts(layer.pBsBuf, nal_len as usize));
+            }
+            Ok(Some(out))
+        }
+    }
+}
+
+impl Drop for H264Encoder {
+    fn drop(&mut self) {
+        unsafe {
+            (**self.encoder).Uninitialize.unwrap()(self.encoder);
+            WelsDestroySVCEncoder(self.encoder);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_size() {
+        assert_eq!(target_size(3840, 2160, 1920, 1080), (1920, 1080));
+        assert_eq!(target_size(3840, 2160, 1366, 768), (1364, 768));
+        assert_eq!(target_size(1280, 720, 1920, 1080), (1280, 720));
+        assert_eq!(target_size(2560, 1440, 0, 0), (2560, 1440));
+        assert_eq!(target_size(2560, 1600, 1920, 0), (1920, 1200));
+    }
+
+    #[test]
+    fn test_settings_limit() {
+        let mut settings = VideoSettings::default();
+        assert_eq!(settings.limit(), (0, 0));
+        settings.max_width = 1366;
+        settings.max_height = 768;
+        settings.bandwidth_level = 2;
+        assert_eq!(settings.limit(), (1280, 720));
+        settings.bandwidth_level = 1;
+        assert_eq!(settings.limit(), (1366, 768));
+    }
+
+    #[test]
+    fn test_crop_blit() {
+        //每行4个像素，带一个像素的填充
+        let frame: Vec<u8> = (0..3 * 5 * 4).map(|i| (i / 4) as u8).collect();
+        let crop = Rect {
+            x: 1,
+            y: 1,
+            width: 5,
+            height: 5,
+        };
+        //超出画面的部分被裁掉
+        assert_eq!(clip(Some(crop), 4, 3), (1, 1, 3, 2));
+        let cropped = crop_frame(&frame, 4, 3, Some(crop));
+        let pixels: Vec<u8> = cropped.chunks(4).map(|pixel| pixel[0]).collect();
+        assert_eq!(pixels, vec![6, 7, 8, 11, 12, 13]);
+        //放在画布左上角外面时只复制重叠的部分
+        let mut canvas = vec![0; 2 * 2 * 4];
+        blit(&mut canvas, 2, &cropped, 3, 2, -2, 1);
+        let pixels: Vec<u8> = canvas.chunks(4).map(|pixel| pixel[0]).collect();
+        assert_eq!(pixels, vec![0, 0, 8, 0]);
+    }
 }
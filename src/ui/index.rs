@@ -1,8 +1,33 @@
 use sciter::dom::event::*;
 use sciter::{Element, HELEMENT};
+use stream::id_conn::IdClient;
+use stream::permission::Permissions;
 use stream::protobuf::Message;
+use stream::ResultType;
 
-use crate::config::Config;
+use crate::client::presence;
+use crate::config::{Config, Peer};
+use crate::server::{approval, chat_server, login_guard, second_factor};
+use crate::ui::remote::chat_value;
+
+fn peers_value(peers: Vec<Peer>) -> sciter::Value {
+    let mut list = sciter::Value::array(0);
+    for peer in peers {
+        let mut item = sciter::Value::map();
+        item.set_item("has_password", peer.has_password());
+        item.set_item("tags", peer.tags.join(","));
+        item.set_item("alias", peer.alias);
+        item.set_item("last_connected", peer.last_connected as f64);
+        item.set_item("id", peer.id);
+        //还没有查询结果时不设置
+        if let Some(online) = presence::is_online(&peer.id) {
+            item.set_item("online", online);
+        }
+        item.set_item("fingerprint", peer.fingerprint);
+        list.push(item);
+    }
+    list
+}
 
 pub struct IndexWindow {
     sender: stream::tokio::sync::mpsc::Sender<Vec<u8>>,
@@ -33,6 +58,189 @@ impl IndexWindow {
     fn update_password(&self) -> String {
         Config::update_password()
     }
+    fn is_permanent_password_set(&self) -> bool {
+        Config::is_permanent_password_set()
+    }
+    //固定密码用于无人值守，为空时清除，返回错误信息
+    fn set_permanent_password(&self, password: String) -> String {
+        match Config::set_permanent_password(&password) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    fn is_view_password_set(&self) -> bool {
+        Config::is_view_password_set()
+    }
+    //仅查看密码，主控端用它登录时只能看不能操作
+    fn set_view_password(&self, password: String) -> String {
+        match Config::set_view_password(&password) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    fn is_second_factor_enabled(&self) -> bool {
+        second_factor::is_enabled()
+    }
+    //生成验证码密钥，返回otpauth地址，输入验证码确认后才启用
+    fn begin_second_factor(&self) -> String {
+        second_factor::begin_enroll()
+    }
+    //返回错误信息
+    fn confirm_second_factor(&self, code: String) -> String {
+        match second_factor::confirm_enroll(&code) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    fn disable_second_factor(&self) {
+        second_factor::disable();
+    }
+    //有登录失败记录的代号和来源地址
+    fn get_lockouts(&self) -> sciter::Value {
+        let mut list = sciter::Value::array(0);
+        for lockout in login_guard::list() {
+            let mut item = sciter::Value::map();
+            item.set_item("kind", lockout.kind);
+            item.set_item("key", lockout.key);
+            item.set_item("failures", lockout.failures as i32);
+            item.set_item("remaining", lockout.remaining as i32);
+            list.push(item);
+        }
+        list
+    }
+    //解除所有锁定
+    fn clear_lockouts(&self) {
+        login_guard::clear();
+    }
+    //各项权限的值和名称
+    fn get_permission_items(&self) -> sciter::Value {
+        let mut list = sciter::Value::array(0);
+        for (permission, name) in Permissions::ITEMS.iter() {
+            let mut item = sciter::Value::map();
+            item.set_item("bit", permission.bits() as i32);
+            item.set_item("name", *name);
+            list.push(item);
+        }
+        list
+    }
+    //设备密码授予的权限
+    fn get_permissions(&self) -> i32 {
+        Config::get_permissions().bits() as i32
+    }
+    fn set_permissions(&self, bits: i32) {
+        Config::set_permissions(Permissions::from_bits(bits as u32));
+    }
+    fn is_terminal_enabled(&self) -> bool {
+        Config::is_terminal_enabled()
+    }
+    //是否允许主控端打开终端
+    fn set_terminal_enabled(&self, enabled: bool) {
+        Config::set_terminal_enabled(enabled);
+    }
+    fn is_ask_before_accept(&self) -> bool {
+        Config::is_ask_before_accept()
+    }
+    //主控端密码正确后是否还要确认
+    fn set_ask_before_accept(&self, ask: bool) {
+        Config::set_ask_before_accept(ask);
+    }
+    //等待确认的连接，没有时返回空
+    fn get_approval(&self) -> sciter::Value {
+        match approval::pending() {
+            Some(request) => {
+                let mut item = sciter::Value::map();
+                item.set_item("id", request.id as f64);
+                item.set_item("peer_id", request.peer_id);
+                item.set_item("fingerprint", request.fingerprint);
+                item.set_item("permissions", request.permissions.bits() as i32);
+                item.set_item("view_only", Permissions::VIEW_ONLY.bits() as i32);
+                item.set_item("remaining", request.remaining as i32);
+                item
+            }
+            None => sciter::Value::null(),
+        }
+    }
+    //bits为授予的权限，0为拒绝
+    fn answer_approval(&self, id: f64, bits: i32) {
+        let granted = Some(Permissions::from_bits(bits as u32))
+            .filter(|granted| *granted != Permissions::NONE);
+        approval::answer(id as u64, granted);
+    }
+    //和主控端聊天，返回错误信息
+    fn chat_send(&self, text: String) -> String {
+        match chat_server::send(&text) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    fn chat_history(&self) -> sciter::Value {
+        chat_value(chat_server::history())
+    }
+    fn is_chat_connected(&self) -> bool {
+        chat_server::is_connected()
+    }
+    //地址簿，按最近连接的时间排序
+    fn get_peers(&self) -> sciter::Value {
+        peers_value(Config::get_peers())
+    }
+    fn get_recent_peers(&self) -> sciter::Value {
+        peers_value(Config::get_recent_peers())
+    }
+    //tags用逗号分隔
+    fn save_peer(&self, id: String, alias: String, tags: String) {
+        let tags = tags
+            .split(|c| c == ',' || c == '，')
+            .map(|tag| tag.trim().to_string())
+            .filter(|tag| !tag.is_empty())
+            .collect();
+        Config::save_peer(id.trim(), alias.trim(), tags);
+    }
+    fn remove_peer(&self, id: String) {
+        Config::remove_peer(&id);
+    }
+    //为空时清除，返回错误信息
+    fn set_peer_password(&self, id: String, password: String) -> String {
+        match Config::set_peer_password(&id, &password) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    fn forget_peer_fingerprint(&self, id: String) {
+        Config::forget_peer_fingerprint(&id);
+    }
+    //通过ID服务器发送
+    fn send(&self, bytes: ResultType<Vec<u8>>) {
+        let bytes = match bytes {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                log::info!("消息编码失败:{:?}", e);
+                return;
+            }
+        };
+        let sender = self.sender.clone();
+        std::thread::spawn(move || {
+            let rs = TOKIO_RUNTIME.block_on(sender.send(bytes));
+            log::info!("rs:{:?}", rs);
+        });
+    }
+    //查询地址簿中的设备是否在线，结果由get_peers返回
+    fn query_online(&self) {
+        let peer_ids: Vec<String> = Config::get_peers()
+            .into_iter()
+            .map(|peer| peer.id)
+            .collect();
+        if !peer_ids.is_empty() {
+            self.send(IdClient::query_online(peer_ids));
+        }
+    }
+    fn is_discoverable(&self) -> bool {
+        Config::is_discoverable()
+    }
+    //是否允许其他设备查询本机是否在线
+    fn set_discoverable(&self, discoverable: bool) {
+        Config::set_discoverable(discoverable);
+        self.send(IdClient::set_discoverable(discoverable));
+    }
     fn connect(&self, peer_id: String) {
         println!("peer:{}", peer_id);
         let my_id = Config::get_id();
@@ -59,6 +267,37 @@ impl sciter::EventHandler for IndexWindow {
       fn get_password();
       fn update_id();
       fn update_password();
+      fn is_permanent_password_set();
+      fn set_permanent_password(String);
+      fn is_view_password_set();
+      fn set_view_password(String);
+      fn is_second_factor_enabled();
+      fn begin_second_factor();
+      fn confirm_second_factor(String);
+      fn disable_second_factor();
+      fn get_lockouts();
+      fn clear_lockouts();
+      fn get_permission_items();
+      fn get_permissions();
+      fn set_permissions(i32);
+      fn is_terminal_enabled();
+      fn set_terminal_enabled(bool);
+      fn is_ask_before_accept();
+      fn set_ask_before_accept(bool);
+      fn get_approval();
+      fn answer_approval(f64, i32);
+      fn chat_send(String);
+      fn chat_history();
+      fn is_chat_connected();
+      fn get_peers();
+      fn get_recent_peers();
+      fn save_peer(String, String, String);
+      fn remove_peer(String);
+      fn set_peer_password(String, String);
+      fn forget_peer_fingerprint(String);
+      fn query_online();
+      fn is_discoverable();
+      fn set_discoverable(bool);
       fn connect(String);
     }
     fn get_subscription(&mut self) -> Option<EVENT_GROUPS> {
@@ -1,12 +1,22 @@
 
 
-use crate::client::{key_enent, mouse_event};
-use crate::config::Config;
+use crate::chat::Entry;
+use crate::client::{
+    audio_client, chat_client, clipboard_client, file_browser_client, file_transfer_client,
+    input_status, key_enent, mouse_event, port_forward_client, remote_event_client,
+    terminal_client, video_client,
+};
+use crate::config::{Config, KeyMacro};
+use crate::file_transfer::State;
+use crate::keycode;
+use crate::port_forward::Kind;
 use crossbeam::atomic::AtomicCell;
 use sciter::dom::event::*;
 use sciter::video::{video_destination, AssetPtr};
-use sciter::{make_args, Element, HELEMENT};
-use stream::sha2::{Digest, Sha256};
+use sciter::{make_args, Element, Value, HELEMENT};
+use stream::message::file_entry;
+use stream::permission::Permissions;
+use stream::RemoteError;
 lazy_static::lazy_static! {
   static ref ELEMENT_CELL:AtomicCell<Option<Element>> =  AtomicCell::new(None);
 }
@@ -23,12 +33,340 @@ impl RemoteEventHandler {
         Config::get_id()
     }
     fn mouse_enter(&self) {
-        key_enent::hook(true);
+        //仅查看时不截获本机按键
+        if remote_event_client::permissions().contains(Permissions::INPUT) {
+            key_enent::hook(true);
+        }
     }
     fn mouse_leave(&self) {
         key_enent::hook(false);
     }
-    fn mouse_event(&self) {}
+    //键码模式发送按键，文本模式发送输入法提交的文本
+    fn set_text_mode(&self, text_mode: bool) {
+        key_enent::set_text_mode(text_mode);
+    }
+    //文本模式下窗口收到的按键，vk为虚拟键码
+    fn key_event(&self, vk: i32, down: bool, modifiers: i32) {
+        if key_enent::is_text_mode() {
+            key_enent::send_key(vk as u32, down, modifiers as u32);
+        }
+    }
+    fn text_input(&self, text: String) {
+        if key_enent::is_text_mode() {
+            key_enent::send_text(text);
+        }
+    }
+    //本次会话是否同步剪贴板
+    fn set_clipboard_enabled(&self, enabled: bool) {
+        if let Err(e) = clipboard_client::set_enabled(enabled) {
+            log::info!("设置剪贴板同步失败:{:?}", e);
+        }
+    }
+    fn is_clipboard_enabled(&self) -> bool {
+        clipboard_client::is_enabled()
+    }
+    //特殊组合键，取值同SpecialKey.Name
+    fn send_special_key(&self, name: i32) {
+        match stream::protobuf::ProtobufEnum::from_i32(name) {
+            Some(name) => key_enent::send_special(name),
+            None => log::info!("未知的特殊组合键:{}", name),
+        }
+    }
+    fn get_macros(&self) -> Value {
+        let mut macros = Value::array(0);
+        for key_macro in Config::get_macros() {
+            let mut item = Value::map();
+            item.set_item("name", key_macro.name);
+            item.set_item("steps", key_macro.steps.join(", "));
+            macros.push(item);
+        }
+        macros
+    }
+    //steps为逗号分隔的组合键，如 ControlLeft+KeyC, ControlLeft+KeyV，返回错误信息
+    fn save_macro(&self, name: String, steps: String) -> String {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return String::from("名称不能为空");
+        }
+        let steps: Vec<String> = steps
+            .split(',')
+            .map(|step| step.trim().to_string())
+            .filter(|step| !step.is_empty())
+            .collect();
+        if steps.is_empty() {
+            return String::from("按键不能为空");
+        }
+        if let Some(step) = steps
+            .iter()
+            .find(|step| keycode::parse_combo(step).is_none())
+        {
+            return format!("无法识别的组合键:{}", step);
+        }
+        Config::set_macro(KeyMacro { name, steps });
+        String::new()
+    }
+    fn remove_macro(&self, name: String) {
+        Config::remove_macro(&name);
+    }
+    fn run_macro(&self, name: String) {
+        if let Err(e) = key_enent::send_macro(&name) {
+            log::info!("执行按键宏失败:{:?}", e);
+        }
+    }
+    //发送本机文件，dir为被控端目录，为空时使用被控端的默认目录，返回错误信息
+    fn send_file(&self, path: String, dir: String) -> String {
+        match file_transfer_client::send_file(&path, dir.trim()) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    //请求被控端的文件，返回错误信息
+    fn request_file(&self, path: String) -> String {
+        match file_transfer_client::request_file(path.trim()) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    fn get_transfers(&self) -> Value {
+        let mut transfers = Value::array(0);
+        for transfer in file_transfer_client::transfers() {
+            let mut item = Value::map();
+            item.set_item("name", transfer.name);
+            item.set_item("outgoing", transfer.outgoing);
+            //进度百分比
+            let percent = if transfer.size == 0 {
+                0
+            } else {
+                (transfer.transferred * 100 / transfer.size) as i32
+            };
+            item.set_item("percent", percent);
+            let state = match transfer.state {
+                State::Pending => String::from("等待"),
+                State::Running => String::from("传输中"),
+                State::Done => String::from("完成"),
+                State::Failed(e) => format!("失败:{}", e),
+            };
+            item.set_item("state", state);
+            transfers.push(item);
+        }
+        transfers
+    }
+    fn clear_transfers(&self) {
+        file_transfer_client::clear_finished();
+    }
+    //浏览被控端目录，path为空时列出根目录
+    fn browse(&self, path: String) -> Value {
+        let mut result = Value::map();
+        let rs = if path.is_empty() {
+            file_browser_client::roots()
+        } else {
+            file_browser_client::list_dir(&path)
+        };
+        result.set_item("read_only", file_browser_client::is_read_only());
+        let entries = match rs {
+            Ok(entries) => entries,
+            Err(e) => {
+                result.set_item("error", e.to_string());
+                return result;
+            }
+        };
+        let mut items = Value::array(0);
+        for entry in entries {
+            let mut item = Value::map();
+            let full_path = if path.is_empty() {
+                entry.name.clone()
+            } else {
+                file_browser_client::join(&path, &entry.name)
+            };
+            item.set_item("name", entry.name);
+            item.set_item("path", full_path);
+            item.set_item("size", entry.size as f64);
+            item.set_item("modified", entry.modified as f64);
+            item.set_item(
+                "dir",
+                entry.entry_type.enum_value_or_default() == file_entry::Type::Dir,
+            );
+            items.push(item);
+        }
+        result.set_item("entries", items);
+        result
+    }
+    fn browse_parent(&self, path: String) -> String {
+        file_browser_client::parent(&path).unwrap_or_default()
+    }
+    fn browse_join(&self, dir: String, name: String) -> String {
+        file_browser_client::join(&dir, &name)
+    }
+    //以下返回错误信息
+    fn browse_mkdir(&self, path: String) -> String {
+        match file_browser_client::mkdir(&path) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    fn browse_rename(&self, from: String, to: String) -> String {
+        match file_browser_client::rename(&from, &to) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    fn browse_remove(&self, path: String, recursive: bool) -> String {
+        match file_browser_client::remove(&path, recursive) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    //打开终端，返回错误信息
+    fn terminal_open(&self, rows: i32, cols: i32) -> String {
+        match terminal_client::open(rows.max(1) as u32, cols.max(1) as u32) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    fn terminal_input(&self, text: String) {
+        if let Err(e) = terminal_client::input(text.as_bytes()) {
+            log::info!("终端输入失败:{:?}", e);
+        }
+    }
+    fn terminal_resize(&self, rows: i32, cols: i32) {
+        if let Err(e) = terminal_client::resize(rows.max(1) as u32, cols.max(1) as u32) {
+            log::info!("调整终端大小失败:{:?}", e);
+        }
+    }
+    fn terminal_output(&self) -> String {
+        terminal_client::take_output()
+    }
+    //shell退出后返回退出信息，运行中返回空
+    fn terminal_exit(&self) -> String {
+        match terminal_client::exit_status() {
+            Some((_, error)) if !error.is_empty() => error,
+            Some((code, _)) => format!("shell已退出:{}", code),
+            None => String::new(),
+        }
+    }
+    //本地转发，本机监听local_port，返回错误信息
+    fn add_local_forward(&self, local_port: i32, host: String, port: i32) -> String {
+        if !(1..=65535).contains(&local_port) {
+            return format!("非法的端口:{}", local_port);
+        }
+        match port_forward_client::add_local(local_port as u16, host.trim(), port.max(0) as u32) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    //远程转发，被控端监听remote_port，返回错误信息
+    fn add_remote_forward(&self, remote_port: i32, host: String, port: i32) -> String {
+        match port_forward_client::add_remote(
+            remote_port.max(0) as u32,
+            host.trim(),
+            port.max(0) as u32,
+        ) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    fn remove_forward(&self, id: i32) {
+        port_forward_client::remove(id as u32);
+    }
+    fn get_tunnels(&self) -> Value {
+        let mut tunnels = Value::array(0);
+        for tunnel in port_forward_client::tunnels() {
+            let mut item = Value::map();
+            item.set_item("id", tunnel.id as i32);
+            item.set_item("remote", tunnel.kind == Kind::Remote);
+            item.set_item("listen", tunnel.listen);
+            item.set_item("target", tunnel.target);
+            item.set_item("connections", tunnel.connections as i32);
+            item.set_item("sent", tunnel.sent as f64);
+            item.set_item("received", tunnel.received as f64);
+            tunnels.push(item);
+        }
+        tunnels
+    }
+    //发送聊天消息，返回错误信息
+    fn chat_send(&self, text: String) -> String {
+        match chat_client::send(&text) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+    fn chat_history(&self) -> Value {
+        chat_value(chat_client::history())
+    }
+    fn set_audio_enabled(&self, enabled: bool) {
+        if let Err(e) = audio_client::set_enabled(enabled) {
+            log::info!("设置音频失败:{:?}", e);
+        }
+    }
+    fn is_audio_enabled(&self) -> bool {
+        audio_client::is_enabled()
+    }
+    //鼠标事件，active取值同MouseEvent.Active。
+    //移动时x、y为视图坐标（相对移动时为位移），滚动时为高精度滚动量
+    fn mouse_event(&self, active: i32, key: i32, x: i32, y: i32, view_w: i32, view_h: i32) {
+        //仅查看时不发送，避免事件堆积
+        if !remote_event_client::permissions().contains(Permissions::INPUT) {
+            return;
+        }
+        let frame = video_client::frame_size();
+        let rs = match active {
+            1 => mouse_event::button(key as u32, true),
+            2 => mouse_event::button(key as u32, false),
+            3 => match mouse_event::normalize(x, y, (view_w, view_h), frame) {
+                Some((norm_x, norm_y)) => {
+                    let display_id = video_client::displays().current;
+                    mouse_event::move_to(norm_x, norm_y, display_id, frame)
+                }
+                None => return,
+            },
+            4 | 5 => mouse_event::scroll(x, y),
+            6 => match mouse_event::fit((view_w, view_h), frame) {
+                //视图上的位移换算成画面上的位移
+                Some((_, _, width, height)) => mouse_event::move_relative(
+                    (x as f64 * frame.0 as f64 / width).round() as i32,
+                    (y as f64 * frame.1 as f64 / height).round() as i32,
+                ),
+                None => mouse_event::move_relative(x, y),
+            },
+            _ => return,
+        };
+        if let Err(e) = rs {
+            log::info!("发送鼠标事件失败:{:?}", e);
+        }
+    }
+    //显示器列表
+    fn get_displays(&self) -> Value {
+        let list = video_client::displays();
+        let mut displays = Value::array(0);
+        for display in list.displays {
+            let mut item = Value::map();
+            item.set_item("id", display.id);
+            item.set_item("name", display.name);
+            item.set_item("width", display.width as i32);
+            item.set_item("height", display.height as i32);
+            item.set_item("primary", display.primary);
+            displays.push(item);
+        }
+        let mut rs = Value::map();
+        rs.set_item("current", list.current);
+        rs.set_item("displays", displays);
+        rs
+    }
+    fn switch_display(&self, id: i32) {
+        if let Err(e) = video_client::switch_display(id) {
+            log::info!("切换显示器失败:{:?}", e);
+        }
+    }
+    //被控端输入注入的错误
+    fn get_input_errors(&self) -> String {
+        input_status::errors().join("\n")
+    }
+    //画面最大分辨率，0为原始分辨率
+    fn set_resolution(&self, width: i32, height: i32) {
+        if let Err(e) = video_client::set_resolution(width.max(0) as u32, height.max(0) as u32) {
+            log::info!("设置分辨率失败:{:?}", e);
+        }
+    }
     // #[inline]
     // fn call(&self, func: &str, args: &[Value]) {
     //     if let Some(e) = ELEMENT_CELL.take() {
@@ -46,7 +384,60 @@ impl sciter::EventHandler for RemoteEventHandler {
       fn get_id();
       fn mouse_leave();
       fn mouse_enter();
+      fn mouse_event(i32, i32, i32, i32, i32, i32);
+      fn set_text_mode(bool);
+      fn key_event(i32, bool, i32);
+      fn text_input(String);
+      fn set_clipboard_enabled(bool);
+      fn is_clipboard_enabled();
+      fn send_special_key(i32);
+      fn get_macros();
+      fn save_macro(String, String);
+      fn remove_macro(String);
+      fn run_macro(String);
+      fn get_displays();
+      fn switch_display(i32);
+      fn set_resolution(i32, i32);
+      fn get_input_errors();
+      fn send_file(String, String);
+      fn request_file(String);
+      fn get_transfers();
+      fn clear_transfers();
+      fn browse(String);
+      fn browse_parent(String);
+      fn browse_join(String, String);
+      fn browse_mkdir(String);
+      fn browse_rename(String, String);
+      fn browse_remove(String, bool);
+      fn terminal_open(i32, i32);
+      fn terminal_input(String);
+      fn terminal_resize(i32, i32);
+      fn terminal_output();
+      fn terminal_exit();
+      fn add_local_forward(i32, String, i32);
+      fn add_remote_forward(i32, String, i32);
+      fn remove_forward(i32);
+      fn get_tunnels();
+      fn chat_send(String);
+      fn chat_history();
+      fn set_audio_enabled(bool);
+      fn is_audio_enabled();
+    }
+}
+///聊天记录转成界面使用的数组，主控端和被控端窗口共用
+pub fn chat_value(entries: Vec<Entry>) -> Value {
+    let mut list = Value::array(0);
+    for entry in entries {
+        let mut item = Value::map();
+        item.set_item("id", entry.id as f64);
+        item.set_item("time", entry.timestamp as f64);
+        item.set_item("outgoing", entry.outgoing);
+        item.set_item("sender", entry.sender);
+        item.set_item("text", entry.text);
+        item.set_item("delivered", entry.delivered);
+        list.push(item);
     }
+    list
 }
 pub struct RemoteWindow {
     // element: Option<Element>,
@@ -60,20 +451,55 @@ pub struct RemoteWindow {
 }
 pub struct CallHandler(Element);
 impl CallHandler {
-    //弹窗并获取密码
+    //弹窗并获取密码，由连接过程用被控端的盐派生密钥
     pub fn passwrod_wind(&self, peer_id: String, msg: String) -> Option<Vec<u8>> {
         if let Ok(rs) = self.0.call_method("getPassword", &make_args!(peer_id, msg)) {
             if let Some(password) = rs.as_string() {
-                let mut hasher2 = Sha256::new();
-                hasher2.update(password);
-                let d = hasher2.finalize()[..].into();
                 log::info!("call:success,func:getPassword ");
-                return Some(d);
+                return Some(password.into_bytes());
             }
         }
         log::info!("获取密码失败");
         None
     }
+    //被控端要求第二因素时输入验证码，msg为上次的错误
+    pub fn second_factor_wind(&self, peer_id: String, msg: String) -> Option<Vec<u8>> {
+        if let Ok(rs) = self
+            .0
+            .call_method("getSecondFactor", &make_args!(peer_id, msg))
+        {
+            if let Some(code) = rs.as_string() {
+                return Some(code.into_bytes());
+            }
+        }
+        log::info!("获取验证码失败");
+        None
+    }
+    //被控端拒绝连接时提示原因，其他错误只记录日志
+    pub fn connect_error(&self, e: Option<&RemoteError>) {
+        let msg = match e {
+            Some(RemoteError::Rejected(msg)) => msg.clone(),
+            _ => return,
+        };
+        if let Err(e) = self.0.call_method("showError", &make_args!(msg)) {
+            log::info!("显示错误失败:{:?}", e);
+        }
+    }
+    //按被控端授予的权限隐藏没有权限的功能
+    pub fn apply_permissions(&self, permissions: Permissions) {
+        let mut granted = Value::map();
+        granted.set_item("input", permissions.contains(Permissions::INPUT));
+        granted.set_item("clipboard", permissions.contains(Permissions::CLIPBOARD));
+        granted.set_item("file", permissions.contains(Permissions::FILE));
+        granted.set_item("terminal", permissions.contains(Permissions::TERMINAL));
+        granted.set_item("forward", permissions.contains(Permissions::PORT_FORWARD));
+        granted.set_item("audio", permissions.contains(Permissions::AUDIO));
+        granted.set_item("chat", permissions.contains(Permissions::CHAT));
+        granted.set_item("text", permissions.describe());
+        if let Err(e) = self.0.call_method("applyPermissions", &make_args!(granted)) {
+            log::info!("设置权限失败:{:?}", e);
+        }
+    }
 }
 
 impl Drop for RemoteWindow {
@@ -114,9 +540,7 @@ impl sciter::EventHandler for RemoteWindow {
             println!("开始连接");
             let hall_handler = CallHandler(Element::from(root));
             let password_callback =
-                move |peer_id: String,
-                      _check_info: String,
-                      rs: stream::relay::LoginResponseEnum| {
+                move |peer_id: String, check_info: String, rs: stream::relay::LoginResponseEnum| {
                     match rs {
                         stream::relay::LoginResponseEnum::First => {
                             hall_handler.passwrod_wind(peer_id, String::new())
@@ -127,6 +551,9 @@ impl sciter::EventHandler for RemoteWindow {
                         stream::relay::LoginResponseEnum::Frequently => {
                             hall_handler.passwrod_wind(peer_id, String::from("错误次数过多"))
                         }
+                        stream::relay::LoginResponseEnum::NeedSecondFactor => {
+                            hall_handler.second_factor_wind(peer_id, check_info)
+                        }
                     }
                 };
             let peer_id = self.peer_id.take().unwrap();
@@ -145,15 +572,21 @@ impl sciter::EventHandler for RemoteWindow {
                 relay_addr,
                 relay_pub_key,
             ) {
-                Ok(channel_manager) => self.channel_manager = Some(channel_manager),
+                Ok(channel_manager) => {
+                    CallHandler(Element::from(root))
+                        .apply_permissions(remote_event_client::permissions());
+                    self.channel_manager = Some(channel_manager)
+                }
                 Err(e) => {
                     log::info!("连接错误：{:?}", e);
+                    CallHandler(Element::from(root)).connect_error(e.downcast_ref());
                 }
             };
         }
     }
     fn detached(&mut self, _root: HELEMENT) {
         println!("[video] <video> element is detached");
+        port_forward_client::stop();
     }
 
     fn on_event(
@@ -182,7 +615,7 @@ impl sciter::EventHandler for RemoteWindow {
                     let hall_handler = CallHandler(Element::from(source));
                     let password_callback =
                         move |peer_id: String,
-                              _check_info: String,
+                              check_info: String,
                               rs: stream::relay::LoginResponseEnum| {
                             println!("弹窗:{:?}", rs);
                             match rs {
@@ -194,6 +627,9 @@ impl sciter::EventHandler for RemoteWindow {
                                 }
                                 stream::relay::LoginResponseEnum::Frequently => hall_handler
                                     .passwrod_wind(peer_id, String::from("错误次数过多")),
+                                stream::relay::LoginResponseEnum::NeedSecondFactor => {
+                                    hall_handler.second_factor_wind(peer_id, check_info)
+                                }
                             }
                         };
                     let peer_id = self.peer_id.take().unwrap();
@@ -213,9 +649,14 @@ impl sciter::EventHandler for RemoteWindow {
                         relay_addr,
                         relay_pub_key,
                     ) {
-                        Ok(channel_manager) => self.channel_manager = Some(channel_manager),
+                        Ok(channel_manager) => {
+                            CallHandler(Element::from(source))
+                                .apply_permissions(remote_event_client::permissions());
+                            self.channel_manager = Some(channel_manager)
+                        }
                         Err(e) => {
                             log::info!("连接错误：{:?}", e);
+                            CallHandler(Element::from(source)).connect_error(e.downcast_ref());
                         }
                     };
                     // self.video.store(Some(site));
@@ -13,7 +13,8 @@ use tokio_util::codec::Framed;
 use crate::{
     codec::TcpBytesCodec,
     rendezvous_proto::{
-        register_peer_response, relay_response, rendezvous_message, RendezvousMessage,
+        register_peer_response, relay_response, rendezvous_message, QueryOnline, RendezvousMessage,
+        SetDiscoverable,
     },
     tcp::TcpTlsClient,
     utils::Cert,
@@ -24,6 +25,7 @@ use crate::{
 pub enum CallbackCode {
     MsgError(String), //消息错误
     PeerOffline,      //对方不在线
+    Throttled(u32),   //请求过于频繁，多少秒后再试
 }
 
 pub struct IdClient {
@@ -31,9 +33,19 @@ pub struct IdClient {
     server_key: String,
     cert: Cert,
     pub_key: Vec<u8>,
+    //是否允许其他设备查询本机是否在线
+    discoverable: bool,
     error_callback: fn(msg: CallbackCode),
-    control_server_callback:
-        fn(peer_id: String, relay_id: String, relay_addr: String, relay_pub_key: Vec<u8>),
+    //查询在线的结果，参数为在线的设备
+    online_callback: fn(online: Vec<String>),
+    control_server_callback: fn(
+        peer_id: String,
+        peer_pub_key: Vec<u8>,
+        relay_id: String,
+        relay_addr: String,
+        relay_pub_key: Vec<u8>,
+        peer_addr: String,
+    ),
     control_client_callback: fn(
         peer_id: String,
         peer_pub_key: Vec<u8>,
@@ -49,12 +61,16 @@ impl IdClient {
         server_key: String,
         cert: Cert,
         pub_key: Vec<u8>,
+        discoverable: bool,
         error_callback: fn(msg: CallbackCode),
+        online_callback: fn(online: Vec<String>),
         control_server_callback: fn(
             peer_id: String,
+            peer_pub_key: Vec<u8>,
             relay_id: String,
             relay_addr: String,
             relay_pub_key: Vec<u8>,
+            peer_addr: String,
         ),
         control_client_callback: fn(
             peer_id: String,
@@ -69,11 +85,31 @@ impl IdClient {
             server_key,
             cert,
             pub_key,
+            discoverable,
             error_callback,
+            online_callback,
             control_server_callback,
             control_client_callback,
         }
     }
+    ///查询设备是否在线的消息，通过start的receiver发送，结果由online_callback返回
+    pub fn query_online(peer_ids: Vec<String>) -> ResultType<Vec<u8>> {
+        let mut msg = RendezvousMessage::new();
+        msg.set_query_online(QueryOnline {
+            peer_id: peer_ids,
+            ..Default::default()
+        });
+        Ok(msg.write_to_bytes()?)
+    }
+    ///修改是否允许被查询的消息
+    pub fn set_discoverable(discoverable: bool) -> ResultType<Vec<u8>> {
+        let mut msg = RendezvousMessage::new();
+        msg.set_set_discoverable(SetDiscoverable {
+            discoverable,
+            ..Default::default()
+        });
+        Ok(msg.write_to_bytes()?)
+    }
     pub async fn start<A: ToSocketAddrs>(
         &self,
         addr: A,
@@ -99,6 +135,7 @@ impl IdClient {
             server_key: self.server_key.clone(),
             cert: self.cert.cert_der()?,
             pub_key: self.pub_key.clone(),
+            discoverable: self.discoverable,
             ..Default::default()
         });
         framed.send(register.write_to_bytes()?).await?;
@@ -126,10 +163,12 @@ impl IdClient {
             Err(RemoteError::Disconnection)?
         }
         let error_callback = self.error_callback;
+        let online_callback = self.online_callback;
         let control_server_callback = self.control_server_callback;
         let control_client_callback = self.control_client_callback;
         let a = IdClient::loop_(
             error_callback,
+            online_callback,
             control_server_callback,
             control_client_callback,
             receiver,
@@ -194,7 +233,8 @@ impl IdClient {
     }
     async fn loop_(
         error_callback: fn(CallbackCode),
-        control_server_callback: fn(String, String, String, Vec<u8>),
+        online_callback: fn(Vec<String>),
+        control_server_callback: fn(String, Vec<u8>, String, String, Vec<u8>, String),
         control_client_callback: fn(String, Vec<u8>, String, String, Vec<u8>),
         mut receiver: Receiver<Vec<u8>>,
         mut framed: Framed<TlsStream<TcpStream>, TcpBytesCodec>,
@@ -229,7 +269,8 @@ impl IdClient {
                                 match msg.code.enum_value_or_default(){
                                     relay_response::Code::Success => {
                                         if msg.is_control{
-                                            control_server_callback(msg.peer_id,msg.ralay_id,msg.relay_addr,msg.ralay_pub_key);
+                                            //peer_pub_key为主控端的公钥，用于显示指纹，peer_addr为主控端的地址
+                                            control_server_callback(msg.peer_id,msg.peer_pub_key,msg.ralay_id,msg.relay_addr,msg.ralay_pub_key,msg.peer_addr);
                                         }else{
                                             control_client_callback(msg.peer_id,msg.peer_pub_key,msg.ralay_id,msg.relay_addr,msg.ralay_pub_key);
                                         }
@@ -238,8 +279,12 @@ impl IdClient {
                                         error_callback(CallbackCode::MsgError(msg.message));
                                     },
                                     relay_response::Code::Offline => error_callback(CallbackCode::PeerOffline),
+                                    relay_response::Code::Throttled => error_callback(CallbackCode::Throttled(msg.retry_after)),
                                 }
                             },
+                            Some(rendezvous_message::Union::online_response(msg)) => {
+                                online_callback(msg.online);
+                            },
                             Some(s) => {
                                 log::info!("id消息类型错误：{:?}",s);
                             },
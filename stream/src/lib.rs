@@ -6,12 +6,14 @@ mod config;
 pub mod id_conn;
 #[path = "./protos/message.rs"]
 pub mod message;
+pub mod permission;
 pub mod quic;
 pub mod relay;
 pub mod remote_channel;
 #[path = "./protos/rendezvous.rs"]
 pub mod rendezvous_proto;
 pub mod tcp;
+pub mod totp;
 pub mod utils;
 pub type ResultType<F, E = anyhow::Error> = anyhow::Result<F, E>;
 pub use protobuf;
@@ -30,6 +32,7 @@ pub enum RemoteError {
     Encrypt(String),
     Login(String),
     Channel(String),
+    Rejected(String), //被控端拒绝连接
     IO(std::io::Error),
     IdRepeat,          //id重复
     ServerKeyNotMatch, //服务器key不匹配
@@ -46,6 +49,7 @@ impl fmt::Display for RemoteError {
             RemoteError::Decrypt(e) => write!(f, "Decrypt {}", e),
             RemoteError::Login(e) => write!(f, "Login {}", e),
             RemoteError::Channel(e) => write!(f, "Channel {}", e),
+            RemoteError::Rejected(e) => write!(f, "Rejected {}", e),
             RemoteError::CipherInit(e) => write!(f, "CipherInit {}", e),
             RemoteError::InvalidData(e) => write!(f, "InvalidData {}", e),
             RemoteError::IO(e) => write!(f, "IO {:?}", e),
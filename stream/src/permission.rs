@@ -0,0 +1,121 @@
+//! 会话权限。被控端在登录成功时按密码等级或用户的确认结果授予，
+//! 通道管理器拒绝对方建立未授权的通道
+
+use std::ops::{BitAnd, BitOr};
+
+use crate::message::ChannelType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Permissions(pub u32);
+
+impl Permissions {
+    pub const NONE: Permissions = Permissions(0);
+    //画面
+    pub const VIEW: Permissions = Permissions(1);
+    //键盘鼠标
+    pub const INPUT: Permissions = Permissions(1 << 1);
+    pub const CLIPBOARD: Permissions = Permissions(1 << 2);
+    //文件传输和文件浏览
+    pub const FILE: Permissions = Permissions(1 << 3);
+    pub const TERMINAL: Permissions = Permissions(1 << 4);
+    pub const PORT_FORWARD: Permissions = Permissions(1 << 5);
+    pub const AUDIO: Permissions = Permissions(1 << 6);
+    pub const CHAT: Permissions = Permissions(1 << 7);
+    ///仅查看，可以看画面、听声音和聊天，不能操作
+    pub const VIEW_ONLY: Permissions =
+        Permissions(Permissions::VIEW.0 | Permissions::AUDIO.0 | Permissions::CHAT.0);
+    ///完全控制
+    pub const FULL: Permissions = Permissions(0xff);
+
+    ///单项权限和显示名称
+    pub const ITEMS: [(Permissions, &str); 8] = [
+        (Permissions::VIEW, "查看画面"),
+        (Permissions::INPUT, "键盘鼠标"),
+        (Permissions::CLIPBOARD, "剪贴板"),
+        (Permissions::FILE, "文件"),
+        (Permissions::TERMINAL, "终端"),
+        (Permissions::PORT_FORWARD, "端口转发"),
+        (Permissions::AUDIO, "声音"),
+        (Permissions::CHAT, "聊天"),
+    ];
+
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+    ///去掉未定义的位
+    pub fn from_bits(bits: u32) -> Self {
+        Permissions(bits) & Permissions::FULL
+    }
+    pub fn contains(self, other: Permissions) -> bool {
+        self.0 & other.0 == other.0
+    }
+    ///建立该类型的通道需要的权限
+    pub fn required(channel_type: ChannelType) -> Permissions {
+        match channel_type {
+            ChannelType::NoDefine => Permissions::NONE,
+            ChannelType::Video => Permissions::VIEW,
+            ChannelType::KeyEvent | ChannelType::MouseEvent => Permissions::INPUT,
+            ChannelType::Clipboard => Permissions::CLIPBOARD,
+            ChannelType::FileTransfer | ChannelType::FileBrowser => Permissions::FILE,
+            ChannelType::Terminal => Permissions::TERMINAL,
+            ChannelType::PortForward => Permissions::PORT_FORWARD,
+            ChannelType::Audio => Permissions::AUDIO,
+            ChannelType::Chat => Permissions::CHAT,
+        }
+    }
+    pub fn allows(self, channel_type: ChannelType) -> bool {
+        self.contains(Permissions::required(channel_type))
+    }
+    ///用于界面和审计日志，如 查看画面,声音,聊天
+    pub fn describe(self) -> String {
+        if self == Permissions::FULL {
+            return String::from("完全控制");
+        }
+        let names: Vec<&str> = Permissions::ITEMS
+            .iter()
+            .filter(|(item, _)| self.contains(*item))
+            .map(|(_, name)| *name)
+            .collect();
+        if names.is_empty() {
+            return String::from("无");
+        }
+        names.join(",")
+    }
+}
+
+impl BitAnd for Permissions {
+    type Output = Permissions;
+
+    fn bitand(self, rhs: Permissions) -> Permissions {
+        Permissions(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for Permissions {
+    type Output = Permissions;
+
+    fn bitor(self, rhs: Permissions) -> Permissions {
+        Permissions(self.0 | rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows() {
+        let view_only = Permissions::VIEW_ONLY;
+        assert!(view_only.allows(ChannelType::Video));
+        assert!(view_only.allows(ChannelType::Chat));
+        assert!(!view_only.allows(ChannelType::MouseEvent));
+        assert!(!view_only.allows(ChannelType::FileBrowser));
+        assert!(Permissions::FULL.allows(ChannelType::Terminal));
+        assert_eq!(
+            Permissions::from_bits(0xffff_ffff) & Permissions::INPUT,
+            Permissions::INPUT
+        );
+        assert_eq!(view_only.describe(), "查看画面,声音,聊天");
+        assert_eq!(Permissions::FULL.describe(), "完全控制");
+    }
+}
@@ -256,6 +256,8 @@ pub struct ServerHello {
     pub rand1: u64,
     pub rand2: u64,
     pub hash: ::std::vec::Vec<u8>,
+    pub salt: ::std::vec::Vec<u8>,
+    pub rounds: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::rt::CachedSize,
@@ -289,6 +291,16 @@ impl ServerHello {
             |m: &ServerHello| { &m.hash },
             |m: &mut ServerHello| { &mut m.hash },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "salt",
+            |m: &ServerHello| { &m.salt },
+            |m: &mut ServerHello| { &mut m.salt },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "rounds",
+            |m: &ServerHello| { &m.rounds },
+            |m: &mut ServerHello| { &mut m.rounds },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ServerHello>(
             "ServerHello",
             1,
@@ -324,6 +336,18 @@ impl ::protobuf::Message for ServerHello {
                     }
                     self.hash = is.read_bytes()?;
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.salt = is.read_bytes()?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.rounds = is.read_uint32()?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -345,6 +369,12 @@ impl ::protobuf::Message for ServerHello {
         if !self.hash.is_empty() {
             my_size += ::protobuf::rt::bytes_size(3, &self.hash);
         }
+        if !self.salt.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.salt);
+        }
+        if self.rounds != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.rounds, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -360,6 +390,12 @@ impl ::protobuf::Message for ServerHello {
         if !self.hash.is_empty() {
             os.write_bytes(3, &self.hash)?;
         }
+        if !self.salt.is_empty() {
+            os.write_bytes(4, &self.salt)?;
+        }
+        if self.rounds != 0 {
+            os.write_uint32(5, self.rounds)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -389,6 +425,8 @@ impl ::protobuf::Message for ServerHello {
             rand1: 0,
             rand2: 0,
             hash: ::std::vec::Vec::new(),
+            salt: ::std::vec::Vec::new(),
+            rounds: 0,
             unknown_fields: ::protobuf::UnknownFields::new(),
             cached_size: ::protobuf::rt::CachedSize::new(),
         };
@@ -401,6 +439,8 @@ impl ::protobuf::Clear for ServerHello {
         self.rand1 = 0;
         self.rand2 = 0;
         self.hash.clear();
+        self.salt.clear();
+        self.rounds = 0;
         self.unknown_fields.clear();
     }
 }
@@ -420,6 +460,8 @@ pub struct LoginRequest {
     // message fields
     pub password: ::std::vec::Vec<u8>,
     pub hash: ::std::vec::Vec<u8>,
+    pub otp: ::std::string::String,
+    pub proof: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::rt::CachedSize,
@@ -448,6 +490,16 @@ impl LoginRequest {
             |m: &LoginRequest| { &m.hash },
             |m: &mut LoginRequest| { &mut m.hash },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "otp",
+            |m: &LoginRequest| { &m.otp },
+            |m: &mut LoginRequest| { &mut m.otp },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "proof",
+            |m: &LoginRequest| { &m.proof },
+            |m: &mut LoginRequest| { &mut m.proof },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LoginRequest>(
             "LoginRequest",
             2,
@@ -477,6 +529,18 @@ impl ::protobuf::Message for LoginRequest {
                     }
                     self.hash = is.read_bytes()?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.otp = is.read_string()?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.proof = is.read_bytes()?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -495,6 +559,12 @@ impl ::protobuf::Message for LoginRequest {
         if !self.hash.is_empty() {
             my_size += ::protobuf::rt::bytes_size(2, &self.hash);
         }
+        if !self.otp.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.otp);
+        }
+        if !self.proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.proof);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -507,6 +577,12 @@ impl ::protobuf::Message for LoginRequest {
         if !self.hash.is_empty() {
             os.write_bytes(2, &self.hash)?;
         }
+        if !self.otp.is_empty() {
+            os.write_string(3, &self.otp)?;
+        }
+        if !self.proof.is_empty() {
+            os.write_bytes(4, &self.proof)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -535,6 +611,8 @@ impl ::protobuf::Message for LoginRequest {
         static instance: LoginRequest = LoginRequest {
             password: ::std::vec::Vec::new(),
             hash: ::std::vec::Vec::new(),
+            otp: ::std::string::String::new(),
+            proof: ::std::vec::Vec::new(),
             unknown_fields: ::protobuf::UnknownFields::new(),
             cached_size: ::protobuf::rt::CachedSize::new(),
         };
@@ -546,6 +624,8 @@ impl ::protobuf::Clear for LoginRequest {
     fn clear(&mut self) {
         self.password.clear();
         self.hash.clear();
+        self.otp.clear();
+        self.proof.clear();
         self.unknown_fields.clear();
     }
 }
@@ -565,6 +645,8 @@ pub struct LoginResponse {
     // message fields
     pub code: ::protobuf::ProtobufEnumOrUnknown<login_response::Code>,
     pub error: ::std::string::String,
+    pub permissions: u32,
+    pub lockout_secs: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::rt::CachedSize,
@@ -593,6 +675,16 @@ impl LoginResponse {
             |m: &LoginResponse| { &m.error },
             |m: &mut LoginResponse| { &mut m.error },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "permissions",
+            |m: &LoginResponse| { &m.permissions },
+            |m: &mut LoginResponse| { &mut m.permissions },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "lockout_secs",
+            |m: &LoginResponse| { &m.lockout_secs },
+            |m: &mut LoginResponse| { &mut m.lockout_secs },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<LoginResponse>(
             "LoginResponse",
             3,
@@ -622,6 +714,18 @@ impl ::protobuf::Message for LoginResponse {
                     }
                     self.error = is.read_string()?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.permissions = is.read_uint32()?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.lockout_secs = is.read_uint32()?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -640,6 +744,12 @@ impl ::protobuf::Message for LoginResponse {
         if !self.error.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.error);
         }
+        if self.permissions != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.permissions, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.lockout_secs != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.lockout_secs, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -652,6 +762,12 @@ impl ::protobuf::Message for LoginResponse {
         if !self.error.is_empty() {
             os.write_string(2, &self.error)?;
         }
+        if self.permissions != 0 {
+            os.write_uint32(3, self.permissions)?;
+        }
+        if self.lockout_secs != 0 {
+            os.write_uint32(4, self.lockout_secs)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -680,6 +796,8 @@ impl ::protobuf::Message for LoginResponse {
         static instance: LoginResponse = LoginResponse {
             code: ::protobuf::ProtobufEnumOrUnknown::from_i32(0),
             error: ::std::string::String::new(),
+            permissions: 0,
+            lockout_secs: 0,
             unknown_fields: ::protobuf::UnknownFields::new(),
             cached_size: ::protobuf::rt::CachedSize::new(),
         };
@@ -691,6 +809,8 @@ impl ::protobuf::Clear for LoginResponse {
     fn clear(&mut self) {
         self.code = ::protobuf::ProtobufEnumOrUnknown::new(login_response::Code::NotMatch);
         self.error.clear();
+        self.permissions = 0;
+        self.lockout_secs = 0;
         self.unknown_fields.clear();
     }
 }
@@ -712,6 +832,8 @@ pub mod login_response {
         NotMatch = 0,
         Success = 1,
         Frequently = 2,
+        Rejected = 3,
+        NeedSecondFactor = 4,
     }
 
     impl ::protobuf::ProtobufEnum for Code {
@@ -724,6 +846,8 @@ pub mod login_response {
                 0 => ::std::option::Option::Some(Code::NotMatch),
                 1 => ::std::option::Option::Some(Code::Success),
                 2 => ::std::option::Option::Some(Code::Frequently),
+                3 => ::std::option::Option::Some(Code::Rejected),
+                4 => ::std::option::Option::Some(Code::NeedSecondFactor),
                 _ => ::std::option::Option::None
             }
         }
@@ -733,6 +857,8 @@ pub mod login_response {
                 Code::NotMatch,
                 Code::Success,
                 Code::Frequently,
+                Code::Rejected,
+                Code::NeedSecondFactor,
             ];
             values
         }
@@ -1399,6 +1525,8 @@ pub struct KeyEvent {
     // message fields
     pub key: u32,
     pub active: ::protobuf::ProtobufEnumOrUnknown<key_event::Active>,
+    pub code: ::std::string::String,
+    pub modifiers: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::rt::CachedSize,
@@ -1427,6 +1555,16 @@ impl KeyEvent {
             |m: &KeyEvent| { &m.active },
             |m: &mut KeyEvent| { &mut m.active },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &KeyEvent| { &m.code },
+            |m: &mut KeyEvent| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "modifiers",
+            |m: &KeyEvent| { &m.modifiers },
+            |m: &mut KeyEvent| { &mut m.modifiers },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<KeyEvent>(
             "KeyEvent",
             6,
@@ -1456,6 +1594,18 @@ impl ::protobuf::Message for KeyEvent {
                     }
                     self.active = is.read_enum_or_unknown()?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.code = is.read_string()?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.modifiers = is.read_uint32()?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1474,6 +1624,12 @@ impl ::protobuf::Message for KeyEvent {
         if self.active != ::protobuf::ProtobufEnumOrUnknown::new(key_event::Active::Click) {
             my_size += ::protobuf::rt::enum_or_unknown_size(2, self.active);
         }
+        if !self.code.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.code);
+        }
+        if self.modifiers != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.modifiers, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1486,6 +1642,12 @@ impl ::protobuf::Message for KeyEvent {
         if self.active != ::protobuf::ProtobufEnumOrUnknown::new(key_event::Active::Click) {
             os.write_enum(2, ::protobuf::ProtobufEnumOrUnknown::value(&self.active))?;
         }
+        if !self.code.is_empty() {
+            os.write_string(3, &self.code)?;
+        }
+        if self.modifiers != 0 {
+            os.write_uint32(4, self.modifiers)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1514,6 +1676,8 @@ impl ::protobuf::Message for KeyEvent {
         static instance: KeyEvent = KeyEvent {
             key: 0,
             active: ::protobuf::ProtobufEnumOrUnknown::from_i32(0),
+            code: ::std::string::String::new(),
+            modifiers: 0,
             unknown_fields: ::protobuf::UnknownFields::new(),
             cached_size: ::protobuf::rt::CachedSize::new(),
         };
@@ -1525,6 +1689,8 @@ impl ::protobuf::Clear for KeyEvent {
     fn clear(&mut self) {
         self.key = 0;
         self.active = ::protobuf::ProtobufEnumOrUnknown::new(key_event::Active::Click);
+        self.code.clear();
+        self.modifiers = 0;
         self.unknown_fields.clear();
     }
 }
@@ -1594,65 +1760,47 @@ pub mod key_event {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct MouseEvent {
+pub struct TextInput {
     // message fields
-    pub key: u32,
-    pub active: ::protobuf::ProtobufEnumOrUnknown<mouse_event::Active>,
-    pub move_x: i32,
-    pub move_y: i32,
-    pub scroll_len: i32,
+    pub text: ::std::string::String,
+    pub composition: ::protobuf::ProtobufEnumOrUnknown<text_input::Composition>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::rt::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a MouseEvent {
-    fn default() -> &'a MouseEvent {
-        <MouseEvent as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a TextInput {
+    fn default() -> &'a TextInput {
+        <TextInput as ::protobuf::Message>::default_instance()
     }
 }
 
-impl MouseEvent {
-    pub fn new() -> MouseEvent {
+impl TextInput {
+    pub fn new() -> TextInput {
         ::std::default::Default::default()
     }
 
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::new();
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "key",
-            |m: &MouseEvent| { &m.key },
-            |m: &mut MouseEvent| { &mut m.key },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "active",
-            |m: &MouseEvent| { &m.active },
-            |m: &mut MouseEvent| { &mut m.active },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "move_x",
-            |m: &MouseEvent| { &m.move_x },
-            |m: &mut MouseEvent| { &mut m.move_x },
-        ));
-        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "move_y",
-            |m: &MouseEvent| { &m.move_y },
-            |m: &mut MouseEvent| { &mut m.move_y },
+            "text",
+            |m: &TextInput| { &m.text },
+            |m: &mut TextInput| { &mut m.text },
         ));
         fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
-            "scroll_len",
-            |m: &MouseEvent| { &m.scroll_len },
-            |m: &mut MouseEvent| { &mut m.scroll_len },
+            "composition",
+            |m: &TextInput| { &m.composition },
+            |m: &mut TextInput| { &mut m.composition },
         ));
-        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MouseEvent>(
-            "MouseEvent",
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TextInput>(
+            "TextInput",
             7,
             fields,
         )
     }
 }
 
-impl ::protobuf::Message for MouseEvent {
+impl ::protobuf::Message for TextInput {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1662,34 +1810,16 @@ impl ::protobuf::Message for MouseEvent {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.key = is.read_uint32()?;
+                    self.text = is.read_string()?;
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.active = is.read_enum_or_unknown()?;
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.move_x = is.read_int32()?;
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.move_y = is.read_int32()?;
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.scroll_len = is.read_int32()?;
+                    self.composition = is.read_enum_or_unknown()?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1703,20 +1833,11 @@ impl ::protobuf::Message for MouseEvent {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.key != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.key, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.active != ::protobuf::ProtobufEnumOrUnknown::new(mouse_event::Active::Click) {
-            my_size += ::protobuf::rt::enum_or_unknown_size(2, self.active);
-        }
-        if self.move_x != 0 {
-            my_size += ::protobuf::rt::value_size(3, self.move_x, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if self.move_y != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.move_y, ::protobuf::wire_format::WireTypeVarint);
+        if !self.text.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.text);
         }
-        if self.scroll_len != 0 {
-            my_size += ::protobuf::rt::value_size(5, self.scroll_len, ::protobuf::wire_format::WireTypeVarint);
+        if self.composition != ::protobuf::ProtobufEnumOrUnknown::new(text_input::Composition::Commit) {
+            my_size += ::protobuf::rt::enum_or_unknown_size(2, self.composition);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1724,20 +1845,11 @@ impl ::protobuf::Message for MouseEvent {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if self.key != 0 {
-            os.write_uint32(1, self.key)?;
-        }
-        if self.active != ::protobuf::ProtobufEnumOrUnknown::new(mouse_event::Active::Click) {
-            os.write_enum(2, ::protobuf::ProtobufEnumOrUnknown::value(&self.active))?;
-        }
-        if self.move_x != 0 {
-            os.write_int32(3, self.move_x)?;
-        }
-        if self.move_y != 0 {
-            os.write_int32(4, self.move_y)?;
+        if !self.text.is_empty() {
+            os.write_string(1, &self.text)?;
         }
-        if self.scroll_len != 0 {
-            os.write_int32(5, self.scroll_len)?;
+        if self.composition != ::protobuf::ProtobufEnumOrUnknown::new(text_input::Composition::Commit) {
+            os.write_enum(2, ::protobuf::ProtobufEnumOrUnknown::value(&self.composition))?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1755,21 +1867,18 @@ impl ::protobuf::Message for MouseEvent {
         &mut self.unknown_fields
     }
 
-    fn new() -> MouseEvent {
-        MouseEvent::new()
+    fn new() -> TextInput {
+        TextInput::new()
     }
 
     fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
         ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 7)
     }
 
-    fn default_instance() -> &'static MouseEvent {
-        static instance: MouseEvent = MouseEvent {
-            key: 0,
-            active: ::protobuf::ProtobufEnumOrUnknown::from_i32(0),
-            move_x: 0,
-            move_y: 0,
-            scroll_len: 0,
+    fn default_instance() -> &'static TextInput {
+        static instance: TextInput = TextInput {
+            text: ::std::string::String::new(),
+            composition: ::protobuf::ProtobufEnumOrUnknown::from_i32(0),
             unknown_fields: ::protobuf::UnknownFields::new(),
             cached_size: ::protobuf::rt::CachedSize::new(),
         };
@@ -1777,61 +1886,55 @@ impl ::protobuf::Message for MouseEvent {
     }
 }
 
-impl ::protobuf::Clear for MouseEvent {
+impl ::protobuf::Clear for TextInput {
     fn clear(&mut self) {
-        self.key = 0;
-        self.active = ::protobuf::ProtobufEnumOrUnknown::new(mouse_event::Active::Click);
-        self.move_x = 0;
-        self.move_y = 0;
-        self.scroll_len = 0;
+        self.text.clear();
+        self.composition = ::protobuf::ProtobufEnumOrUnknown::new(text_input::Composition::Commit);
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for MouseEvent {
+impl ::std::fmt::Debug for TextInput {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for MouseEvent {
+impl ::protobuf::reflect::ProtobufValue for TextInput {
     type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
 }
 
-/// Nested message and enums of message `MouseEvent`
-pub mod mouse_event {
+/// Nested message and enums of message `TextInput`
+pub mod text_input {
     #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
-    pub enum Active {
-        Click = 0,
-        Down = 1,
-        Up = 2,
-        Move = 3,
-        ScrollY = 4,
+    pub enum Composition {
+        Commit = 0,
+        Start = 1,
+        Update = 2,
+        End = 3,
     }
 
-    impl ::protobuf::ProtobufEnum for Active {
+    impl ::protobuf::ProtobufEnum for Composition {
         fn value(&self) -> i32 {
             *self as i32
         }
 
-        fn from_i32(value: i32) -> ::std::option::Option<Active> {
+        fn from_i32(value: i32) -> ::std::option::Option<Composition> {
             match value {
-                0 => ::std::option::Option::Some(Active::Click),
-                1 => ::std::option::Option::Some(Active::Down),
-                2 => ::std::option::Option::Some(Active::Up),
-                3 => ::std::option::Option::Some(Active::Move),
-                4 => ::std::option::Option::Some(Active::ScrollY),
+                0 => ::std::option::Option::Some(Composition::Commit),
+                1 => ::std::option::Option::Some(Composition::Start),
+                2 => ::std::option::Option::Some(Composition::Update),
+                3 => ::std::option::Option::Some(Composition::End),
                 _ => ::std::option::Option::None
             }
         }
 
         fn values() -> &'static [Self] {
-            static values: &'static [Active] = &[
-                Active::Click,
-                Active::Down,
-                Active::Up,
-                Active::Move,
-                Active::ScrollY,
+            static values: &'static [Composition] = &[
+                Composition::Commit,
+                Composition::Start,
+                Composition::Update,
+                Composition::End,
             ];
             values
         }
@@ -1841,19 +1944,9489 @@ pub mod mouse_event {
         }
     }
 
-    impl ::std::default::Default for Active {
+    impl ::std::default::Default for Composition {
         fn default() -> Self {
-            Active::Click
+            Composition::Commit
         }
     }
 
-    impl ::protobuf::reflect::ProtobufValue for Active {
+    impl ::protobuf::reflect::ProtobufValue for Composition {
         type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeEnum<Self>;
     }
 
-    impl Active {
+    impl Composition {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new_2::<Composition>("TextInput.Composition", 5)
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KeyboardSnapshot {
+    // message fields
+    pub pressed: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KeyboardSnapshot {
+    fn default() -> &'a KeyboardSnapshot {
+        <KeyboardSnapshot as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KeyboardSnapshot {
+    pub fn new() -> KeyboardSnapshot {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "pressed",
+            |m: &KeyboardSnapshot| { &m.pressed },
+            |m: &mut KeyboardSnapshot| { &mut m.pressed },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<KeyboardSnapshot>(
+            "KeyboardSnapshot",
+            8,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for KeyboardSnapshot {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.pressed)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.pressed {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.pressed {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> KeyboardSnapshot {
+        KeyboardSnapshot::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 8)
+    }
+
+    fn default_instance() -> &'static KeyboardSnapshot {
+        static instance: KeyboardSnapshot = KeyboardSnapshot {
+            pressed: ::std::vec::Vec::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for KeyboardSnapshot {
+    fn clear(&mut self) {
+        self.pressed.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KeyboardSnapshot {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyboardSnapshot {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KeyCombo {
+    // message fields
+    pub codes: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KeyCombo {
+    fn default() -> &'a KeyCombo {
+        <KeyCombo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KeyCombo {
+    pub fn new() -> KeyCombo {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "codes",
+            |m: &KeyCombo| { &m.codes },
+            |m: &mut KeyCombo| { &mut m.codes },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<KeyCombo>(
+            "KeyCombo",
+            9,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for KeyCombo {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.codes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.codes {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.codes {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> KeyCombo {
+        KeyCombo::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 9)
+    }
+
+    fn default_instance() -> &'static KeyCombo {
+        static instance: KeyCombo = KeyCombo {
+            codes: ::std::vec::Vec::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for KeyCombo {
+    fn clear(&mut self) {
+        self.codes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KeyCombo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyCombo {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SpecialKey {
+    // message fields
+    pub name: ::protobuf::ProtobufEnumOrUnknown<special_key::Name>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SpecialKey {
+    fn default() -> &'a SpecialKey {
+        <SpecialKey as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SpecialKey {
+    pub fn new() -> SpecialKey {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &SpecialKey| { &m.name },
+            |m: &mut SpecialKey| { &mut m.name },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SpecialKey>(
+            "SpecialKey",
+            10,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for SpecialKey {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.name = is.read_enum_or_unknown()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.name != ::protobuf::ProtobufEnumOrUnknown::new(special_key::Name::CtrlAltDel) {
+            my_size += ::protobuf::rt::enum_or_unknown_size(1, self.name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.name != ::protobuf::ProtobufEnumOrUnknown::new(special_key::Name::CtrlAltDel) {
+            os.write_enum(1, ::protobuf::ProtobufEnumOrUnknown::value(&self.name))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> SpecialKey {
+        SpecialKey::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 10)
+    }
+
+    fn default_instance() -> &'static SpecialKey {
+        static instance: SpecialKey = SpecialKey {
+            name: ::protobuf::ProtobufEnumOrUnknown::from_i32(0),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for SpecialKey {
+    fn clear(&mut self) {
+        self.name = ::protobuf::ProtobufEnumOrUnknown::new(special_key::Name::CtrlAltDel);
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SpecialKey {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SpecialKey {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `SpecialKey`
+pub mod special_key {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    pub enum Name {
+        CtrlAltDel = 0,
+        LockScreen = 1,
+        AltTab = 2,
+        PrintScreen = 3,
+        TaskManager = 4,
+        AltF4 = 5,
+        StartMenu = 6,
+    }
+
+    impl ::protobuf::ProtobufEnum for Name {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Name> {
+            match value {
+                0 => ::std::option::Option::Some(Name::CtrlAltDel),
+                1 => ::std::option::Option::Some(Name::LockScreen),
+                2 => ::std::option::Option::Some(Name::AltTab),
+                3 => ::std::option::Option::Some(Name::PrintScreen),
+                4 => ::std::option::Option::Some(Name::TaskManager),
+                5 => ::std::option::Option::Some(Name::AltF4),
+                6 => ::std::option::Option::Some(Name::StartMenu),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [Name] = &[
+                Name::CtrlAltDel,
+                Name::LockScreen,
+                Name::AltTab,
+                Name::PrintScreen,
+                Name::TaskManager,
+                Name::AltF4,
+                Name::StartMenu,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> ::protobuf::reflect::EnumDescriptor {
+            ::protobuf::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 6)
+        }
+    }
+
+    impl ::std::default::Default for Name {
+        fn default() -> Self {
+            Name::CtrlAltDel
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Name {
+        type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeEnum<Self>;
+    }
+
+    impl Name {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new_2::<Name>("SpecialKey.Name", 6)
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KeyMacro {
+    // message fields
+    pub name: ::std::string::String,
+    pub steps: ::std::vec::Vec<KeyCombo>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KeyMacro {
+    fn default() -> &'a KeyMacro {
+        <KeyMacro as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KeyMacro {
+    pub fn new() -> KeyMacro {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &KeyMacro| { &m.name },
+            |m: &mut KeyMacro| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "steps",
+            |m: &KeyMacro| { &m.steps },
+            |m: &mut KeyMacro| { &mut m.steps },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<KeyMacro>(
+            "KeyMacro",
+            11,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for KeyMacro {
+    fn is_initialized(&self) -> bool {
+        for v in &self.steps {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.name = is.read_string()?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into_vec(wire_type, is, &mut self.steps)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        for value in &self.steps {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        for v in &self.steps {
+            ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> KeyMacro {
+        KeyMacro::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 11)
+    }
+
+    fn default_instance() -> &'static KeyMacro {
+        static instance: KeyMacro = KeyMacro {
+            name: ::std::string::String::new(),
+            steps: ::std::vec::Vec::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for KeyMacro {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.steps.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KeyMacro {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyMacro {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KeyboardMessage {
+    // message oneof groups
+    pub union: ::std::option::Option<keyboard_message::Union>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KeyboardMessage {
+    fn default() -> &'a KeyboardMessage {
+        <KeyboardMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KeyboardMessage {
+    pub fn new() -> KeyboardMessage {
+        ::std::default::Default::default()
+    }
+
+    // .KeyEvent key_event = 1;
+
+    pub fn get_key_event(&self) -> &KeyEvent {
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::key_event(ref v)) => v,
+            _ => <KeyEvent as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_key_event(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_key_event(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::key_event(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_event(&mut self, v: KeyEvent) {
+        self.union = ::std::option::Option::Some(keyboard_message::Union::key_event(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_key_event(&mut self) -> &mut KeyEvent {
+        if let ::std::option::Option::Some(keyboard_message::Union::key_event(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(keyboard_message::Union::key_event(KeyEvent::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::key_event(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_key_event(&mut self) -> KeyEvent {
+        if self.has_key_event() {
+            match self.union.take() {
+                ::std::option::Option::Some(keyboard_message::Union::key_event(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            KeyEvent::new()
+        }
+    }
+
+    // .TextInput text_input = 2;
+
+    pub fn get_text_input(&self) -> &TextInput {
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::text_input(ref v)) => v,
+            _ => <TextInput as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_text_input(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_text_input(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::text_input(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text_input(&mut self, v: TextInput) {
+        self.union = ::std::option::Option::Some(keyboard_message::Union::text_input(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_text_input(&mut self) -> &mut TextInput {
+        if let ::std::option::Option::Some(keyboard_message::Union::text_input(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(keyboard_message::Union::text_input(TextInput::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::text_input(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_text_input(&mut self) -> TextInput {
+        if self.has_text_input() {
+            match self.union.take() {
+                ::std::option::Option::Some(keyboard_message::Union::text_input(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            TextInput::new()
+        }
+    }
+
+    // .KeyboardSnapshot snapshot = 3;
+
+    pub fn get_snapshot(&self) -> &KeyboardSnapshot {
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::snapshot(ref v)) => v,
+            _ => <KeyboardSnapshot as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_snapshot(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_snapshot(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::snapshot(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snapshot(&mut self, v: KeyboardSnapshot) {
+        self.union = ::std::option::Option::Some(keyboard_message::Union::snapshot(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_snapshot(&mut self) -> &mut KeyboardSnapshot {
+        if let ::std::option::Option::Some(keyboard_message::Union::snapshot(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(keyboard_message::Union::snapshot(KeyboardSnapshot::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::snapshot(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_snapshot(&mut self) -> KeyboardSnapshot {
+        if self.has_snapshot() {
+            match self.union.take() {
+                ::std::option::Option::Some(keyboard_message::Union::snapshot(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            KeyboardSnapshot::new()
+        }
+    }
+
+    // .SpecialKey special_key = 4;
+
+    pub fn get_special_key(&self) -> &SpecialKey {
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::special_key(ref v)) => v,
+            _ => <SpecialKey as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_special_key(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_special_key(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::special_key(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_special_key(&mut self, v: SpecialKey) {
+        self.union = ::std::option::Option::Some(keyboard_message::Union::special_key(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_special_key(&mut self) -> &mut SpecialKey {
+        if let ::std::option::Option::Some(keyboard_message::Union::special_key(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(keyboard_message::Union::special_key(SpecialKey::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::special_key(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_special_key(&mut self) -> SpecialKey {
+        if self.has_special_key() {
+            match self.union.take() {
+                ::std::option::Option::Some(keyboard_message::Union::special_key(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SpecialKey::new()
+        }
+    }
+
+    // .KeyMacro key_macro = 5;
+
+    pub fn get_key_macro(&self) -> &KeyMacro {
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::key_macro(ref v)) => v,
+            _ => <KeyMacro as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_key_macro(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_key_macro(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::key_macro(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_macro(&mut self, v: KeyMacro) {
+        self.union = ::std::option::Option::Some(keyboard_message::Union::key_macro(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_key_macro(&mut self) -> &mut KeyMacro {
+        if let ::std::option::Option::Some(keyboard_message::Union::key_macro(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(keyboard_message::Union::key_macro(KeyMacro::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(keyboard_message::Union::key_macro(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_key_macro(&mut self) -> KeyMacro {
+        if self.has_key_macro() {
+            match self.union.take() {
+                ::std::option::Option::Some(keyboard_message::Union::key_macro(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            KeyMacro::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, KeyEvent>(
+            "key_event",
+            KeyboardMessage::has_key_event,
+            KeyboardMessage::get_key_event,
+            KeyboardMessage::mut_key_event,
+            KeyboardMessage::set_key_event,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, TextInput>(
+            "text_input",
+            KeyboardMessage::has_text_input,
+            KeyboardMessage::get_text_input,
+            KeyboardMessage::mut_text_input,
+            KeyboardMessage::set_text_input,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, KeyboardSnapshot>(
+            "snapshot",
+            KeyboardMessage::has_snapshot,
+            KeyboardMessage::get_snapshot,
+            KeyboardMessage::mut_snapshot,
+            KeyboardMessage::set_snapshot,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SpecialKey>(
+            "special_key",
+            KeyboardMessage::has_special_key,
+            KeyboardMessage::get_special_key,
+            KeyboardMessage::mut_special_key,
+            KeyboardMessage::set_special_key,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, KeyMacro>(
+            "key_macro",
+            KeyboardMessage::has_key_macro,
+            KeyboardMessage::get_key_macro,
+            KeyboardMessage::mut_key_macro,
+            KeyboardMessage::set_key_macro,
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<KeyboardMessage>(
+            "KeyboardMessage",
+            12,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for KeyboardMessage {
+    fn is_initialized(&self) -> bool {
+        if let Some(keyboard_message::Union::key_event(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(keyboard_message::Union::text_input(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(keyboard_message::Union::snapshot(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(keyboard_message::Union::special_key(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(keyboard_message::Union::key_macro(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(keyboard_message::Union::key_event(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(keyboard_message::Union::text_input(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(keyboard_message::Union::snapshot(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(keyboard_message::Union::special_key(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(keyboard_message::Union::key_macro(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &keyboard_message::Union::key_event(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &keyboard_message::Union::text_input(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &keyboard_message::Union::snapshot(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &keyboard_message::Union::special_key(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &keyboard_message::Union::key_macro(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &keyboard_message::Union::key_event(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &keyboard_message::Union::text_input(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+                &keyboard_message::Union::snapshot(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+                },
+                &keyboard_message::Union::special_key(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+                &keyboard_message::Union::key_macro(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> KeyboardMessage {
+        KeyboardMessage::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 12)
+    }
+
+    fn default_instance() -> &'static KeyboardMessage {
+        static instance: KeyboardMessage = KeyboardMessage {
+            union: ::std::option::Option::None,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for KeyboardMessage {
+    fn clear(&mut self) {
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KeyboardMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyboardMessage {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `KeyboardMessage`
+pub mod keyboard_message {
+
+    #[derive(Clone,PartialEq,Debug)]
+    pub enum Union {
+        key_event(super::KeyEvent),
+        text_input(super::TextInput),
+        snapshot(super::KeyboardSnapshot),
+        special_key(super::SpecialKey),
+        key_macro(super::KeyMacro),
+    }
+
+    impl ::protobuf::Oneof for Union {
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MouseEvent {
+    // message fields
+    pub key: u32,
+    pub active: ::protobuf::ProtobufEnumOrUnknown<mouse_event::Active>,
+    pub move_x: i32,
+    pub move_y: i32,
+    pub scroll_len: i32,
+    pub wheel_delta: i32,
+    pub normalized: bool,
+    pub norm_x: f32,
+    pub norm_y: f32,
+    pub display_id: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MouseEvent {
+    fn default() -> &'a MouseEvent {
+        <MouseEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl MouseEvent {
+    pub fn new() -> MouseEvent {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "key",
+            |m: &MouseEvent| { &m.key },
+            |m: &mut MouseEvent| { &mut m.key },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "active",
+            |m: &MouseEvent| { &m.active },
+            |m: &mut MouseEvent| { &mut m.active },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "move_x",
+            |m: &MouseEvent| { &m.move_x },
+            |m: &mut MouseEvent| { &mut m.move_x },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "move_y",
+            |m: &MouseEvent| { &m.move_y },
+            |m: &mut MouseEvent| { &mut m.move_y },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "scroll_len",
+            |m: &MouseEvent| { &m.scroll_len },
+            |m: &mut MouseEvent| { &mut m.scroll_len },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "wheel_delta",
+            |m: &MouseEvent| { &m.wheel_delta },
+            |m: &mut MouseEvent| { &mut m.wheel_delta },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "normalized",
+            |m: &MouseEvent| { &m.normalized },
+            |m: &mut MouseEvent| { &mut m.normalized },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "norm_x",
+            |m: &MouseEvent| { &m.norm_x },
+            |m: &mut MouseEvent| { &mut m.norm_x },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "norm_y",
+            |m: &MouseEvent| { &m.norm_y },
+            |m: &mut MouseEvent| { &mut m.norm_y },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "display_id",
+            |m: &MouseEvent| { &m.display_id },
+            |m: &mut MouseEvent| { &mut m.display_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<MouseEvent>(
+            "MouseEvent",
+            13,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for MouseEvent {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.key = is.read_uint32()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.active = is.read_enum_or_unknown()?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.move_x = is.read_int32()?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.move_y = is.read_int32()?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.scroll_len = is.read_int32()?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.wheel_delta = is.read_int32()?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.normalized = is.read_bool()?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.norm_x = is.read_float()?;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.norm_y = is.read_float()?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.display_id = is.read_int32()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.key != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.key, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.active != ::protobuf::ProtobufEnumOrUnknown::new(mouse_event::Active::Click) {
+            my_size += ::protobuf::rt::enum_or_unknown_size(2, self.active);
+        }
+        if self.move_x != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.move_x, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.move_y != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.move_y, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.scroll_len != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.scroll_len, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.wheel_delta != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.wheel_delta, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.normalized != false {
+            my_size += 2;
+        }
+        if self.norm_x != 0. {
+            my_size += 5;
+        }
+        if self.norm_y != 0. {
+            my_size += 5;
+        }
+        if self.display_id != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.display_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.key != 0 {
+            os.write_uint32(1, self.key)?;
+        }
+        if self.active != ::protobuf::ProtobufEnumOrUnknown::new(mouse_event::Active::Click) {
+            os.write_enum(2, ::protobuf::ProtobufEnumOrUnknown::value(&self.active))?;
+        }
+        if self.move_x != 0 {
+            os.write_int32(3, self.move_x)?;
+        }
+        if self.move_y != 0 {
+            os.write_int32(4, self.move_y)?;
+        }
+        if self.scroll_len != 0 {
+            os.write_int32(5, self.scroll_len)?;
+        }
+        if self.wheel_delta != 0 {
+            os.write_int32(6, self.wheel_delta)?;
+        }
+        if self.normalized != false {
+            os.write_bool(7, self.normalized)?;
+        }
+        if self.norm_x != 0. {
+            os.write_float(8, self.norm_x)?;
+        }
+        if self.norm_y != 0. {
+            os.write_float(9, self.norm_y)?;
+        }
+        if self.display_id != 0 {
+            os.write_int32(10, self.display_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> MouseEvent {
+        MouseEvent::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 13)
+    }
+
+    fn default_instance() -> &'static MouseEvent {
+        static instance: MouseEvent = MouseEvent {
+            key: 0,
+            active: ::protobuf::ProtobufEnumOrUnknown::from_i32(0),
+            move_x: 0,
+            move_y: 0,
+            scroll_len: 0,
+            wheel_delta: 0,
+            normalized: false,
+            norm_x: 0.,
+            norm_y: 0.,
+            display_id: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for MouseEvent {
+    fn clear(&mut self) {
+        self.key = 0;
+        self.active = ::protobuf::ProtobufEnumOrUnknown::new(mouse_event::Active::Click);
+        self.move_x = 0;
+        self.move_y = 0;
+        self.scroll_len = 0;
+        self.wheel_delta = 0;
+        self.normalized = false;
+        self.norm_x = 0.;
+        self.norm_y = 0.;
+        self.display_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MouseEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MouseEvent {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `MouseEvent`
+pub mod mouse_event {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    pub enum Active {
+        Click = 0,
+        Down = 1,
+        Up = 2,
+        Move = 3,
+        ScrollY = 4,
+        ScrollX = 5,
+        MoveRelative = 6,
+    }
+
+    impl ::protobuf::ProtobufEnum for Active {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Active> {
+            match value {
+                0 => ::std::option::Option::Some(Active::Click),
+                1 => ::std::option::Option::Some(Active::Down),
+                2 => ::std::option::Option::Some(Active::Up),
+                3 => ::std::option::Option::Some(Active::Move),
+                4 => ::std::option::Option::Some(Active::ScrollY),
+                5 => ::std::option::Option::Some(Active::ScrollX),
+                6 => ::std::option::Option::Some(Active::MoveRelative),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [Active] = &[
+                Active::Click,
+                Active::Down,
+                Active::Up,
+                Active::Move,
+                Active::ScrollY,
+                Active::ScrollX,
+                Active::MoveRelative,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> ::protobuf::reflect::EnumDescriptor {
+            ::protobuf::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 7)
+        }
+    }
+
+    impl ::std::default::Default for Active {
+        fn default() -> Self {
+            Active::Click
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Active {
+        type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeEnum<Self>;
+    }
+
+    impl Active {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new_2::<Active>("MouseEvent.Active", 7)
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct InputBatch {
+    // message fields
+    pub events: ::std::vec::Vec<MouseEvent>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a InputBatch {
+    fn default() -> &'a InputBatch {
+        <InputBatch as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InputBatch {
+    pub fn new() -> InputBatch {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "events",
+            |m: &InputBatch| { &m.events },
+            |m: &mut InputBatch| { &mut m.events },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InputBatch>(
+            "InputBatch",
+            14,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for InputBatch {
+    fn is_initialized(&self) -> bool {
+        for v in &self.events {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into_vec(wire_type, is, &mut self.events)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.events {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> InputBatch {
+        InputBatch::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 14)
+    }
+
+    fn default_instance() -> &'static InputBatch {
+        static instance: InputBatch = InputBatch {
+            events: ::std::vec::Vec::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for InputBatch {
+    fn clear(&mut self) {
+        self.events.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for InputBatch {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InputBatch {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ClipboardData {
+    // message fields
+    pub text: ::std::string::String,
+    pub html: ::std::string::String,
+    pub image: ::std::vec::Vec<u8>,
+    pub image_width: u32,
+    pub image_height: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ClipboardData {
+    fn default() -> &'a ClipboardData {
+        <ClipboardData as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ClipboardData {
+    pub fn new() -> ClipboardData {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "text",
+            |m: &ClipboardData| { &m.text },
+            |m: &mut ClipboardData| { &mut m.text },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "html",
+            |m: &ClipboardData| { &m.html },
+            |m: &mut ClipboardData| { &mut m.html },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "image",
+            |m: &ClipboardData| { &m.image },
+            |m: &mut ClipboardData| { &mut m.image },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "image_width",
+            |m: &ClipboardData| { &m.image_width },
+            |m: &mut ClipboardData| { &mut m.image_width },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "image_height",
+            |m: &ClipboardData| { &m.image_height },
+            |m: &mut ClipboardData| { &mut m.image_height },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ClipboardData>(
+            "ClipboardData",
+            15,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for ClipboardData {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.text = is.read_string()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.html = is.read_string()?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.image = is.read_bytes()?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.image_width = is.read_uint32()?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.image_height = is.read_uint32()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.text.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.text);
+        }
+        if !self.html.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.html);
+        }
+        if !self.image.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.image);
+        }
+        if self.image_width != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.image_width, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.image_height != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.image_height, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.text.is_empty() {
+            os.write_string(1, &self.text)?;
+        }
+        if !self.html.is_empty() {
+            os.write_string(2, &self.html)?;
+        }
+        if !self.image.is_empty() {
+            os.write_bytes(3, &self.image)?;
+        }
+        if self.image_width != 0 {
+            os.write_uint32(4, self.image_width)?;
+        }
+        if self.image_height != 0 {
+            os.write_uint32(5, self.image_height)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> ClipboardData {
+        ClipboardData::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 15)
+    }
+
+    fn default_instance() -> &'static ClipboardData {
+        static instance: ClipboardData = ClipboardData {
+            text: ::std::string::String::new(),
+            html: ::std::string::String::new(),
+            image: ::std::vec::Vec::new(),
+            image_width: 0,
+            image_height: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for ClipboardData {
+    fn clear(&mut self) {
+        self.text.clear();
+        self.html.clear();
+        self.image.clear();
+        self.image_width = 0;
+        self.image_height = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ClipboardData {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ClipboardData {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ClipboardMessage {
+    // message oneof groups
+    pub union: ::std::option::Option<clipboard_message::Union>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ClipboardMessage {
+    fn default() -> &'a ClipboardMessage {
+        <ClipboardMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ClipboardMessage {
+    pub fn new() -> ClipboardMessage {
+        ::std::default::Default::default()
+    }
+
+    // .ClipboardData data = 1;
+
+    pub fn get_data(&self) -> &ClipboardData {
+        match self.union {
+            ::std::option::Option::Some(clipboard_message::Union::data(ref v)) => v,
+            _ => <ClipboardData as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_data(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_data(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(clipboard_message::Union::data(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ClipboardData) {
+        self.union = ::std::option::Option::Some(clipboard_message::Union::data(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_data(&mut self) -> &mut ClipboardData {
+        if let ::std::option::Option::Some(clipboard_message::Union::data(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(clipboard_message::Union::data(ClipboardData::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(clipboard_message::Union::data(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ClipboardData {
+        if self.has_data() {
+            match self.union.take() {
+                ::std::option::Option::Some(clipboard_message::Union::data(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ClipboardData::new()
+        }
+    }
+
+    // bool enabled = 2;
+
+    pub fn get_enabled(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(clipboard_message::Union::enabled(v)) => v,
+            _ => false,
+        }
+    }
+
+    pub fn clear_enabled(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_enabled(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(clipboard_message::Union::enabled(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enabled(&mut self, v: bool) {
+        self.union = ::std::option::Option::Some(clipboard_message::Union::enabled(v))
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ClipboardData>(
+            "data",
+            ClipboardMessage::has_data,
+            ClipboardMessage::get_data,
+            ClipboardMessage::mut_data,
+            ClipboardMessage::set_data,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+            "enabled",
+            ClipboardMessage::has_enabled,
+            ClipboardMessage::get_enabled,
+            ClipboardMessage::set_enabled,
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ClipboardMessage>(
+            "ClipboardMessage",
+            16,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for ClipboardMessage {
+    fn is_initialized(&self) -> bool {
+        if let Some(clipboard_message::Union::data(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(clipboard_message::Union::data(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(clipboard_message::Union::enabled(is.read_bool()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &clipboard_message::Union::data(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &clipboard_message::Union::enabled(v) => {
+                    my_size += 2;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &clipboard_message::Union::data(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &clipboard_message::Union::enabled(v) => {
+                    os.write_bool(2, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> ClipboardMessage {
+        ClipboardMessage::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 16)
+    }
+
+    fn default_instance() -> &'static ClipboardMessage {
+        static instance: ClipboardMessage = ClipboardMessage {
+            union: ::std::option::Option::None,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for ClipboardMessage {
+    fn clear(&mut self) {
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ClipboardMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ClipboardMessage {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `ClipboardMessage`
+pub mod clipboard_message {
+
+    #[derive(Clone,PartialEq,Debug)]
+    pub enum Union {
+        data(super::ClipboardData),
+        enabled(bool),
+    }
+
+    impl ::protobuf::Oneof for Union {
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileOffer {
+    // message fields
+    pub id: u64,
+    pub name: ::std::string::String,
+    pub size: u64,
+    pub sha256: ::std::vec::Vec<u8>,
+    pub dir: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileOffer {
+    fn default() -> &'a FileOffer {
+        <FileOffer as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileOffer {
+    pub fn new() -> FileOffer {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &FileOffer| { &m.id },
+            |m: &mut FileOffer| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &FileOffer| { &m.name },
+            |m: &mut FileOffer| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &FileOffer| { &m.size },
+            |m: &mut FileOffer| { &mut m.size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sha256",
+            |m: &FileOffer| { &m.sha256 },
+            |m: &mut FileOffer| { &mut m.sha256 },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "dir",
+            |m: &FileOffer| { &m.dir },
+            |m: &mut FileOffer| { &mut m.dir },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FileOffer>(
+            "FileOffer",
+            17,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for FileOffer {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.id = is.read_uint64()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.name = is.read_string()?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.size = is.read_uint64()?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.sha256 = is.read_bytes()?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.dir = is.read_string()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.sha256.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.sha256);
+        }
+        if !self.dir.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.dir);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if self.size != 0 {
+            os.write_uint64(3, self.size)?;
+        }
+        if !self.sha256.is_empty() {
+            os.write_bytes(4, &self.sha256)?;
+        }
+        if !self.dir.is_empty() {
+            os.write_string(5, &self.dir)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> FileOffer {
+        FileOffer::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 17)
+    }
+
+    fn default_instance() -> &'static FileOffer {
+        static instance: FileOffer = FileOffer {
+            id: 0,
+            name: ::std::string::String::new(),
+            size: 0,
+            sha256: ::std::vec::Vec::new(),
+            dir: ::std::string::String::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for FileOffer {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.name.clear();
+        self.size = 0;
+        self.sha256.clear();
+        self.dir.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileOffer {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileOffer {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileRequest {
+    // message fields
+    pub id: u64,
+    pub path: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileRequest {
+    fn default() -> &'a FileRequest {
+        <FileRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileRequest {
+    pub fn new() -> FileRequest {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &FileRequest| { &m.id },
+            |m: &mut FileRequest| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "path",
+            |m: &FileRequest| { &m.path },
+            |m: &mut FileRequest| { &mut m.path },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FileRequest>(
+            "FileRequest",
+            18,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for FileRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.id = is.read_uint64()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.path = is.read_string()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.path);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        if !self.path.is_empty() {
+            os.write_string(2, &self.path)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> FileRequest {
+        FileRequest::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 18)
+    }
+
+    fn default_instance() -> &'static FileRequest {
+        static instance: FileRequest = FileRequest {
+            id: 0,
+            path: ::std::string::String::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for FileRequest {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.path.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileRequest {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileAccept {
+    // message fields
+    pub id: u64,
+    pub offset: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileAccept {
+    fn default() -> &'a FileAccept {
+        <FileAccept as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileAccept {
+    pub fn new() -> FileAccept {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &FileAccept| { &m.id },
+            |m: &mut FileAccept| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "offset",
+            |m: &FileAccept| { &m.offset },
+            |m: &mut FileAccept| { &mut m.offset },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FileAccept>(
+            "FileAccept",
+            19,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for FileAccept {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.id = is.read_uint64()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.offset = is.read_uint64()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        if self.offset != 0 {
+            os.write_uint64(2, self.offset)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> FileAccept {
+        FileAccept::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 19)
+    }
+
+    fn default_instance() -> &'static FileAccept {
+        static instance: FileAccept = FileAccept {
+            id: 0,
+            offset: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for FileAccept {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.offset = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileAccept {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileAccept {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileReject {
+    // message fields
+    pub id: u64,
+    pub reason: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileReject {
+    fn default() -> &'a FileReject {
+        <FileReject as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileReject {
+    pub fn new() -> FileReject {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &FileReject| { &m.id },
+            |m: &mut FileReject| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "reason",
+            |m: &FileReject| { &m.reason },
+            |m: &mut FileReject| { &mut m.reason },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FileReject>(
+            "FileReject",
+            20,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for FileReject {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.id = is.read_uint64()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.reason = is.read_string()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.reason.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.reason);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        if !self.reason.is_empty() {
+            os.write_string(2, &self.reason)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> FileReject {
+        FileReject::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 20)
+    }
+
+    fn default_instance() -> &'static FileReject {
+        static instance: FileReject = FileReject {
+            id: 0,
+            reason: ::std::string::String::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for FileReject {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.reason.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileReject {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileReject {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileChunk {
+    // message fields
+    pub id: u64,
+    pub offset: u64,
+    pub data: ::std::vec::Vec<u8>,
+    pub sha256: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileChunk {
+    fn default() -> &'a FileChunk {
+        <FileChunk as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileChunk {
+    pub fn new() -> FileChunk {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &FileChunk| { &m.id },
+            |m: &mut FileChunk| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "offset",
+            |m: &FileChunk| { &m.offset },
+            |m: &mut FileChunk| { &mut m.offset },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "data",
+            |m: &FileChunk| { &m.data },
+            |m: &mut FileChunk| { &mut m.data },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sha256",
+            |m: &FileChunk| { &m.sha256 },
+            |m: &mut FileChunk| { &mut m.sha256 },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FileChunk>(
+            "FileChunk",
+            21,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for FileChunk {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.id = is.read_uint64()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.offset = is.read_uint64()?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = is.read_bytes()?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.sha256 = is.read_bytes()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.data);
+        }
+        if !self.sha256.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.sha256);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        if self.offset != 0 {
+            os.write_uint64(2, self.offset)?;
+        }
+        if !self.data.is_empty() {
+            os.write_bytes(3, &self.data)?;
+        }
+        if !self.sha256.is_empty() {
+            os.write_bytes(4, &self.sha256)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> FileChunk {
+        FileChunk::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 21)
+    }
+
+    fn default_instance() -> &'static FileChunk {
+        static instance: FileChunk = FileChunk {
+            id: 0,
+            offset: 0,
+            data: ::std::vec::Vec::new(),
+            sha256: ::std::vec::Vec::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for FileChunk {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.offset = 0;
+        self.data.clear();
+        self.sha256.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileChunk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileChunk {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileDone {
+    // message fields
+    pub id: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileDone {
+    fn default() -> &'a FileDone {
+        <FileDone as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileDone {
+    pub fn new() -> FileDone {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &FileDone| { &m.id },
+            |m: &mut FileDone| { &mut m.id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FileDone>(
+            "FileDone",
+            22,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for FileDone {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.id = is.read_uint64()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> FileDone {
+        FileDone::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 22)
+    }
+
+    fn default_instance() -> &'static FileDone {
+        static instance: FileDone = FileDone {
+            id: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for FileDone {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileDone {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileDone {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileResult {
+    // message fields
+    pub id: u64,
+    pub ok: bool,
+    pub error: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileResult {
+    fn default() -> &'a FileResult {
+        <FileResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileResult {
+    pub fn new() -> FileResult {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &FileResult| { &m.id },
+            |m: &mut FileResult| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "ok",
+            |m: &FileResult| { &m.ok },
+            |m: &mut FileResult| { &mut m.ok },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &FileResult| { &m.error },
+            |m: &mut FileResult| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FileResult>(
+            "FileResult",
+            23,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for FileResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.id = is.read_uint64()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.ok = is.read_bool()?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.error = is.read_string()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ok != false {
+            my_size += 2;
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        if self.ok != false {
+            os.write_bool(2, self.ok)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(3, &self.error)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> FileResult {
+        FileResult::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 23)
+    }
+
+    fn default_instance() -> &'static FileResult {
+        static instance: FileResult = FileResult {
+            id: 0,
+            ok: false,
+            error: ::std::string::String::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for FileResult {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.ok = false;
+        self.error.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileResult {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileTransferMessage {
+    // message oneof groups
+    pub union: ::std::option::Option<file_transfer_message::Union>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileTransferMessage {
+    fn default() -> &'a FileTransferMessage {
+        <FileTransferMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileTransferMessage {
+    pub fn new() -> FileTransferMessage {
+        ::std::default::Default::default()
+    }
+
+    // .FileOffer offer = 1;
+
+    pub fn get_offer(&self) -> &FileOffer {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::offer(ref v)) => v,
+            _ => <FileOffer as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_offer(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_offer(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::offer(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_offer(&mut self, v: FileOffer) {
+        self.union = ::std::option::Option::Some(file_transfer_message::Union::offer(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_offer(&mut self) -> &mut FileOffer {
+        if let ::std::option::Option::Some(file_transfer_message::Union::offer(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(file_transfer_message::Union::offer(FileOffer::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::offer(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_offer(&mut self) -> FileOffer {
+        if self.has_offer() {
+            match self.union.take() {
+                ::std::option::Option::Some(file_transfer_message::Union::offer(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileOffer::new()
+        }
+    }
+
+    // .FileRequest request = 2;
+
+    pub fn get_request(&self) -> &FileRequest {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::request(ref v)) => v,
+            _ => <FileRequest as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_request(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_request(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::request(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_request(&mut self, v: FileRequest) {
+        self.union = ::std::option::Option::Some(file_transfer_message::Union::request(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_request(&mut self) -> &mut FileRequest {
+        if let ::std::option::Option::Some(file_transfer_message::Union::request(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(file_transfer_message::Union::request(FileRequest::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::request(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_request(&mut self) -> FileRequest {
+        if self.has_request() {
+            match self.union.take() {
+                ::std::option::Option::Some(file_transfer_message::Union::request(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileRequest::new()
+        }
+    }
+
+    // .FileAccept accept = 3;
+
+    pub fn get_accept(&self) -> &FileAccept {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::accept(ref v)) => v,
+            _ => <FileAccept as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_accept(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_accept(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::accept(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accept(&mut self, v: FileAccept) {
+        self.union = ::std::option::Option::Some(file_transfer_message::Union::accept(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accept(&mut self) -> &mut FileAccept {
+        if let ::std::option::Option::Some(file_transfer_message::Union::accept(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(file_transfer_message::Union::accept(FileAccept::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::accept(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_accept(&mut self) -> FileAccept {
+        if self.has_accept() {
+            match self.union.take() {
+                ::std::option::Option::Some(file_transfer_message::Union::accept(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileAccept::new()
+        }
+    }
+
+    // .FileReject reject = 4;
+
+    pub fn get_reject(&self) -> &FileReject {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::reject(ref v)) => v,
+            _ => <FileReject as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_reject(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_reject(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::reject(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reject(&mut self, v: FileReject) {
+        self.union = ::std::option::Option::Some(file_transfer_message::Union::reject(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_reject(&mut self) -> &mut FileReject {
+        if let ::std::option::Option::Some(file_transfer_message::Union::reject(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(file_transfer_message::Union::reject(FileReject::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::reject(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_reject(&mut self) -> FileReject {
+        if self.has_reject() {
+            match self.union.take() {
+                ::std::option::Option::Some(file_transfer_message::Union::reject(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileReject::new()
+        }
+    }
+
+    // .FileChunk chunk = 5;
+
+    pub fn get_chunk(&self) -> &FileChunk {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::chunk(ref v)) => v,
+            _ => <FileChunk as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_chunk(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_chunk(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::chunk(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chunk(&mut self, v: FileChunk) {
+        self.union = ::std::option::Option::Some(file_transfer_message::Union::chunk(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_chunk(&mut self) -> &mut FileChunk {
+        if let ::std::option::Option::Some(file_transfer_message::Union::chunk(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(file_transfer_message::Union::chunk(FileChunk::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::chunk(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_chunk(&mut self) -> FileChunk {
+        if self.has_chunk() {
+            match self.union.take() {
+                ::std::option::Option::Some(file_transfer_message::Union::chunk(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileChunk::new()
+        }
+    }
+
+    // .FileDone done = 6;
+
+    pub fn get_done(&self) -> &FileDone {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::done(ref v)) => v,
+            _ => <FileDone as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_done(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_done(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::done(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_done(&mut self, v: FileDone) {
+        self.union = ::std::option::Option::Some(file_transfer_message::Union::done(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_done(&mut self) -> &mut FileDone {
+        if let ::std::option::Option::Some(file_transfer_message::Union::done(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(file_transfer_message::Union::done(FileDone::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::done(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_done(&mut self) -> FileDone {
+        if self.has_done() {
+            match self.union.take() {
+                ::std::option::Option::Some(file_transfer_message::Union::done(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileDone::new()
+        }
+    }
+
+    // .FileResult result = 7;
+
+    pub fn get_result(&self) -> &FileResult {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::result(ref v)) => v,
+            _ => <FileResult as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_result(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_result(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::result(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: FileResult) {
+        self.union = ::std::option::Option::Some(file_transfer_message::Union::result(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_result(&mut self) -> &mut FileResult {
+        if let ::std::option::Option::Some(file_transfer_message::Union::result(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(file_transfer_message::Union::result(FileResult::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(file_transfer_message::Union::result(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_result(&mut self) -> FileResult {
+        if self.has_result() {
+            match self.union.take() {
+                ::std::option::Option::Some(file_transfer_message::Union::result(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            FileResult::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, FileOffer>(
+            "offer",
+            FileTransferMessage::has_offer,
+            FileTransferMessage::get_offer,
+            FileTransferMessage::mut_offer,
+            FileTransferMessage::set_offer,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, FileRequest>(
+            "request",
+            FileTransferMessage::has_request,
+            FileTransferMessage::get_request,
+            FileTransferMessage::mut_request,
+            FileTransferMessage::set_request,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, FileAccept>(
+            "accept",
+            FileTransferMessage::has_accept,
+            FileTransferMessage::get_accept,
+            FileTransferMessage::mut_accept,
+            FileTransferMessage::set_accept,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, FileReject>(
+            "reject",
+            FileTransferMessage::has_reject,
+            FileTransferMessage::get_reject,
+            FileTransferMessage::mut_reject,
+            FileTransferMessage::set_reject,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, FileChunk>(
+            "chunk",
+            FileTransferMessage::has_chunk,
+            FileTransferMessage::get_chunk,
+            FileTransferMessage::mut_chunk,
+            FileTransferMessage::set_chunk,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, FileDone>(
+            "done",
+            FileTransferMessage::has_done,
+            FileTransferMessage::get_done,
+            FileTransferMessage::mut_done,
+            FileTransferMessage::set_done,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, FileResult>(
+            "result",
+            FileTransferMessage::has_result,
+            FileTransferMessage::get_result,
+            FileTransferMessage::mut_result,
+            FileTransferMessage::set_result,
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FileTransferMessage>(
+            "FileTransferMessage",
+            24,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for FileTransferMessage {
+    fn is_initialized(&self) -> bool {
+        if let Some(file_transfer_message::Union::offer(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(file_transfer_message::Union::request(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(file_transfer_message::Union::accept(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(file_transfer_message::Union::reject(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(file_transfer_message::Union::chunk(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(file_transfer_message::Union::done(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(file_transfer_message::Union::result(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(file_transfer_message::Union::offer(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(file_transfer_message::Union::request(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(file_transfer_message::Union::accept(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(file_transfer_message::Union::reject(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(file_transfer_message::Union::chunk(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(file_transfer_message::Union::done(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(file_transfer_message::Union::result(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &file_transfer_message::Union::offer(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &file_transfer_message::Union::request(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &file_transfer_message::Union::accept(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &file_transfer_message::Union::reject(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &file_transfer_message::Union::chunk(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &file_transfer_message::Union::done(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &file_transfer_message::Union::result(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &file_transfer_message::Union::offer(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &file_transfer_message::Union::request(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+                &file_transfer_message::Union::accept(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+                },
+                &file_transfer_message::Union::reject(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+                &file_transfer_message::Union::chunk(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+                &file_transfer_message::Union::done(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+                },
+                &file_transfer_message::Union::result(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> FileTransferMessage {
+        FileTransferMessage::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 24)
+    }
+
+    fn default_instance() -> &'static FileTransferMessage {
+        static instance: FileTransferMessage = FileTransferMessage {
+            union: ::std::option::Option::None,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for FileTransferMessage {
+    fn clear(&mut self) {
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileTransferMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileTransferMessage {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `FileTransferMessage`
+pub mod file_transfer_message {
+
+    #[derive(Clone,PartialEq,Debug)]
+    pub enum Union {
+        offer(super::FileOffer),
+        request(super::FileRequest),
+        accept(super::FileAccept),
+        reject(super::FileReject),
+        chunk(super::FileChunk),
+        done(super::FileDone),
+        result(super::FileResult),
+    }
+
+    impl ::protobuf::Oneof for Union {
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileEntry {
+    // message fields
+    pub name: ::std::string::String,
+    pub size: u64,
+    pub modified: u64,
+    pub entry_type: ::protobuf::ProtobufEnumOrUnknown<file_entry::Type>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileEntry {
+    fn default() -> &'a FileEntry {
+        <FileEntry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileEntry {
+    pub fn new() -> FileEntry {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &FileEntry| { &m.name },
+            |m: &mut FileEntry| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "size",
+            |m: &FileEntry| { &m.size },
+            |m: &mut FileEntry| { &mut m.size },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "modified",
+            |m: &FileEntry| { &m.modified },
+            |m: &mut FileEntry| { &mut m.modified },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "entry_type",
+            |m: &FileEntry| { &m.entry_type },
+            |m: &mut FileEntry| { &mut m.entry_type },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FileEntry>(
+            "FileEntry",
+            25,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for FileEntry {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.name = is.read_string()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.size = is.read_uint64()?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.modified = is.read_uint64()?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.entry_type = is.read_enum_or_unknown()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.size != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.size, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.modified != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.modified, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.entry_type != ::protobuf::ProtobufEnumOrUnknown::new(file_entry::Type::File) {
+            my_size += ::protobuf::rt::enum_or_unknown_size(4, self.entry_type);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.size != 0 {
+            os.write_uint64(2, self.size)?;
+        }
+        if self.modified != 0 {
+            os.write_uint64(3, self.modified)?;
+        }
+        if self.entry_type != ::protobuf::ProtobufEnumOrUnknown::new(file_entry::Type::File) {
+            os.write_enum(4, ::protobuf::ProtobufEnumOrUnknown::value(&self.entry_type))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> FileEntry {
+        FileEntry::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 25)
+    }
+
+    fn default_instance() -> &'static FileEntry {
+        static instance: FileEntry = FileEntry {
+            name: ::std::string::String::new(),
+            size: 0,
+            modified: 0,
+            entry_type: ::protobuf::ProtobufEnumOrUnknown::from_i32(0),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for FileEntry {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.size = 0;
+        self.modified = 0;
+        self.entry_type = ::protobuf::ProtobufEnumOrUnknown::new(file_entry::Type::File);
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileEntry {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `FileEntry`
+pub mod file_entry {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    pub enum Type {
+        File = 0,
+        Dir = 1,
+        Symlink = 2,
+        Other = 3,
+    }
+
+    impl ::protobuf::ProtobufEnum for Type {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Type> {
+            match value {
+                0 => ::std::option::Option::Some(Type::File),
+                1 => ::std::option::Option::Some(Type::Dir),
+                2 => ::std::option::Option::Some(Type::Symlink),
+                3 => ::std::option::Option::Some(Type::Other),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [Type] = &[
+                Type::File,
+                Type::Dir,
+                Type::Symlink,
+                Type::Other,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> ::protobuf::reflect::EnumDescriptor {
+            ::protobuf::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 8)
+        }
+    }
+
+    impl ::std::default::Default for Type {
+        fn default() -> Self {
+            Type::File
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Type {
+        type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeEnum<Self>;
+    }
+
+    impl Type {
+        pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new_2::<Type>("FileEntry.Type", 8)
+        }
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RenameFile {
+    // message fields
+    pub from: ::std::string::String,
+    pub to: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RenameFile {
+    fn default() -> &'a RenameFile {
+        <RenameFile as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RenameFile {
+    pub fn new() -> RenameFile {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "from",
+            |m: &RenameFile| { &m.from },
+            |m: &mut RenameFile| { &mut m.from },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "to",
+            |m: &RenameFile| { &m.to },
+            |m: &mut RenameFile| { &mut m.to },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RenameFile>(
+            "RenameFile",
+            26,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for RenameFile {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.from = is.read_string()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.to = is.read_string()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.from.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.from);
+        }
+        if !self.to.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.to);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.from.is_empty() {
+            os.write_string(1, &self.from)?;
+        }
+        if !self.to.is_empty() {
+            os.write_string(2, &self.to)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> RenameFile {
+        RenameFile::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 26)
+    }
+
+    fn default_instance() -> &'static RenameFile {
+        static instance: RenameFile = RenameFile {
+            from: ::std::string::String::new(),
+            to: ::std::string::String::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for RenameFile {
+    fn clear(&mut self) {
+        self.from.clear();
+        self.to.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RenameFile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RenameFile {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RemoveFile {
+    // message fields
+    pub path: ::std::string::String,
+    pub recursive: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RemoveFile {
+    fn default() -> &'a RemoveFile {
+        <RemoveFile as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RemoveFile {
+    pub fn new() -> RemoveFile {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "path",
+            |m: &RemoveFile| { &m.path },
+            |m: &mut RemoveFile| { &mut m.path },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "recursive",
+            |m: &RemoveFile| { &m.recursive },
+            |m: &mut RemoveFile| { &mut m.recursive },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RemoveFile>(
+            "RemoveFile",
+            27,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for RemoveFile {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.path = is.read_string()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.recursive = is.read_bool()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.path);
+        }
+        if self.recursive != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_string(1, &self.path)?;
+        }
+        if self.recursive != false {
+            os.write_bool(2, self.recursive)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> RemoveFile {
+        RemoveFile::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 27)
+    }
+
+    fn default_instance() -> &'static RemoveFile {
+        static instance: RemoveFile = RemoveFile {
+            path: ::std::string::String::new(),
+            recursive: false,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for RemoveFile {
+    fn clear(&mut self) {
+        self.path.clear();
+        self.recursive = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RemoveFile {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RemoveFile {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileBrowserRequest {
+    // message fields
+    pub id: u64,
+    // message oneof groups
+    pub union: ::std::option::Option<file_browser_request::Union>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileBrowserRequest {
+    fn default() -> &'a FileBrowserRequest {
+        <FileBrowserRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileBrowserRequest {
+    pub fn new() -> FileBrowserRequest {
+        ::std::default::Default::default()
+    }
+
+    // bool roots = 2;
+
+    pub fn get_roots(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::roots(v)) => v,
+            _ => false,
+        }
+    }
+
+    pub fn clear_roots(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_roots(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::roots(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_roots(&mut self, v: bool) {
+        self.union = ::std::option::Option::Some(file_browser_request::Union::roots(v))
+    }
+
+    // string list_dir = 3;
+
+    pub fn get_list_dir(&self) -> &str {
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::list_dir(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    pub fn clear_list_dir(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_list_dir(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::list_dir(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_list_dir(&mut self, v: ::std::string::String) {
+        self.union = ::std::option::Option::Some(file_browser_request::Union::list_dir(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_list_dir(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(file_browser_request::Union::list_dir(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(file_browser_request::Union::list_dir(::std::string::String::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::list_dir(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_list_dir(&mut self) -> ::std::string::String {
+        if self.has_list_dir() {
+            match self.union.take() {
+                ::std::option::Option::Some(file_browser_request::Union::list_dir(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string stat = 4;
+
+    pub fn get_stat(&self) -> &str {
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::stat(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    pub fn clear_stat(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_stat(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::stat(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stat(&mut self, v: ::std::string::String) {
+        self.union = ::std::option::Option::Some(file_browser_request::Union::stat(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_stat(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(file_browser_request::Union::stat(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(file_browser_request::Union::stat(::std::string::String::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::stat(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_stat(&mut self) -> ::std::string::String {
+        if self.has_stat() {
+            match self.union.take() {
+                ::std::option::Option::Some(file_browser_request::Union::stat(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string mkdir = 5;
+
+    pub fn get_mkdir(&self) -> &str {
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::mkdir(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    pub fn clear_mkdir(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_mkdir(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::mkdir(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mkdir(&mut self, v: ::std::string::String) {
+        self.union = ::std::option::Option::Some(file_browser_request::Union::mkdir(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mkdir(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(file_browser_request::Union::mkdir(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(file_browser_request::Union::mkdir(::std::string::String::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::mkdir(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_mkdir(&mut self) -> ::std::string::String {
+        if self.has_mkdir() {
+            match self.union.take() {
+                ::std::option::Option::Some(file_browser_request::Union::mkdir(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .RenameFile rename = 6;
+
+    pub fn get_rename(&self) -> &RenameFile {
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::rename(ref v)) => v,
+            _ => <RenameFile as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_rename(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_rename(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::rename(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rename(&mut self, v: RenameFile) {
+        self.union = ::std::option::Option::Some(file_browser_request::Union::rename(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rename(&mut self) -> &mut RenameFile {
+        if let ::std::option::Option::Some(file_browser_request::Union::rename(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(file_browser_request::Union::rename(RenameFile::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::rename(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_rename(&mut self) -> RenameFile {
+        if self.has_rename() {
+            match self.union.take() {
+                ::std::option::Option::Some(file_browser_request::Union::rename(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RenameFile::new()
+        }
+    }
+
+    // .RemoveFile remove = 7;
+
+    pub fn get_remove(&self) -> &RemoveFile {
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::remove(ref v)) => v,
+            _ => <RemoveFile as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_remove(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_remove(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::remove(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remove(&mut self, v: RemoveFile) {
+        self.union = ::std::option::Option::Some(file_browser_request::Union::remove(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_remove(&mut self) -> &mut RemoveFile {
+        if let ::std::option::Option::Some(file_browser_request::Union::remove(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(file_browser_request::Union::remove(RemoveFile::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(file_browser_request::Union::remove(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_remove(&mut self) -> RemoveFile {
+        if self.has_remove() {
+            match self.union.take() {
+                ::std::option::Option::Some(file_browser_request::Union::remove(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            RemoveFile::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &FileBrowserRequest| { &m.id },
+            |m: &mut FileBrowserRequest| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+            "roots",
+            FileBrowserRequest::has_roots,
+            FileBrowserRequest::get_roots,
+            FileBrowserRequest::set_roots,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "list_dir",
+            FileBrowserRequest::has_list_dir,
+            FileBrowserRequest::get_list_dir,
+            FileBrowserRequest::set_list_dir,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "stat",
+            FileBrowserRequest::has_stat,
+            FileBrowserRequest::get_stat,
+            FileBrowserRequest::set_stat,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "mkdir",
+            FileBrowserRequest::has_mkdir,
+            FileBrowserRequest::get_mkdir,
+            FileBrowserRequest::set_mkdir,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, RenameFile>(
+            "rename",
+            FileBrowserRequest::has_rename,
+            FileBrowserRequest::get_rename,
+            FileBrowserRequest::mut_rename,
+            FileBrowserRequest::set_rename,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, RemoveFile>(
+            "remove",
+            FileBrowserRequest::has_remove,
+            FileBrowserRequest::get_remove,
+            FileBrowserRequest::mut_remove,
+            FileBrowserRequest::set_remove,
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FileBrowserRequest>(
+            "FileBrowserRequest",
+            28,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for FileBrowserRequest {
+    fn is_initialized(&self) -> bool {
+        if let Some(file_browser_request::Union::rename(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(file_browser_request::Union::remove(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.id = is.read_uint64()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(file_browser_request::Union::roots(is.read_bool()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(file_browser_request::Union::list_dir(is.read_string()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(file_browser_request::Union::stat(is.read_string()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(file_browser_request::Union::mkdir(is.read_string()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(file_browser_request::Union::rename(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(file_browser_request::Union::remove(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &file_browser_request::Union::roots(v) => {
+                    my_size += 2;
+                },
+                &file_browser_request::Union::list_dir(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+                &file_browser_request::Union::stat(ref v) => {
+                    my_size += ::protobuf::rt::string_size(4, &v);
+                },
+                &file_browser_request::Union::mkdir(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+                &file_browser_request::Union::rename(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &file_browser_request::Union::remove(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &file_browser_request::Union::roots(v) => {
+                    os.write_bool(2, v)?;
+                },
+                &file_browser_request::Union::list_dir(ref v) => {
+                    os.write_string(3, v)?;
+                },
+                &file_browser_request::Union::stat(ref v) => {
+                    os.write_string(4, v)?;
+                },
+                &file_browser_request::Union::mkdir(ref v) => {
+                    os.write_string(5, v)?;
+                },
+                &file_browser_request::Union::rename(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+                },
+                &file_browser_request::Union::remove(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> FileBrowserRequest {
+        FileBrowserRequest::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 28)
+    }
+
+    fn default_instance() -> &'static FileBrowserRequest {
+        static instance: FileBrowserRequest = FileBrowserRequest {
+            id: 0,
+            union: ::std::option::Option::None,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for FileBrowserRequest {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileBrowserRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileBrowserRequest {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `FileBrowserRequest`
+pub mod file_browser_request {
+
+    #[derive(Clone,PartialEq,Debug)]
+    pub enum Union {
+        roots(bool),
+        list_dir(::std::string::String),
+        stat(::std::string::String),
+        mkdir(::std::string::String),
+        rename(super::RenameFile),
+        remove(super::RemoveFile),
+    }
+
+    impl ::protobuf::Oneof for Union {
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FileBrowserResponse {
+    // message fields
+    pub id: u64,
+    pub error: ::std::string::String,
+    pub entries: ::std::vec::Vec<FileEntry>,
+    pub read_only: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FileBrowserResponse {
+    fn default() -> &'a FileBrowserResponse {
+        <FileBrowserResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FileBrowserResponse {
+    pub fn new() -> FileBrowserResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &FileBrowserResponse| { &m.id },
+            |m: &mut FileBrowserResponse| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &FileBrowserResponse| { &m.error },
+            |m: &mut FileBrowserResponse| { &mut m.error },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "entries",
+            |m: &FileBrowserResponse| { &m.entries },
+            |m: &mut FileBrowserResponse| { &mut m.entries },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "read_only",
+            |m: &FileBrowserResponse| { &m.read_only },
+            |m: &mut FileBrowserResponse| { &mut m.read_only },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<FileBrowserResponse>(
+            "FileBrowserResponse",
+            29,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for FileBrowserResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.entries {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.id = is.read_uint64()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.error = is.read_string()?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into_vec(wire_type, is, &mut self.entries)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.read_only = is.read_bool()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        for value in &self.entries {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.read_only != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        for v in &self.entries {
+            ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+        };
+        if self.read_only != false {
+            os.write_bool(4, self.read_only)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> FileBrowserResponse {
+        FileBrowserResponse::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 29)
+    }
+
+    fn default_instance() -> &'static FileBrowserResponse {
+        static instance: FileBrowserResponse = FileBrowserResponse {
+            id: 0,
+            error: ::std::string::String::new(),
+            entries: ::std::vec::Vec::new(),
+            read_only: false,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for FileBrowserResponse {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.error.clear();
+        self.entries.clear();
+        self.read_only = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FileBrowserResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FileBrowserResponse {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TerminalSize {
+    // message fields
+    pub rows: u32,
+    pub cols: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TerminalSize {
+    fn default() -> &'a TerminalSize {
+        <TerminalSize as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TerminalSize {
+    pub fn new() -> TerminalSize {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "rows",
+            |m: &TerminalSize| { &m.rows },
+            |m: &mut TerminalSize| { &mut m.rows },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "cols",
+            |m: &TerminalSize| { &m.cols },
+            |m: &mut TerminalSize| { &mut m.cols },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TerminalSize>(
+            "TerminalSize",
+            30,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for TerminalSize {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.rows = is.read_uint32()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.cols = is.read_uint32()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.rows != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.rows, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cols != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.cols, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.rows != 0 {
+            os.write_uint32(1, self.rows)?;
+        }
+        if self.cols != 0 {
+            os.write_uint32(2, self.cols)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> TerminalSize {
+        TerminalSize::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 30)
+    }
+
+    fn default_instance() -> &'static TerminalSize {
+        static instance: TerminalSize = TerminalSize {
+            rows: 0,
+            cols: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for TerminalSize {
+    fn clear(&mut self) {
+        self.rows = 0;
+        self.cols = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TerminalSize {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TerminalSize {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TerminalExit {
+    // message fields
+    pub code: i32,
+    pub error: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TerminalExit {
+    fn default() -> &'a TerminalExit {
+        <TerminalExit as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TerminalExit {
+    pub fn new() -> TerminalExit {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &TerminalExit| { &m.code },
+            |m: &mut TerminalExit| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &TerminalExit| { &m.error },
+            |m: &mut TerminalExit| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TerminalExit>(
+            "TerminalExit",
+            31,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for TerminalExit {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.code = is.read_int32()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.error = is.read_string()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.code != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.code, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.code != 0 {
+            os.write_int32(1, self.code)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> TerminalExit {
+        TerminalExit::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 31)
+    }
+
+    fn default_instance() -> &'static TerminalExit {
+        static instance: TerminalExit = TerminalExit {
+            code: 0,
+            error: ::std::string::String::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for TerminalExit {
+    fn clear(&mut self) {
+        self.code = 0;
+        self.error.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TerminalExit {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TerminalExit {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TerminalMessage {
+    // message oneof groups
+    pub union: ::std::option::Option<terminal_message::Union>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TerminalMessage {
+    fn default() -> &'a TerminalMessage {
+        <TerminalMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TerminalMessage {
+    pub fn new() -> TerminalMessage {
+        ::std::default::Default::default()
+    }
+
+    // .TerminalSize open = 1;
+
+    pub fn get_open(&self) -> &TerminalSize {
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::open(ref v)) => v,
+            _ => <TerminalSize as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_open(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_open(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::open(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_open(&mut self, v: TerminalSize) {
+        self.union = ::std::option::Option::Some(terminal_message::Union::open(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_open(&mut self) -> &mut TerminalSize {
+        if let ::std::option::Option::Some(terminal_message::Union::open(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(terminal_message::Union::open(TerminalSize::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::open(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_open(&mut self) -> TerminalSize {
+        if self.has_open() {
+            match self.union.take() {
+                ::std::option::Option::Some(terminal_message::Union::open(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            TerminalSize::new()
+        }
+    }
+
+    // bytes input = 2;
+
+    pub fn get_input(&self) -> &[u8] {
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::input(ref v)) => v,
+            _ => &[],
+        }
+    }
+
+    pub fn clear_input(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_input(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::input(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_input(&mut self, v: ::std::vec::Vec<u8>) {
+        self.union = ::std::option::Option::Some(terminal_message::Union::input(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_input(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if let ::std::option::Option::Some(terminal_message::Union::input(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(terminal_message::Union::input(::std::vec::Vec::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::input(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_input(&mut self) -> ::std::vec::Vec<u8> {
+        if self.has_input() {
+            match self.union.take() {
+                ::std::option::Option::Some(terminal_message::Union::input(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::vec::Vec::new()
+        }
+    }
+
+    // bytes output = 3;
+
+    pub fn get_output(&self) -> &[u8] {
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::output(ref v)) => v,
+            _ => &[],
+        }
+    }
+
+    pub fn clear_output(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_output(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::output(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_output(&mut self, v: ::std::vec::Vec<u8>) {
+        self.union = ::std::option::Option::Some(terminal_message::Union::output(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_output(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if let ::std::option::Option::Some(terminal_message::Union::output(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(terminal_message::Union::output(::std::vec::Vec::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::output(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_output(&mut self) -> ::std::vec::Vec<u8> {
+        if self.has_output() {
+            match self.union.take() {
+                ::std::option::Option::Some(terminal_message::Union::output(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::vec::Vec::new()
+        }
+    }
+
+    // .TerminalSize resize = 4;
+
+    pub fn get_resize(&self) -> &TerminalSize {
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::resize(ref v)) => v,
+            _ => <TerminalSize as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_resize(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_resize(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::resize(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resize(&mut self, v: TerminalSize) {
+        self.union = ::std::option::Option::Some(terminal_message::Union::resize(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_resize(&mut self) -> &mut TerminalSize {
+        if let ::std::option::Option::Some(terminal_message::Union::resize(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(terminal_message::Union::resize(TerminalSize::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::resize(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_resize(&mut self) -> TerminalSize {
+        if self.has_resize() {
+            match self.union.take() {
+                ::std::option::Option::Some(terminal_message::Union::resize(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            TerminalSize::new()
+        }
+    }
+
+    // .TerminalExit exit = 5;
+
+    pub fn get_exit(&self) -> &TerminalExit {
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::exit(ref v)) => v,
+            _ => <TerminalExit as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_exit(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_exit(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::exit(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exit(&mut self, v: TerminalExit) {
+        self.union = ::std::option::Option::Some(terminal_message::Union::exit(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_exit(&mut self) -> &mut TerminalExit {
+        if let ::std::option::Option::Some(terminal_message::Union::exit(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(terminal_message::Union::exit(TerminalExit::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(terminal_message::Union::exit(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_exit(&mut self) -> TerminalExit {
+        if self.has_exit() {
+            match self.union.take() {
+                ::std::option::Option::Some(terminal_message::Union::exit(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            TerminalExit::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, TerminalSize>(
+            "open",
+            TerminalMessage::has_open,
+            TerminalMessage::get_open,
+            TerminalMessage::mut_open,
+            TerminalMessage::set_open,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "input",
+            TerminalMessage::has_input,
+            TerminalMessage::get_input,
+            TerminalMessage::set_input,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "output",
+            TerminalMessage::has_output,
+            TerminalMessage::get_output,
+            TerminalMessage::set_output,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, TerminalSize>(
+            "resize",
+            TerminalMessage::has_resize,
+            TerminalMessage::get_resize,
+            TerminalMessage::mut_resize,
+            TerminalMessage::set_resize,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, TerminalExit>(
+            "exit",
+            TerminalMessage::has_exit,
+            TerminalMessage::get_exit,
+            TerminalMessage::mut_exit,
+            TerminalMessage::set_exit,
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<TerminalMessage>(
+            "TerminalMessage",
+            32,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for TerminalMessage {
+    fn is_initialized(&self) -> bool {
+        if let Some(terminal_message::Union::open(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(terminal_message::Union::resize(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(terminal_message::Union::exit(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(terminal_message::Union::open(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(terminal_message::Union::input(is.read_bytes()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(terminal_message::Union::output(is.read_bytes()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(terminal_message::Union::resize(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(terminal_message::Union::exit(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &terminal_message::Union::open(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &terminal_message::Union::input(ref v) => {
+                    my_size += ::protobuf::rt::bytes_size(2, &v);
+                },
+                &terminal_message::Union::output(ref v) => {
+                    my_size += ::protobuf::rt::bytes_size(3, &v);
+                },
+                &terminal_message::Union::resize(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &terminal_message::Union::exit(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &terminal_message::Union::open(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &terminal_message::Union::input(ref v) => {
+                    os.write_bytes(2, v)?;
+                },
+                &terminal_message::Union::output(ref v) => {
+                    os.write_bytes(3, v)?;
+                },
+                &terminal_message::Union::resize(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+                &terminal_message::Union::exit(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> TerminalMessage {
+        TerminalMessage::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 32)
+    }
+
+    fn default_instance() -> &'static TerminalMessage {
+        static instance: TerminalMessage = TerminalMessage {
+            union: ::std::option::Option::None,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for TerminalMessage {
+    fn clear(&mut self) {
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TerminalMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TerminalMessage {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `TerminalMessage`
+pub mod terminal_message {
+
+    #[derive(Clone,PartialEq,Debug)]
+    pub enum Union {
+        open(super::TerminalSize),
+        input(::std::vec::Vec<u8>),
+        output(::std::vec::Vec<u8>),
+        resize(super::TerminalSize),
+        exit(super::TerminalExit),
+    }
+
+    impl ::protobuf::Oneof for Union {
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ForwardOpen {
+    // message fields
+    pub host: ::std::string::String,
+    pub port: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ForwardOpen {
+    fn default() -> &'a ForwardOpen {
+        <ForwardOpen as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ForwardOpen {
+    pub fn new() -> ForwardOpen {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "host",
+            |m: &ForwardOpen| { &m.host },
+            |m: &mut ForwardOpen| { &mut m.host },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "port",
+            |m: &ForwardOpen| { &m.port },
+            |m: &mut ForwardOpen| { &mut m.port },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ForwardOpen>(
+            "ForwardOpen",
+            33,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for ForwardOpen {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.host = is.read_string()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.port = is.read_uint32()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.host.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.host);
+        }
+        if self.port != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.port, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.host.is_empty() {
+            os.write_string(1, &self.host)?;
+        }
+        if self.port != 0 {
+            os.write_uint32(2, self.port)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> ForwardOpen {
+        ForwardOpen::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 33)
+    }
+
+    fn default_instance() -> &'static ForwardOpen {
+        static instance: ForwardOpen = ForwardOpen {
+            host: ::std::string::String::new(),
+            port: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for ForwardOpen {
+    fn clear(&mut self) {
+        self.host.clear();
+        self.port = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ForwardOpen {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ForwardOpen {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ForwardListen {
+    // message fields
+    pub port: u32,
+    pub host: ::std::string::String,
+    pub target_port: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ForwardListen {
+    fn default() -> &'a ForwardListen {
+        <ForwardListen as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ForwardListen {
+    pub fn new() -> ForwardListen {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "port",
+            |m: &ForwardListen| { &m.port },
+            |m: &mut ForwardListen| { &mut m.port },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "host",
+            |m: &ForwardListen| { &m.host },
+            |m: &mut ForwardListen| { &mut m.host },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "target_port",
+            |m: &ForwardListen| { &m.target_port },
+            |m: &mut ForwardListen| { &mut m.target_port },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ForwardListen>(
+            "ForwardListen",
+            34,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for ForwardListen {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.port = is.read_uint32()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.host = is.read_string()?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.target_port = is.read_uint32()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.port != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.port, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.host.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.host);
+        }
+        if self.target_port != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.target_port, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.port != 0 {
+            os.write_uint32(1, self.port)?;
+        }
+        if !self.host.is_empty() {
+            os.write_string(2, &self.host)?;
+        }
+        if self.target_port != 0 {
+            os.write_uint32(3, self.target_port)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> ForwardListen {
+        ForwardListen::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 34)
+    }
+
+    fn default_instance() -> &'static ForwardListen {
+        static instance: ForwardListen = ForwardListen {
+            port: 0,
+            host: ::std::string::String::new(),
+            target_port: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for ForwardListen {
+    fn clear(&mut self) {
+        self.port = 0;
+        self.host.clear();
+        self.target_port = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ForwardListen {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ForwardListen {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ForwardResult {
+    // message fields
+    pub ok: bool,
+    pub error: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ForwardResult {
+    fn default() -> &'a ForwardResult {
+        <ForwardResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ForwardResult {
+    pub fn new() -> ForwardResult {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "ok",
+            |m: &ForwardResult| { &m.ok },
+            |m: &mut ForwardResult| { &mut m.ok },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "error",
+            |m: &ForwardResult| { &m.error },
+            |m: &mut ForwardResult| { &mut m.error },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ForwardResult>(
+            "ForwardResult",
+            35,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for ForwardResult {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.ok = is.read_bool()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.error = is.read_string()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.ok != false {
+            my_size += 2;
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.ok != false {
+            os.write_bool(1, self.ok)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(2, &self.error)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> ForwardResult {
+        ForwardResult::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 35)
+    }
+
+    fn default_instance() -> &'static ForwardResult {
+        static instance: ForwardResult = ForwardResult {
+            ok: false,
+            error: ::std::string::String::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for ForwardResult {
+    fn clear(&mut self) {
+        self.ok = false;
+        self.error.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ForwardResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ForwardResult {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PortForwardMessage {
+    // message oneof groups
+    pub union: ::std::option::Option<port_forward_message::Union>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PortForwardMessage {
+    fn default() -> &'a PortForwardMessage {
+        <PortForwardMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PortForwardMessage {
+    pub fn new() -> PortForwardMessage {
+        ::std::default::Default::default()
+    }
+
+    // .ForwardOpen open = 1;
+
+    pub fn get_open(&self) -> &ForwardOpen {
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::open(ref v)) => v,
+            _ => <ForwardOpen as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_open(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_open(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::open(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_open(&mut self, v: ForwardOpen) {
+        self.union = ::std::option::Option::Some(port_forward_message::Union::open(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_open(&mut self) -> &mut ForwardOpen {
+        if let ::std::option::Option::Some(port_forward_message::Union::open(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(port_forward_message::Union::open(ForwardOpen::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::open(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_open(&mut self) -> ForwardOpen {
+        if self.has_open() {
+            match self.union.take() {
+                ::std::option::Option::Some(port_forward_message::Union::open(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ForwardOpen::new()
+        }
+    }
+
+    // .ForwardListen listen = 2;
+
+    pub fn get_listen(&self) -> &ForwardListen {
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::listen(ref v)) => v,
+            _ => <ForwardListen as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_listen(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_listen(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::listen(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_listen(&mut self, v: ForwardListen) {
+        self.union = ::std::option::Option::Some(port_forward_message::Union::listen(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_listen(&mut self) -> &mut ForwardListen {
+        if let ::std::option::Option::Some(port_forward_message::Union::listen(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(port_forward_message::Union::listen(ForwardListen::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::listen(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_listen(&mut self) -> ForwardListen {
+        if self.has_listen() {
+            match self.union.take() {
+                ::std::option::Option::Some(port_forward_message::Union::listen(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ForwardListen::new()
+        }
+    }
+
+    // .ForwardResult result = 3;
+
+    pub fn get_result(&self) -> &ForwardResult {
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::result(ref v)) => v,
+            _ => <ForwardResult as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_result(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_result(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::result(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: ForwardResult) {
+        self.union = ::std::option::Option::Some(port_forward_message::Union::result(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_result(&mut self) -> &mut ForwardResult {
+        if let ::std::option::Option::Some(port_forward_message::Union::result(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(port_forward_message::Union::result(ForwardResult::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::result(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_result(&mut self) -> ForwardResult {
+        if self.has_result() {
+            match self.union.take() {
+                ::std::option::Option::Some(port_forward_message::Union::result(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ForwardResult::new()
+        }
+    }
+
+    // bytes data = 4;
+
+    pub fn get_data(&self) -> &[u8] {
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::data(ref v)) => v,
+            _ => &[],
+        }
+    }
+
+    pub fn clear_data(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_data(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::data(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.union = ::std::option::Option::Some(port_forward_message::Union::data(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if let ::std::option::Option::Some(port_forward_message::Union::data(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(port_forward_message::Union::data(::std::vec::Vec::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::data(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        if self.has_data() {
+            match self.union.take() {
+                ::std::option::Option::Some(port_forward_message::Union::data(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::vec::Vec::new()
+        }
+    }
+
+    // bool close = 5;
+
+    pub fn get_close(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::close(v)) => v,
+            _ => false,
+        }
+    }
+
+    pub fn clear_close(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_close(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(port_forward_message::Union::close(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_close(&mut self, v: bool) {
+        self.union = ::std::option::Option::Some(port_forward_message::Union::close(v))
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ForwardOpen>(
+            "open",
+            PortForwardMessage::has_open,
+            PortForwardMessage::get_open,
+            PortForwardMessage::mut_open,
+            PortForwardMessage::set_open,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ForwardListen>(
+            "listen",
+            PortForwardMessage::has_listen,
+            PortForwardMessage::get_listen,
+            PortForwardMessage::mut_listen,
+            PortForwardMessage::set_listen,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ForwardResult>(
+            "result",
+            PortForwardMessage::has_result,
+            PortForwardMessage::get_result,
+            PortForwardMessage::mut_result,
+            PortForwardMessage::set_result,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_deref_has_get_set_simpler_accessor::<_, _>(
+            "data",
+            PortForwardMessage::has_data,
+            PortForwardMessage::get_data,
+            PortForwardMessage::set_data,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+            "close",
+            PortForwardMessage::has_close,
+            PortForwardMessage::get_close,
+            PortForwardMessage::set_close,
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<PortForwardMessage>(
+            "PortForwardMessage",
+            36,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for PortForwardMessage {
+    fn is_initialized(&self) -> bool {
+        if let Some(port_forward_message::Union::open(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(port_forward_message::Union::listen(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(port_forward_message::Union::result(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(port_forward_message::Union::open(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(port_forward_message::Union::listen(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(port_forward_message::Union::result(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(port_forward_message::Union::data(is.read_bytes()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(port_forward_message::Union::close(is.read_bool()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &port_forward_message::Union::open(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &port_forward_message::Union::listen(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &port_forward_message::Union::result(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &port_forward_message::Union::data(ref v) => {
+                    my_size += ::protobuf::rt::bytes_size(4, &v);
+                },
+                &port_forward_message::Union::close(v) => {
+                    my_size += 2;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &port_forward_message::Union::open(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &port_forward_message::Union::listen(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+                &port_forward_message::Union::result(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+                },
+                &port_forward_message::Union::data(ref v) => {
+                    os.write_bytes(4, v)?;
+                },
+                &port_forward_message::Union::close(v) => {
+                    os.write_bool(5, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> PortForwardMessage {
+        PortForwardMessage::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 36)
+    }
+
+    fn default_instance() -> &'static PortForwardMessage {
+        static instance: PortForwardMessage = PortForwardMessage {
+            union: ::std::option::Option::None,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for PortForwardMessage {
+    fn clear(&mut self) {
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PortForwardMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PortForwardMessage {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `PortForwardMessage`
+pub mod port_forward_message {
+
+    #[derive(Clone,PartialEq,Debug)]
+    pub enum Union {
+        open(super::ForwardOpen),
+        listen(super::ForwardListen),
+        result(super::ForwardResult),
+        data(::std::vec::Vec<u8>),
+        close(bool),
+    }
+
+    impl ::protobuf::Oneof for Union {
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AudioFormat {
+    // message fields
+    pub sample_rate: u32,
+    pub channels: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AudioFormat {
+    fn default() -> &'a AudioFormat {
+        <AudioFormat as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AudioFormat {
+    pub fn new() -> AudioFormat {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sample_rate",
+            |m: &AudioFormat| { &m.sample_rate },
+            |m: &mut AudioFormat| { &mut m.sample_rate },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "channels",
+            |m: &AudioFormat| { &m.channels },
+            |m: &mut AudioFormat| { &mut m.channels },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AudioFormat>(
+            "AudioFormat",
+            37,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for AudioFormat {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.sample_rate = is.read_uint32()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.channels = is.read_uint32()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.sample_rate != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.sample_rate, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.channels != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.channels, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.sample_rate != 0 {
+            os.write_uint32(1, self.sample_rate)?;
+        }
+        if self.channels != 0 {
+            os.write_uint32(2, self.channels)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> AudioFormat {
+        AudioFormat::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 37)
+    }
+
+    fn default_instance() -> &'static AudioFormat {
+        static instance: AudioFormat = AudioFormat {
+            sample_rate: 0,
+            channels: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for AudioFormat {
+    fn clear(&mut self) {
+        self.sample_rate = 0;
+        self.channels = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AudioFormat {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AudioFormat {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AudioFrame {
+    // message fields
+    pub data: ::std::vec::Vec<u8>,
+    pub timestamp: u64,
+    pub seq: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AudioFrame {
+    fn default() -> &'a AudioFrame {
+        <AudioFrame as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AudioFrame {
+    pub fn new() -> AudioFrame {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "data",
+            |m: &AudioFrame| { &m.data },
+            |m: &mut AudioFrame| { &mut m.data },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timestamp",
+            |m: &AudioFrame| { &m.timestamp },
+            |m: &mut AudioFrame| { &mut m.timestamp },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "seq",
+            |m: &AudioFrame| { &m.seq },
+            |m: &mut AudioFrame| { &mut m.seq },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AudioFrame>(
+            "AudioFrame",
+            38,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for AudioFrame {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = is.read_bytes()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.timestamp = is.read_uint64()?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.seq = is.read_uint32()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.data);
+        }
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.seq != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.seq, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.data.is_empty() {
+            os.write_bytes(1, &self.data)?;
+        }
+        if self.timestamp != 0 {
+            os.write_uint64(2, self.timestamp)?;
+        }
+        if self.seq != 0 {
+            os.write_uint32(3, self.seq)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> AudioFrame {
+        AudioFrame::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 38)
+    }
+
+    fn default_instance() -> &'static AudioFrame {
+        static instance: AudioFrame = AudioFrame {
+            data: ::std::vec::Vec::new(),
+            timestamp: 0,
+            seq: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for AudioFrame {
+    fn clear(&mut self) {
+        self.data.clear();
+        self.timestamp = 0;
+        self.seq = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AudioFrame {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AudioFrame {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AudioMessage {
+    // message oneof groups
+    pub union: ::std::option::Option<audio_message::Union>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AudioMessage {
+    fn default() -> &'a AudioMessage {
+        <AudioMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AudioMessage {
+    pub fn new() -> AudioMessage {
+        ::std::default::Default::default()
+    }
+
+    // .AudioFormat format = 1;
+
+    pub fn get_format(&self) -> &AudioFormat {
+        match self.union {
+            ::std::option::Option::Some(audio_message::Union::format(ref v)) => v,
+            _ => <AudioFormat as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_format(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_format(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(audio_message::Union::format(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_format(&mut self, v: AudioFormat) {
+        self.union = ::std::option::Option::Some(audio_message::Union::format(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_format(&mut self) -> &mut AudioFormat {
+        if let ::std::option::Option::Some(audio_message::Union::format(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(audio_message::Union::format(AudioFormat::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(audio_message::Union::format(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_format(&mut self) -> AudioFormat {
+        if self.has_format() {
+            match self.union.take() {
+                ::std::option::Option::Some(audio_message::Union::format(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AudioFormat::new()
+        }
+    }
+
+    // .AudioFrame frame = 2;
+
+    pub fn get_frame(&self) -> &AudioFrame {
+        match self.union {
+            ::std::option::Option::Some(audio_message::Union::frame(ref v)) => v,
+            _ => <AudioFrame as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_frame(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_frame(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(audio_message::Union::frame(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_frame(&mut self, v: AudioFrame) {
+        self.union = ::std::option::Option::Some(audio_message::Union::frame(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_frame(&mut self) -> &mut AudioFrame {
+        if let ::std::option::Option::Some(audio_message::Union::frame(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(audio_message::Union::frame(AudioFrame::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(audio_message::Union::frame(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_frame(&mut self) -> AudioFrame {
+        if self.has_frame() {
+            match self.union.take() {
+                ::std::option::Option::Some(audio_message::Union::frame(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            AudioFrame::new()
+        }
+    }
+
+    // bool enabled = 3;
+
+    pub fn get_enabled(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(audio_message::Union::enabled(v)) => v,
+            _ => false,
+        }
+    }
+
+    pub fn clear_enabled(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_enabled(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(audio_message::Union::enabled(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enabled(&mut self, v: bool) {
+        self.union = ::std::option::Option::Some(audio_message::Union::enabled(v))
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AudioFormat>(
+            "format",
+            AudioMessage::has_format,
+            AudioMessage::get_format,
+            AudioMessage::mut_format,
+            AudioMessage::set_format,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, AudioFrame>(
+            "frame",
+            AudioMessage::has_frame,
+            AudioMessage::get_frame,
+            AudioMessage::mut_frame,
+            AudioMessage::set_frame,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_copy_has_get_set_simpler_accessors::<_, _>(
+            "enabled",
+            AudioMessage::has_enabled,
+            AudioMessage::get_enabled,
+            AudioMessage::set_enabled,
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<AudioMessage>(
+            "AudioMessage",
+            39,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for AudioMessage {
+    fn is_initialized(&self) -> bool {
+        if let Some(audio_message::Union::format(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(audio_message::Union::frame(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(audio_message::Union::format(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(audio_message::Union::frame(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(audio_message::Union::enabled(is.read_bool()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &audio_message::Union::format(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &audio_message::Union::frame(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &audio_message::Union::enabled(v) => {
+                    my_size += 2;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &audio_message::Union::format(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &audio_message::Union::frame(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+                &audio_message::Union::enabled(v) => {
+                    os.write_bool(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> AudioMessage {
+        AudioMessage::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 39)
+    }
+
+    fn default_instance() -> &'static AudioMessage {
+        static instance: AudioMessage = AudioMessage {
+            union: ::std::option::Option::None,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for AudioMessage {
+    fn clear(&mut self) {
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AudioMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AudioMessage {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `AudioMessage`
+pub mod audio_message {
+
+    #[derive(Clone,PartialEq,Debug)]
+    pub enum Union {
+        format(super::AudioFormat),
+        frame(super::AudioFrame),
+        enabled(bool),
+    }
+
+    impl ::protobuf::Oneof for Union {
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ChatText {
+    // message fields
+    pub id: u64,
+    pub timestamp: u64,
+    pub sender: ::std::string::String,
+    pub text: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ChatText {
+    fn default() -> &'a ChatText {
+        <ChatText as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ChatText {
+    pub fn new() -> ChatText {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &ChatText| { &m.id },
+            |m: &mut ChatText| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timestamp",
+            |m: &ChatText| { &m.timestamp },
+            |m: &mut ChatText| { &mut m.timestamp },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "sender",
+            |m: &ChatText| { &m.sender },
+            |m: &mut ChatText| { &mut m.sender },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "text",
+            |m: &ChatText| { &m.text },
+            |m: &mut ChatText| { &mut m.text },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ChatText>(
+            "ChatText",
+            40,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for ChatText {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.id = is.read_uint64()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.timestamp = is.read_uint64()?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.sender = is.read_string()?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.text = is.read_string()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.sender.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.sender);
+        }
+        if !self.text.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.text);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        if self.timestamp != 0 {
+            os.write_uint64(2, self.timestamp)?;
+        }
+        if !self.sender.is_empty() {
+            os.write_string(3, &self.sender)?;
+        }
+        if !self.text.is_empty() {
+            os.write_string(4, &self.text)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> ChatText {
+        ChatText::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 40)
+    }
+
+    fn default_instance() -> &'static ChatText {
+        static instance: ChatText = ChatText {
+            id: 0,
+            timestamp: 0,
+            sender: ::std::string::String::new(),
+            text: ::std::string::String::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for ChatText {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.timestamp = 0;
+        self.sender.clear();
+        self.text.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ChatText {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ChatText {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ChatAck {
+    // message fields
+    pub id: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ChatAck {
+    fn default() -> &'a ChatAck {
+        <ChatAck as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ChatAck {
+    pub fn new() -> ChatAck {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &ChatAck| { &m.id },
+            |m: &mut ChatAck| { &mut m.id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ChatAck>(
+            "ChatAck",
+            41,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for ChatAck {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.id = is.read_uint64()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_uint64(1, self.id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> ChatAck {
+        ChatAck::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 41)
+    }
+
+    fn default_instance() -> &'static ChatAck {
+        static instance: ChatAck = ChatAck {
+            id: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for ChatAck {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ChatAck {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ChatAck {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ChatMessage {
+    // message oneof groups
+    pub union: ::std::option::Option<chat_message::Union>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ChatMessage {
+    fn default() -> &'a ChatMessage {
+        <ChatMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ChatMessage {
+    pub fn new() -> ChatMessage {
+        ::std::default::Default::default()
+    }
+
+    // .ChatText text = 1;
+
+    pub fn get_text(&self) -> &ChatText {
+        match self.union {
+            ::std::option::Option::Some(chat_message::Union::text(ref v)) => v,
+            _ => <ChatText as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_text(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_text(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(chat_message::Union::text(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: ChatText) {
+        self.union = ::std::option::Option::Some(chat_message::Union::text(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_text(&mut self) -> &mut ChatText {
+        if let ::std::option::Option::Some(chat_message::Union::text(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(chat_message::Union::text(ChatText::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(chat_message::Union::text(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> ChatText {
+        if self.has_text() {
+            match self.union.take() {
+                ::std::option::Option::Some(chat_message::Union::text(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ChatText::new()
+        }
+    }
+
+    // .ChatAck ack = 2;
+
+    pub fn get_ack(&self) -> &ChatAck {
+        match self.union {
+            ::std::option::Option::Some(chat_message::Union::ack(ref v)) => v,
+            _ => <ChatAck as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_ack(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_ack(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(chat_message::Union::ack(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ack(&mut self, v: ChatAck) {
+        self.union = ::std::option::Option::Some(chat_message::Union::ack(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ack(&mut self) -> &mut ChatAck {
+        if let ::std::option::Option::Some(chat_message::Union::ack(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(chat_message::Union::ack(ChatAck::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(chat_message::Union::ack(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_ack(&mut self) -> ChatAck {
+        if self.has_ack() {
+            match self.union.take() {
+                ::std::option::Option::Some(chat_message::Union::ack(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ChatAck::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ChatText>(
+            "text",
+            ChatMessage::has_text,
+            ChatMessage::get_text,
+            ChatMessage::mut_text,
+            ChatMessage::set_text,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, ChatAck>(
+            "ack",
+            ChatMessage::has_ack,
+            ChatMessage::get_ack,
+            ChatMessage::mut_ack,
+            ChatMessage::set_ack,
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<ChatMessage>(
+            "ChatMessage",
+            42,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for ChatMessage {
+    fn is_initialized(&self) -> bool {
+        if let Some(chat_message::Union::text(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(chat_message::Union::ack(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(chat_message::Union::text(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(chat_message::Union::ack(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &chat_message::Union::text(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &chat_message::Union::ack(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &chat_message::Union::text(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &chat_message::Union::ack(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> ChatMessage {
+        ChatMessage::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 42)
+    }
+
+    fn default_instance() -> &'static ChatMessage {
+        static instance: ChatMessage = ChatMessage {
+            union: ::std::option::Option::None,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for ChatMessage {
+    fn clear(&mut self) {
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ChatMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ChatMessage {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `ChatMessage`
+pub mod chat_message {
+
+    #[derive(Clone,PartialEq,Debug)]
+    pub enum Union {
+        text(super::ChatText),
+        ack(super::ChatAck),
+    }
+
+    impl ::protobuf::Oneof for Union {
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DisplayInfo {
+    // message fields
+    pub id: i32,
+    pub name: ::std::string::String,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub primary: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DisplayInfo {
+    fn default() -> &'a DisplayInfo {
+        <DisplayInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DisplayInfo {
+    pub fn new() -> DisplayInfo {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &DisplayInfo| { &m.id },
+            |m: &mut DisplayInfo| { &mut m.id },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "name",
+            |m: &DisplayInfo| { &m.name },
+            |m: &mut DisplayInfo| { &mut m.name },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "x",
+            |m: &DisplayInfo| { &m.x },
+            |m: &mut DisplayInfo| { &mut m.x },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "y",
+            |m: &DisplayInfo| { &m.y },
+            |m: &mut DisplayInfo| { &mut m.y },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "width",
+            |m: &DisplayInfo| { &m.width },
+            |m: &mut DisplayInfo| { &mut m.width },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &DisplayInfo| { &m.height },
+            |m: &mut DisplayInfo| { &mut m.height },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "primary",
+            |m: &DisplayInfo| { &m.primary },
+            |m: &mut DisplayInfo| { &mut m.primary },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DisplayInfo>(
+            "DisplayInfo",
+            43,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for DisplayInfo {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.id = is.read_int32()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.name = is.read_string()?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.x = is.read_int32()?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.y = is.read_int32()?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.width = is.read_uint32()?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.height = is.read_uint32()?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.primary = is.read_bool()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        if self.x != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.x, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.y != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.y, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.width != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.width, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.height, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.primary != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_int32(1, self.id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        if self.x != 0 {
+            os.write_int32(3, self.x)?;
+        }
+        if self.y != 0 {
+            os.write_int32(4, self.y)?;
+        }
+        if self.width != 0 {
+            os.write_uint32(5, self.width)?;
+        }
+        if self.height != 0 {
+            os.write_uint32(6, self.height)?;
+        }
+        if self.primary != false {
+            os.write_bool(7, self.primary)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> DisplayInfo {
+        DisplayInfo::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 43)
+    }
+
+    fn default_instance() -> &'static DisplayInfo {
+        static instance: DisplayInfo = DisplayInfo {
+            id: 0,
+            name: ::std::string::String::new(),
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+            primary: false,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for DisplayInfo {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.name.clear();
+        self.x = 0;
+        self.y = 0;
+        self.width = 0;
+        self.height = 0;
+        self.primary = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DisplayInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DisplayInfo {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DisplayList {
+    // message fields
+    pub displays: ::std::vec::Vec<DisplayInfo>,
+    pub current: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DisplayList {
+    fn default() -> &'a DisplayList {
+        <DisplayList as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DisplayList {
+    pub fn new() -> DisplayList {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "displays",
+            |m: &DisplayList| { &m.displays },
+            |m: &mut DisplayList| { &mut m.displays },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "current",
+            |m: &DisplayList| { &m.current },
+            |m: &mut DisplayList| { &mut m.current },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<DisplayList>(
+            "DisplayList",
+            44,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for DisplayList {
+    fn is_initialized(&self) -> bool {
+        for v in &self.displays {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into_vec(wire_type, is, &mut self.displays)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.current = is.read_int32()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.displays {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.current != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.current, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.displays {
+            ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+        };
+        if self.current != 0 {
+            os.write_int32(2, self.current)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> DisplayList {
+        DisplayList::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 44)
+    }
+
+    fn default_instance() -> &'static DisplayList {
+        static instance: DisplayList = DisplayList {
+            displays: ::std::vec::Vec::new(),
+            current: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for DisplayList {
+    fn clear(&mut self) {
+        self.displays.clear();
+        self.current = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DisplayList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DisplayList {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SwitchDisplay {
+    // message fields
+    pub id: i32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SwitchDisplay {
+    fn default() -> &'a SwitchDisplay {
+        <SwitchDisplay as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SwitchDisplay {
+    pub fn new() -> SwitchDisplay {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "id",
+            |m: &SwitchDisplay| { &m.id },
+            |m: &mut SwitchDisplay| { &mut m.id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SwitchDisplay>(
+            "SwitchDisplay",
+            45,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for SwitchDisplay {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.id = is.read_int32()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.id != 0 {
+            os.write_int32(1, self.id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> SwitchDisplay {
+        SwitchDisplay::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 45)
+    }
+
+    fn default_instance() -> &'static SwitchDisplay {
+        static instance: SwitchDisplay = SwitchDisplay {
+            id: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for SwitchDisplay {
+    fn clear(&mut self) {
+        self.id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SwitchDisplay {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SwitchDisplay {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetResolution {
+    // message fields
+    pub width: u32,
+    pub height: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetResolution {
+    fn default() -> &'a SetResolution {
+        <SetResolution as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetResolution {
+    pub fn new() -> SetResolution {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "width",
+            |m: &SetResolution| { &m.width },
+            |m: &mut SetResolution| { &mut m.width },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "height",
+            |m: &SetResolution| { &m.height },
+            |m: &mut SetResolution| { &mut m.height },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SetResolution>(
+            "SetResolution",
+            46,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for SetResolution {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.width = is.read_uint32()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.height = is.read_uint32()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.width != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.width, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.height, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.width != 0 {
+            os.write_uint32(1, self.width)?;
+        }
+        if self.height != 0 {
+            os.write_uint32(2, self.height)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> SetResolution {
+        SetResolution::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 46)
+    }
+
+    fn default_instance() -> &'static SetResolution {
+        static instance: SetResolution = SetResolution {
+            width: 0,
+            height: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for SetResolution {
+    fn clear(&mut self) {
+        self.width = 0;
+        self.height = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetResolution {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetResolution {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VideoFrame {
+    // message fields
+    pub data: ::std::vec::Vec<u8>,
+    pub timestamp: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VideoFrame {
+    fn default() -> &'a VideoFrame {
+        <VideoFrame as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VideoFrame {
+    pub fn new() -> VideoFrame {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "data",
+            |m: &VideoFrame| { &m.data },
+            |m: &mut VideoFrame| { &mut m.data },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "timestamp",
+            |m: &VideoFrame| { &m.timestamp },
+            |m: &mut VideoFrame| { &mut m.timestamp },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<VideoFrame>(
+            "VideoFrame",
+            47,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for VideoFrame {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = is.read_bytes()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.timestamp = is.read_uint64()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.data);
+        }
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.data.is_empty() {
+            os.write_bytes(1, &self.data)?;
+        }
+        if self.timestamp != 0 {
+            os.write_uint64(2, self.timestamp)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> VideoFrame {
+        VideoFrame::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 47)
+    }
+
+    fn default_instance() -> &'static VideoFrame {
+        static instance: VideoFrame = VideoFrame {
+            data: ::std::vec::Vec::new(),
+            timestamp: 0,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for VideoFrame {
+    fn clear(&mut self) {
+        self.data.clear();
+        self.timestamp = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VideoFrame {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VideoFrame {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VideoMessage {
+    // message oneof groups
+    pub union: ::std::option::Option<video_message::Union>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VideoMessage {
+    fn default() -> &'a VideoMessage {
+        <VideoMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VideoMessage {
+    pub fn new() -> VideoMessage {
+        ::std::default::Default::default()
+    }
+
+    // .DisplayList display_list = 1;
+
+    pub fn get_display_list(&self) -> &DisplayList {
+        match self.union {
+            ::std::option::Option::Some(video_message::Union::display_list(ref v)) => v,
+            _ => <DisplayList as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_display_list(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_display_list(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(video_message::Union::display_list(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_display_list(&mut self, v: DisplayList) {
+        self.union = ::std::option::Option::Some(video_message::Union::display_list(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_display_list(&mut self) -> &mut DisplayList {
+        if let ::std::option::Option::Some(video_message::Union::display_list(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(video_message::Union::display_list(DisplayList::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(video_message::Union::display_list(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_display_list(&mut self) -> DisplayList {
+        if self.has_display_list() {
+            match self.union.take() {
+                ::std::option::Option::Some(video_message::Union::display_list(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            DisplayList::new()
+        }
+    }
+
+    // .VideoFrame video_frame = 2;
+
+    pub fn get_video_frame(&self) -> &VideoFrame {
+        match self.union {
+            ::std::option::Option::Some(video_message::Union::video_frame(ref v)) => v,
+            _ => <VideoFrame as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_video_frame(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_video_frame(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(video_message::Union::video_frame(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_video_frame(&mut self, v: VideoFrame) {
+        self.union = ::std::option::Option::Some(video_message::Union::video_frame(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_video_frame(&mut self) -> &mut VideoFrame {
+        if let ::std::option::Option::Some(video_message::Union::video_frame(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(video_message::Union::video_frame(VideoFrame::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(video_message::Union::video_frame(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_video_frame(&mut self) -> VideoFrame {
+        if self.has_video_frame() {
+            match self.union.take() {
+                ::std::option::Option::Some(video_message::Union::video_frame(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            VideoFrame::new()
+        }
+    }
+
+    // .SwitchDisplay switch_display = 3;
+
+    pub fn get_switch_display(&self) -> &SwitchDisplay {
+        match self.union {
+            ::std::option::Option::Some(video_message::Union::switch_display(ref v)) => v,
+            _ => <SwitchDisplay as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_switch_display(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_switch_display(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(video_message::Union::switch_display(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_switch_display(&mut self, v: SwitchDisplay) {
+        self.union = ::std::option::Option::Some(video_message::Union::switch_display(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_switch_display(&mut self) -> &mut SwitchDisplay {
+        if let ::std::option::Option::Some(video_message::Union::switch_display(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(video_message::Union::switch_display(SwitchDisplay::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(video_message::Union::switch_display(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_switch_display(&mut self) -> SwitchDisplay {
+        if self.has_switch_display() {
+            match self.union.take() {
+                ::std::option::Option::Some(video_message::Union::switch_display(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SwitchDisplay::new()
+        }
+    }
+
+    // .SetResolution set_resolution = 4;
+
+    pub fn get_set_resolution(&self) -> &SetResolution {
+        match self.union {
+            ::std::option::Option::Some(video_message::Union::set_resolution(ref v)) => v,
+            _ => <SetResolution as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_set_resolution(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_set_resolution(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(video_message::Union::set_resolution(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_set_resolution(&mut self, v: SetResolution) {
+        self.union = ::std::option::Option::Some(video_message::Union::set_resolution(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_set_resolution(&mut self) -> &mut SetResolution {
+        if let ::std::option::Option::Some(video_message::Union::set_resolution(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(video_message::Union::set_resolution(SetResolution::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(video_message::Union::set_resolution(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_set_resolution(&mut self) -> SetResolution {
+        if self.has_set_resolution() {
+            match self.union.take() {
+                ::std::option::Option::Some(video_message::Union::set_resolution(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SetResolution::new()
+        }
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, DisplayList>(
+            "display_list",
+            VideoMessage::has_display_list,
+            VideoMessage::get_display_list,
+            VideoMessage::mut_display_list,
+            VideoMessage::set_display_list,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, VideoFrame>(
+            "video_frame",
+            VideoMessage::has_video_frame,
+            VideoMessage::get_video_frame,
+            VideoMessage::mut_video_frame,
+            VideoMessage::set_video_frame,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SwitchDisplay>(
+            "switch_display",
+            VideoMessage::has_switch_display,
+            VideoMessage::get_switch_display,
+            VideoMessage::mut_switch_display,
+            VideoMessage::set_switch_display,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SetResolution>(
+            "set_resolution",
+            VideoMessage::has_set_resolution,
+            VideoMessage::get_set_resolution,
+            VideoMessage::mut_set_resolution,
+            VideoMessage::set_set_resolution,
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<VideoMessage>(
+            "VideoMessage",
+            48,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for VideoMessage {
+    fn is_initialized(&self) -> bool {
+        if let Some(video_message::Union::display_list(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(video_message::Union::video_frame(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(video_message::Union::switch_display(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(video_message::Union::set_resolution(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(video_message::Union::display_list(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(video_message::Union::video_frame(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(video_message::Union::switch_display(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(video_message::Union::set_resolution(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &video_message::Union::display_list(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &video_message::Union::video_frame(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &video_message::Union::switch_display(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &video_message::Union::set_resolution(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.union {
+            match v {
+                &video_message::Union::display_list(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(1, v, os)?;
+                },
+                &video_message::Union::video_frame(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(2, v, os)?;
+                },
+                &video_message::Union::switch_display(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(3, v, os)?;
+                },
+                &video_message::Union::set_resolution(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(4, v, os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> VideoMessage {
+        VideoMessage::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 48)
+    }
+
+    fn default_instance() -> &'static VideoMessage {
+        static instance: VideoMessage = VideoMessage {
+            union: ::std::option::Option::None,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for VideoMessage {
+    fn clear(&mut self) {
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VideoMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VideoMessage {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `VideoMessage`
+pub mod video_message {
+
+    #[derive(Clone,PartialEq,Debug)]
+    pub enum Union {
+        display_list(super::DisplayList),
+        video_frame(super::VideoFrame),
+        switch_display(super::SwitchDisplay),
+        set_resolution(super::SetResolution),
+    }
+
+    impl ::protobuf::Oneof for Union {
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct InputStatus {
+    // message fields
+    pub code: ::protobuf::ProtobufEnumOrUnknown<input_status::Code>,
+    pub backend: ::std::string::String,
+    pub message: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a InputStatus {
+    fn default() -> &'a InputStatus {
+        <InputStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl InputStatus {
+    pub fn new() -> InputStatus {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "code",
+            |m: &InputStatus| { &m.code },
+            |m: &mut InputStatus| { &mut m.code },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "backend",
+            |m: &InputStatus| { &m.backend },
+            |m: &mut InputStatus| { &mut m.backend },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "message",
+            |m: &InputStatus| { &m.message },
+            |m: &mut InputStatus| { &mut m.message },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<InputStatus>(
+            "InputStatus",
+            49,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for InputStatus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.code = is.read_enum_or_unknown()?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.backend = is.read_string()?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = is.read_string()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.code != ::protobuf::ProtobufEnumOrUnknown::new(input_status::Code::Ok) {
+            my_size += ::protobuf::rt::enum_or_unknown_size(1, self.code);
+        }
+        if !self.backend.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.backend);
+        }
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.message);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.code != ::protobuf::ProtobufEnumOrUnknown::new(input_status::Code::Ok) {
+            os.write_enum(1, ::protobuf::ProtobufEnumOrUnknown::value(&self.code))?;
+        }
+        if !self.backend.is_empty() {
+            os.write_string(2, &self.backend)?;
+        }
+        if !self.message.is_empty() {
+            os.write_string(3, &self.message)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> InputStatus {
+        InputStatus::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 49)
+    }
+
+    fn default_instance() -> &'static InputStatus {
+        static instance: InputStatus = InputStatus {
+            code: ::protobuf::ProtobufEnumOrUnknown::from_i32(0),
+            backend: ::std::string::String::new(),
+            message: ::std::string::String::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for InputStatus {
+    fn clear(&mut self) {
+        self.code = ::protobuf::ProtobufEnumOrUnknown::new(input_status::Code::Ok);
+        self.backend.clear();
+        self.message.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for InputStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for InputStatus {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+/// Nested message and enums of message `InputStatus`
+pub mod input_status {
+    #[derive(Clone,Copy,PartialEq,Eq,Debug,Hash)]
+    pub enum Code {
+        Ok = 0,
+        Unsupported = 1,
+        PermissionDenied = 2,
+        Error = 3,
+    }
+
+    impl ::protobuf::ProtobufEnum for Code {
+        fn value(&self) -> i32 {
+            *self as i32
+        }
+
+        fn from_i32(value: i32) -> ::std::option::Option<Code> {
+            match value {
+                0 => ::std::option::Option::Some(Code::Ok),
+                1 => ::std::option::Option::Some(Code::Unsupported),
+                2 => ::std::option::Option::Some(Code::PermissionDenied),
+                3 => ::std::option::Option::Some(Code::Error),
+                _ => ::std::option::Option::None
+            }
+        }
+
+        fn values() -> &'static [Self] {
+            static values: &'static [Code] = &[
+                Code::Ok,
+                Code::Unsupported,
+                Code::PermissionDenied,
+                Code::Error,
+            ];
+            values
+        }
+
+        fn enum_descriptor_static() -> ::protobuf::reflect::EnumDescriptor {
+            ::protobuf::reflect::EnumDescriptor::new_generated_2(super::file_descriptor(), 9)
+        }
+    }
+
+    impl ::std::default::Default for Code {
+        fn default() -> Self {
+            Code::Ok
+        }
+    }
+
+    impl ::protobuf::reflect::ProtobufValue for Code {
+        type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeEnum<Self>;
+    }
+
+    impl Code {
         pub(in super) fn generated_enum_descriptor_data() -> ::protobuf::reflect::GeneratedEnumDescriptorData {
-            ::protobuf::reflect::GeneratedEnumDescriptorData::new_2::<Active>("MouseEvent.Active", 5)
+            ::protobuf::reflect::GeneratedEnumDescriptorData::new_2::<Code>("InputStatus.Code", 9)
         }
     }
 }
@@ -1915,6 +11488,13 @@ pub enum ChannelType {
     Video = 1,
     KeyEvent = 2,
     MouseEvent = 3,
+    Clipboard = 4,
+    FileTransfer = 5,
+    FileBrowser = 6,
+    Terminal = 7,
+    PortForward = 8,
+    Audio = 9,
+    Chat = 10,
 }
 
 impl ::protobuf::ProtobufEnum for ChannelType {
@@ -1928,6 +11508,13 @@ impl ::protobuf::ProtobufEnum for ChannelType {
             1 => ::std::option::Option::Some(ChannelType::Video),
             2 => ::std::option::Option::Some(ChannelType::KeyEvent),
             3 => ::std::option::Option::Some(ChannelType::MouseEvent),
+            4 => ::std::option::Option::Some(ChannelType::Clipboard),
+            5 => ::std::option::Option::Some(ChannelType::FileTransfer),
+            6 => ::std::option::Option::Some(ChannelType::FileBrowser),
+            7 => ::std::option::Option::Some(ChannelType::Terminal),
+            8 => ::std::option::Option::Some(ChannelType::PortForward),
+            9 => ::std::option::Option::Some(ChannelType::Audio),
+            10 => ::std::option::Option::Some(ChannelType::Chat),
             _ => ::std::option::Option::None
         }
     }
@@ -1938,6 +11525,13 @@ impl ::protobuf::ProtobufEnum for ChannelType {
             ChannelType::Video,
             ChannelType::KeyEvent,
             ChannelType::MouseEvent,
+            ChannelType::Clipboard,
+            ChannelType::FileTransfer,
+            ChannelType::FileBrowser,
+            ChannelType::Terminal,
+            ChannelType::PortForward,
+            ChannelType::Audio,
+            ChannelType::Chat,
         ];
         values
     }
@@ -2020,39 +11614,182 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     IdB\0\x12\x16\n\x05rand1\x18\x03\x20\x01(\x04R\x05rand1B\0\x12\x16\n\x05\
     rand2\x18\x04\x20\x01(\x04R\x05rand2B\0\x12\x12\n\x03key\x18\x05\x20\x01\
     (\x0cR\x03keyB\0\x12\x16\n\x05nonce\x18\x06\x20\x01(\x0cR\x05nonceB\0:\0\
-    \"U\n\x0bServerHello\x12\x16\n\x05rand1\x18\x01\x20\x01(\x04R\x05rand1B\
-    \0\x12\x16\n\x05rand2\x18\x02\x20\x01(\x04R\x05rand2B\0\x12\x14\n\x04has\
-    h\x18\x03\x20\x01(\x0cR\x04hashB\0:\0\"D\n\x0cLoginRequest\x12\x1c\n\x08\
-    password\x18\x01\x20\x01(\x0cR\x08passwordB\0\x12\x14\n\x04hash\x18\x02\
-    \x20\x01(\x0cR\x04hashB\0:\0\"\x8f\x01\n\rLoginResponse\x12)\n\x04code\
+    \"\x85\x01\n\x0bServerHello\x12\x16\n\x05rand1\x18\x01\x20\x01(\x04R\x05\
+    rand1B\0\x12\x16\n\x05rand2\x18\x02\x20\x01(\x04R\x05rand2B\0\x12\x14\n\
+    \x04hash\x18\x03\x20\x01(\x0cR\x04hashB\0\x12\x14\n\x04salt\x18\x04\x20\
+    \x01(\x0cR\x04saltB\0\x12\x18\n\x06rounds\x18\x05\x20\x01(\rR\x06roundsB\
+    \0:\0\"p\n\x0cLoginRequest\x12\x1c\n\x08password\x18\x01\x20\x01(\x0cR\
+    \x08passwordB\0\x12\x14\n\x04hash\x18\x02\x20\x01(\x0cR\x04hashB\0\x12\
+    \x12\n\x03otp\x18\x03\x20\x01(\tR\x03otpB\0\x12\x16\n\x05proof\x18\x04\
+    \x20\x01(\x0cR\x05proofB\0:\0\"\x80\x02\n\rLoginResponse\x12)\n\x04code\
     \x18\x01\x20\x01(\x0e2\x13.LoginResponse.CodeR\x04codeB\0\x12\x16\n\x05e\
-    rror\x18\x02\x20\x01(\tR\x05errorB\0\"9\n\x04Code\x12\x0e\n\x08NotMatch\
-    \x10\0\x1a\0\x12\r\n\x07Success\x10\x01\x1a\0\x12\x10\n\nFrequently\x10\
-    \x02\x1a\0\x1a\0:\0\"\xf9\x01\n\rRemoteMessage\x123\n\x0cclient_hello\
-    \x18\x01\x20\x01(\x0b2\x0c.ClientHelloH\0R\x0bclientHelloB\0\x123\n\x0cs\
-    erver_hello\x18\x02\x20\x01(\x0b2\x0c.ServerHelloH\0R\x0bserverHelloB\0\
-    \x126\n\rlogin_request\x18\x03\x20\x01(\x0b2\r.LoginRequestH\0R\x0clogin\
-    RequestB\0\x129\n\x0elogin_response\x18\x04\x20\x01(\x0b2\x0e.LoginRespo\
-    nseH\0R\rloginResponseB\0B\t\n\x05union\x12\0:\0\"\xce\x01\n\x0cChannelM\
-    over\x12\x10\n\x02id\x18\x01\x20\x01(\rR\x02idB\0\x12+\n\x07control\x18\
-    \x02\x20\x01(\x0e2\x0f.ChannelControlR\x07controlB\0\x121\n\x0cchannel_t\
-    ype\x18\x03\x20\x01(\x0e2\x0c.ChannelTypeR\x0bchannelTypeB\0\x124\n\rcha\
-    nnel_power\x18\x04\x20\x01(\x0e2\r.ChannelPowerR\x0cchannelPowerB\0\x12\
-    \x14\n\x04data\x18\x05\x20\x01(\x0cR\x04dataB\0:\0\"{\n\x08KeyEvent\x12\
+    rror\x18\x02\x20\x01(\tR\x05errorB\0\x12\"\n\x0bpermissions\x18\x03\x20\
+    \x01(\rR\x0bpermissionsB\0\x12#\n\x0clockout_secs\x18\x04\x20\x01(\rR\
+    \x0blockoutSecsB\0\"a\n\x04Code\x12\x0e\n\x08NotMatch\x10\0\x1a\0\x12\r\
+    \n\x07Success\x10\x01\x1a\0\x12\x10\n\nFrequently\x10\x02\x1a\0\x12\x0e\
+    \n\x08Rejected\x10\x03\x1a\0\x12\x16\n\x10NeedSecondFactor\x10\x04\x1a\0\
+    \x1a\0:\0\"\xf9\x01\n\rRemoteMessage\x123\n\x0cclient_hello\x18\x01\x20\
+    \x01(\x0b2\x0c.ClientHelloH\0R\x0bclientHelloB\0\x123\n\x0cserver_hello\
+    \x18\x02\x20\x01(\x0b2\x0c.ServerHelloH\0R\x0bserverHelloB\0\x126\n\rlog\
+    in_request\x18\x03\x20\x01(\x0b2\r.LoginRequestH\0R\x0cloginRequestB\0\
+    \x129\n\x0elogin_response\x18\x04\x20\x01(\x0b2\x0e.LoginResponseH\0R\rl\
+    oginResponseB\0B\t\n\x05union\x12\0:\0\"\xce\x01\n\x0cChannelMover\x12\
+    \x10\n\x02id\x18\x01\x20\x01(\rR\x02idB\0\x12+\n\x07control\x18\x02\x20\
+    \x01(\x0e2\x0f.ChannelControlR\x07controlB\0\x121\n\x0cchannel_type\x18\
+    \x03\x20\x01(\x0e2\x0c.ChannelTypeR\x0bchannelTypeB\0\x124\n\rchannel_po\
+    wer\x18\x04\x20\x01(\x0e2\r.ChannelPowerR\x0cchannelPowerB\0\x12\x14\n\
+    \x04data\x18\x05\x20\x01(\x0cR\x04dataB\0:\0\"\xb1\x01\n\x08KeyEvent\x12\
     \x12\n\x03key\x18\x01\x20\x01(\rR\x03keyB\0\x12*\n\x06active\x18\x02\x20\
-    \x01(\x0e2\x10.KeyEvent.ActiveR\x06activeB\0\"-\n\x06Active\x12\x0b\n\
-    \x05Click\x10\0\x1a\0\x12\n\n\x04Down\x10\x01\x1a\0\x12\x08\n\x02Up\x10\
-    \x02\x1a\0\x1a\0:\0\"\xed\x01\n\nMouseEvent\x12\x12\n\x03key\x18\x01\x20\
-    \x01(\rR\x03keyB\0\x12,\n\x06active\x18\x02\x20\x01(\x0e2\x12.MouseEvent\
-    .ActiveR\x06activeB\0\x12\x17\n\x06move_x\x18\x03\x20\x01(\x05R\x05moveX\
-    B\0\x12\x17\n\x06move_y\x18\x04\x20\x01(\x05R\x05moveYB\0\x12\x1f\n\nscr\
-    oll_len\x18\x05\x20\x01(\x05R\tscrollLenB\0\"H\n\x06Active\x12\x0b\n\x05\
+    \x01(\x0e2\x10.KeyEvent.ActiveR\x06activeB\0\x12\x14\n\x04code\x18\x03\
+    \x20\x01(\tR\x04codeB\0\x12\x1e\n\tmodifiers\x18\x04\x20\x01(\rR\tmodifi\
+    ersB\0\"-\n\x06Active\x12\x0b\n\x05Click\x10\0\x1a\0\x12\n\n\x04Down\x10\
+    \x01\x1a\0\x12\x08\n\x02Up\x10\x02\x1a\0\x1a\0:\0\"\xa4\x01\n\tTextInput\
+    \x12\x14\n\x04text\x18\x01\x20\x01(\tR\x04textB\0\x12:\n\x0bcomposition\
+    \x18\x02\x20\x01(\x0e2\x16.TextInput.CompositionR\x0bcompositionB\0\"C\n\
+    \x0bComposition\x12\x0c\n\x06Commit\x10\0\x1a\0\x12\x0b\n\x05Start\x10\
+    \x01\x1a\0\x12\x0c\n\x06Update\x10\x02\x1a\0\x12\t\n\x03End\x10\x03\x1a\
+    \0\x1a\0:\0\"0\n\x10KeyboardSnapshot\x12\x1a\n\x07pressed\x18\x01\x20\
+    \x03(\tR\x07pressedB\0:\0\"$\n\x08KeyCombo\x12\x16\n\x05codes\x18\x01\
+    \x20\x03(\tR\x05codesB\0:\0\"\xb6\x01\n\nSpecialKey\x12&\n\x04name\x18\
+    \x01\x20\x01(\x0e2\x10.SpecialKey.NameR\x04nameB\0\"~\n\x04Name\x12\x10\
+    \n\nCtrlAltDel\x10\0\x1a\0\x12\x10\n\nLockScreen\x10\x01\x1a\0\x12\x0c\n\
+    \x06AltTab\x10\x02\x1a\0\x12\x11\n\x0bPrintScreen\x10\x03\x1a\0\x12\x11\
+    \n\x0bTaskManager\x10\x04\x1a\0\x12\x0b\n\x05AltF4\x10\x05\x1a\0\x12\x0f\
+    \n\tStartMenu\x10\x06\x1a\0\x1a\0:\0\"E\n\x08KeyMacro\x12\x14\n\x04name\
+    \x18\x01\x20\x01(\tR\x04nameB\0\x12!\n\x05steps\x18\x02\x20\x03(\x0b2\t.\
+    KeyComboR\x05stepsB\0:\0\"\x8a\x02\n\x0fKeyboardMessage\x12*\n\tkey_even\
+    t\x18\x01\x20\x01(\x0b2\t.KeyEventH\0R\x08keyEventB\0\x12-\n\ntext_input\
+    \x18\x02\x20\x01(\x0b2\n.TextInputH\0R\ttextInputB\0\x121\n\x08snapshot\
+    \x18\x03\x20\x01(\x0b2\x11.KeyboardSnapshotH\0R\x08snapshotB\0\x120\n\
+    \x0bspecial_key\x18\x04\x20\x01(\x0b2\x0b.SpecialKeyH\0R\nspecialKeyB\0\
+    \x12*\n\tkey_macro\x18\x05\x20\x01(\x0b2\t.KeyMacroH\0R\x08keyMacroB\0B\
+    \t\n\x05union\x12\0:\0\"\xa8\x03\n\nMouseEvent\x12\x12\n\x03key\x18\x01\
+    \x20\x01(\rR\x03keyB\0\x12,\n\x06active\x18\x02\x20\x01(\x0e2\x12.MouseE\
+    vent.ActiveR\x06activeB\0\x12\x17\n\x06move_x\x18\x03\x20\x01(\x05R\x05m\
+    oveXB\0\x12\x17\n\x06move_y\x18\x04\x20\x01(\x05R\x05moveYB\0\x12\x1f\n\
+    \nscroll_len\x18\x05\x20\x01(\x05R\tscrollLenB\0\x12!\n\x0bwheel_delta\
+    \x18\x06\x20\x01(\x05R\nwheelDeltaB\0\x12\x20\n\nnormalized\x18\x07\x20\
+    \x01(\x08R\nnormalizedB\0\x12\x17\n\x06norm_x\x18\x08\x20\x01(\x02R\x05n\
+    ormXB\0\x12\x17\n\x06norm_y\x18\t\x20\x01(\x02R\x05normYB\0\x12\x1f\n\nd\
+    isplay_id\x18\n\x20\x01(\x05R\tdisplayIdB\0\"k\n\x06Active\x12\x0b\n\x05\
     Click\x10\0\x1a\0\x12\n\n\x04Down\x10\x01\x1a\0\x12\x08\n\x02Up\x10\x02\
     \x1a\0\x12\n\n\x04Move\x10\x03\x1a\0\x12\r\n\x07ScrollY\x10\x04\x1a\0\
-    \x1a\0:\0*;\n\x0eChannelControl\x12\x0c\n\x06Create\x10\0\x1a\0\x12\r\n\
-    \x07Destroy\x10\x01\x1a\0\x12\n\n\x04Data\x10\x02\x1a\0\x1a\0*N\n\x0bCha\
-    nnelType\x12\x0e\n\x08NoDefine\x10\0\x1a\0\x12\x0b\n\x05Video\x10\x01\
-    \x1a\0\x12\x0e\n\x08KeyEvent\x10\x02\x1a\0\x12\x10\n\nMouseEvent\x10\x03\
+    \x12\r\n\x07ScrollX\x10\x05\x1a\0\x12\x12\n\x0cMoveRelative\x10\x06\x1a\
+    \0\x1a\0:\0\"5\n\nInputBatch\x12%\n\x06events\x18\x01\x20\x03(\x0b2\x0b.\
+    MouseEventR\x06eventsB\0:\0\"\x9d\x01\n\rClipboardData\x12\x14\n\x04text\
+    \x18\x01\x20\x01(\tR\x04textB\0\x12\x14\n\x04html\x18\x02\x20\x01(\tR\
+    \x04htmlB\0\x12\x16\n\x05image\x18\x03\x20\x01(\x0cR\x05imageB\0\x12!\n\
+    \x0bimage_width\x18\x04\x20\x01(\rR\nimageWidthB\0\x12#\n\x0cimage_heigh\
+    t\x18\x05\x20\x01(\rR\x0bimageHeightB\0:\0\"e\n\x10ClipboardMessage\x12&\
+    \n\x04data\x18\x01\x20\x01(\x0b2\x0e.ClipboardDataH\0R\x04dataB\0\x12\
+    \x1c\n\x07enabled\x18\x02\x20\x01(\x08H\0R\x07enabledB\0B\t\n\x05union\
+    \x12\0:\0\"y\n\tFileOffer\x12\x10\n\x02id\x18\x01\x20\x01(\x04R\x02idB\0\
+    \x12\x14\n\x04name\x18\x02\x20\x01(\tR\x04nameB\0\x12\x14\n\x04size\x18\
+    \x03\x20\x01(\x04R\x04sizeB\0\x12\x18\n\x06sha256\x18\x04\x20\x01(\x0cR\
+    \x06sha256B\0\x12\x12\n\x03dir\x18\x05\x20\x01(\tR\x03dirB\0:\0\"7\n\x0b\
+    FileRequest\x12\x10\n\x02id\x18\x01\x20\x01(\x04R\x02idB\0\x12\x14\n\x04\
+    path\x18\x02\x20\x01(\tR\x04pathB\0:\0\":\n\nFileAccept\x12\x10\n\x02id\
+    \x18\x01\x20\x01(\x04R\x02idB\0\x12\x18\n\x06offset\x18\x02\x20\x01(\x04\
+    R\x06offsetB\0:\0\":\n\nFileReject\x12\x10\n\x02id\x18\x01\x20\x01(\x04R\
+    \x02idB\0\x12\x18\n\x06reason\x18\x02\x20\x01(\tR\x06reasonB\0:\0\"i\n\t\
+    FileChunk\x12\x10\n\x02id\x18\x01\x20\x01(\x04R\x02idB\0\x12\x18\n\x06of\
+    fset\x18\x02\x20\x01(\x04R\x06offsetB\0\x12\x14\n\x04data\x18\x03\x20\
+    \x01(\x0cR\x04dataB\0\x12\x18\n\x06sha256\x18\x04\x20\x01(\x0cR\x06sha25\
+    6B\0:\0\"\x1e\n\x08FileDone\x12\x10\n\x02id\x18\x01\x20\x01(\x04R\x02idB\
+    \0:\0\"J\n\nFileResult\x12\x10\n\x02id\x18\x01\x20\x01(\x04R\x02idB\0\
+    \x12\x10\n\x02ok\x18\x02\x20\x01(\x08R\x02okB\0\x12\x16\n\x05error\x18\
+    \x03\x20\x01(\tR\x05errorB\0:\0\"\xb8\x02\n\x13FileTransferMessage\x12$\
+    \n\x05offer\x18\x01\x20\x01(\x0b2\n.FileOfferH\0R\x05offerB\0\x12*\n\x07\
+    request\x18\x02\x20\x01(\x0b2\x0c.FileRequestH\0R\x07requestB\0\x12'\n\
+    \x06accept\x18\x03\x20\x01(\x0b2\x0b.FileAcceptH\0R\x06acceptB\0\x12'\n\
+    \x06reject\x18\x04\x20\x01(\x0b2\x0b.FileRejectH\0R\x06rejectB\0\x12$\n\
+    \x05chunk\x18\x05\x20\x01(\x0b2\n.FileChunkH\0R\x05chunkB\0\x12!\n\x04do\
+    ne\x18\x06\x20\x01(\x0b2\t.FileDoneH\0R\x04doneB\0\x12'\n\x06result\x18\
+    \x07\x20\x01(\x0b2\x0b.FileResultH\0R\x06resultB\0B\t\n\x05union\x12\0:\
+    \0\"\xc6\x01\n\tFileEntry\x12\x14\n\x04name\x18\x01\x20\x01(\tR\x04nameB\
+    \0\x12\x14\n\x04size\x18\x02\x20\x01(\x04R\x04sizeB\0\x12\x1c\n\x08modif\
+    ied\x18\x03\x20\x01(\x04R\x08modifiedB\0\x120\n\nentry_type\x18\x04\x20\
+    \x01(\x0e2\x0f.FileEntry.TypeR\tentryTypeB\0\";\n\x04Type\x12\n\n\x04Fil\
+    e\x10\0\x1a\0\x12\t\n\x03Dir\x10\x01\x1a\0\x12\r\n\x07Symlink\x10\x02\
+    \x1a\0\x12\x0b\n\x05Other\x10\x03\x1a\0\x1a\0:\0\"6\n\nRenameFile\x12\
+    \x14\n\x04from\x18\x01\x20\x01(\tR\x04fromB\0\x12\x10\n\x02to\x18\x02\
+    \x20\x01(\tR\x02toB\0:\0\"D\n\nRemoveFile\x12\x14\n\x04path\x18\x01\x20\
+    \x01(\tR\x04pathB\0\x12\x1e\n\trecursive\x18\x02\x20\x01(\x08R\trecursiv\
+    eB\0:\0\"\xf0\x01\n\x12FileBrowserRequest\x12\x10\n\x02id\x18\x01\x20\
+    \x01(\x04R\x02idB\0\x12\x18\n\x05roots\x18\x02\x20\x01(\x08H\0R\x05roots\
+    B\0\x12\x1d\n\x08list_dir\x18\x03\x20\x01(\tH\0R\x07listDirB\0\x12\x16\n\
+    \x04stat\x18\x04\x20\x01(\tH\0R\x04statB\0\x12\x18\n\x05mkdir\x18\x05\
+    \x20\x01(\tH\0R\x05mkdirB\0\x12'\n\x06rename\x18\x06\x20\x01(\x0b2\x0b.R\
+    enameFileH\0R\x06renameB\0\x12'\n\x06remove\x18\x07\x20\x01(\x0b2\x0b.Re\
+    moveFileH\0R\x06removeB\0B\t\n\x05union\x12\0:\0\"\x88\x01\n\x13FileBrow\
+    serResponse\x12\x10\n\x02id\x18\x01\x20\x01(\x04R\x02idB\0\x12\x16\n\x05\
+    error\x18\x02\x20\x01(\tR\x05errorB\0\x12&\n\x07entries\x18\x03\x20\x03(\
+    \x0b2\n.FileEntryR\x07entriesB\0\x12\x1d\n\tread_only\x18\x04\x20\x01(\
+    \x08R\x08readOnlyB\0:\0\"<\n\x0cTerminalSize\x12\x14\n\x04rows\x18\x01\
+    \x20\x01(\rR\x04rowsB\0\x12\x14\n\x04cols\x18\x02\x20\x01(\rR\x04colsB\0\
+    :\0\">\n\x0cTerminalExit\x12\x14\n\x04code\x18\x01\x20\x01(\x05R\x04code\
+    B\0\x12\x16\n\x05error\x18\x02\x20\x01(\tR\x05errorB\0:\0\"\xcd\x01\n\
+    \x0fTerminalMessage\x12%\n\x04open\x18\x01\x20\x01(\x0b2\r.TerminalSizeH\
+    \0R\x04openB\0\x12\x18\n\x05input\x18\x02\x20\x01(\x0cH\0R\x05inputB\0\
+    \x12\x1a\n\x06output\x18\x03\x20\x01(\x0cH\0R\x06outputB\0\x12)\n\x06res\
+    ize\x18\x04\x20\x01(\x0b2\r.TerminalSizeH\0R\x06resizeB\0\x12%\n\x04exit\
+    \x18\x05\x20\x01(\x0b2\r.TerminalExitH\0R\x04exitB\0B\t\n\x05union\x12\0\
+    :\0\";\n\x0bForwardOpen\x12\x14\n\x04host\x18\x01\x20\x01(\tR\x04hostB\0\
+    \x12\x14\n\x04port\x18\x02\x20\x01(\rR\x04portB\0:\0\"`\n\rForwardListen\
+    \x12\x14\n\x04port\x18\x01\x20\x01(\rR\x04portB\0\x12\x14\n\x04host\x18\
+    \x02\x20\x01(\tR\x04hostB\0\x12!\n\x0btarget_port\x18\x03\x20\x01(\rR\nt\
+    argetPortB\0:\0\";\n\rForwardResult\x12\x10\n\x02ok\x18\x01\x20\x01(\x08\
+    R\x02okB\0\x12\x16\n\x05error\x18\x02\x20\x01(\tR\x05errorB\0:\0\"\xd1\
+    \x01\n\x12PortForwardMessage\x12$\n\x04open\x18\x01\x20\x01(\x0b2\x0c.Fo\
+    rwardOpenH\0R\x04openB\0\x12*\n\x06listen\x18\x02\x20\x01(\x0b2\x0e.Forw\
+    ardListenH\0R\x06listenB\0\x12*\n\x06result\x18\x03\x20\x01(\x0b2\x0e.Fo\
+    rwardResultH\0R\x06resultB\0\x12\x16\n\x04data\x18\x04\x20\x01(\x0cH\0R\
+    \x04dataB\0\x12\x18\n\x05close\x18\x05\x20\x01(\x08H\0R\x05closeB\0B\t\n\
+    \x05union\x12\0:\0\"P\n\x0bAudioFormat\x12!\n\x0bsample_rate\x18\x01\x20\
+    \x01(\rR\nsampleRateB\0\x12\x1c\n\x08channels\x18\x02\x20\x01(\rR\x08cha\
+    nnelsB\0:\0\"X\n\nAudioFrame\x12\x14\n\x04data\x18\x01\x20\x01(\x0cR\x04\
+    dataB\0\x12\x1e\n\ttimestamp\x18\x02\x20\x01(\x04R\ttimestampB\0\x12\x12\
+    \n\x03seq\x18\x03\x20\x01(\rR\x03seqB\0:\0\"\x8a\x01\n\x0cAudioMessage\
+    \x12(\n\x06format\x18\x01\x20\x01(\x0b2\x0c.AudioFormatH\0R\x06formatB\0\
+    \x12%\n\x05frame\x18\x02\x20\x01(\x0b2\x0b.AudioFrameH\0R\x05frameB\0\
+    \x12\x1c\n\x07enabled\x18\x03\x20\x01(\x08H\0R\x07enabledB\0B\t\n\x05uni\
+    on\x12\0:\0\"n\n\x08ChatText\x12\x10\n\x02id\x18\x01\x20\x01(\x04R\x02id\
+    B\0\x12\x1e\n\ttimestamp\x18\x02\x20\x01(\x04R\ttimestampB\0\x12\x18\n\
+    \x06sender\x18\x03\x20\x01(\tR\x06senderB\0\x12\x14\n\x04text\x18\x04\
+    \x20\x01(\tR\x04textB\0:\0\"\x1d\n\x07ChatAck\x12\x10\n\x02id\x18\x01\
+    \x20\x01(\x04R\x02idB\0:\0\"]\n\x0bChatMessage\x12!\n\x04text\x18\x01\
+    \x20\x01(\x0b2\t.ChatTextH\0R\x04textB\0\x12\x1e\n\x03ack\x18\x02\x20\
+    \x01(\x0b2\x08.ChatAckH\0R\x03ackB\0B\t\n\x05union\x12\0:\0\"\xa5\x01\n\
+    \x0bDisplayInfo\x12\x10\n\x02id\x18\x01\x20\x01(\x05R\x02idB\0\x12\x14\n\
+    \x04name\x18\x02\x20\x01(\tR\x04nameB\0\x12\x0e\n\x01x\x18\x03\x20\x01(\
+    \x05R\x01xB\0\x12\x0e\n\x01y\x18\x04\x20\x01(\x05R\x01yB\0\x12\x16\n\x05\
+    width\x18\x05\x20\x01(\rR\x05widthB\0\x12\x18\n\x06height\x18\x06\x20\
+    \x01(\rR\x06heightB\0\x12\x1a\n\x07primary\x18\x07\x20\x01(\x08R\x07prim\
+    aryB\0:\0\"W\n\x0bDisplayList\x12*\n\x08displays\x18\x01\x20\x03(\x0b2\
+    \x0c.DisplayInfoR\x08displaysB\0\x12\x1a\n\x07current\x18\x02\x20\x01(\
+    \x05R\x07currentB\0:\0\"#\n\rSwitchDisplay\x12\x10\n\x02id\x18\x01\x20\
+    \x01(\x05R\x02idB\0:\0\"C\n\rSetResolution\x12\x16\n\x05width\x18\x01\
+    \x20\x01(\rR\x05widthB\0\x12\x18\n\x06height\x18\x02\x20\x01(\rR\x06heig\
+    htB\0:\0\"D\n\nVideoFrame\x12\x14\n\x04data\x18\x01\x20\x01(\x0cR\x04dat\
+    aB\0\x12\x1e\n\ttimestamp\x18\x02\x20\x01(\x04R\ttimestampB\0:\0\"\xf8\
+    \x01\n\x0cVideoMessage\x123\n\x0cdisplay_list\x18\x01\x20\x01(\x0b2\x0c.\
+    DisplayListH\0R\x0bdisplayListB\0\x120\n\x0bvideo_frame\x18\x02\x20\x01(\
+    \x0b2\x0b.VideoFrameH\0R\nvideoFrameB\0\x129\n\x0eswitch_display\x18\x03\
+    \x20\x01(\x0b2\x0e.SwitchDisplayH\0R\rswitchDisplayB\0\x129\n\x0eset_res\
+    olution\x18\x04\x20\x01(\x0b2\x0e.SetResolutionH\0R\rsetResolutionB\0B\t\
+    \n\x05union\x12\0:\0\"\xbc\x01\n\x0bInputStatus\x12'\n\x04code\x18\x01\
+    \x20\x01(\x0e2\x11.InputStatus.CodeR\x04codeB\0\x12\x1a\n\x07backend\x18\
+    \x02\x20\x01(\tR\x07backendB\0\x12\x1a\n\x07message\x18\x03\x20\x01(\tR\
+    \x07messageB\0\"J\n\x04Code\x12\x08\n\x02Ok\x10\0\x1a\0\x12\x11\n\x0bUns\
+    upported\x10\x01\x1a\0\x12\x16\n\x10PermissionDenied\x10\x02\x1a\0\x12\
+    \x0b\n\x05Error\x10\x03\x1a\0\x1a\0:\0*;\n\x0eChannelControl\x12\x0c\n\
+    \x06Create\x10\0\x1a\0\x12\r\n\x07Destroy\x10\x01\x1a\0\x12\n\n\x04Data\
+    \x10\x02\x1a\0\x1a\0*\xc2\x01\n\x0bChannelType\x12\x0e\n\x08NoDefine\x10\
+    \0\x1a\0\x12\x0b\n\x05Video\x10\x01\x1a\0\x12\x0e\n\x08KeyEvent\x10\x02\
+    \x1a\0\x12\x10\n\nMouseEvent\x10\x03\x1a\0\x12\x0f\n\tClipboard\x10\x04\
+    \x1a\0\x12\x12\n\x0cFileTransfer\x10\x05\x1a\0\x12\x11\n\x0bFileBrowser\
+    \x10\x06\x1a\0\x12\x0e\n\x08Terminal\x10\x07\x1a\0\x12\x11\n\x0bPortForw\
+    ard\x10\x08\x1a\0\x12\x0b\n\x05Audio\x10\t\x1a\0\x12\n\n\x04Chat\x10\n\
     \x1a\0\x1a\0*5\n\x0cChannelPower\x12\n\n\x04Both\x10\0\x1a\0\x12\n\n\x04\
     Read\x10\x01\x1a\0\x12\x0b\n\x05Write\x10\x02\x1a\0\x1a\0B\0b\x06proto3\
 ";
@@ -2078,14 +11815,60 @@ pub fn file_descriptor() -> ::protobuf::reflect::FileDescriptor {
         messages.push(RemoteMessage::generated_message_descriptor_data());
         messages.push(ChannelMover::generated_message_descriptor_data());
         messages.push(KeyEvent::generated_message_descriptor_data());
+        messages.push(TextInput::generated_message_descriptor_data());
+        messages.push(KeyboardSnapshot::generated_message_descriptor_data());
+        messages.push(KeyCombo::generated_message_descriptor_data());
+        messages.push(SpecialKey::generated_message_descriptor_data());
+        messages.push(KeyMacro::generated_message_descriptor_data());
+        messages.push(KeyboardMessage::generated_message_descriptor_data());
         messages.push(MouseEvent::generated_message_descriptor_data());
+        messages.push(InputBatch::generated_message_descriptor_data());
+        messages.push(ClipboardData::generated_message_descriptor_data());
+        messages.push(ClipboardMessage::generated_message_descriptor_data());
+        messages.push(FileOffer::generated_message_descriptor_data());
+        messages.push(FileRequest::generated_message_descriptor_data());
+        messages.push(FileAccept::generated_message_descriptor_data());
+        messages.push(FileReject::generated_message_descriptor_data());
+        messages.push(FileChunk::generated_message_descriptor_data());
+        messages.push(FileDone::generated_message_descriptor_data());
+        messages.push(FileResult::generated_message_descriptor_data());
+        messages.push(FileTransferMessage::generated_message_descriptor_data());
+        messages.push(FileEntry::generated_message_descriptor_data());
+        messages.push(RenameFile::generated_message_descriptor_data());
+        messages.push(RemoveFile::generated_message_descriptor_data());
+        messages.push(FileBrowserRequest::generated_message_descriptor_data());
+        messages.push(FileBrowserResponse::generated_message_descriptor_data());
+        messages.push(TerminalSize::generated_message_descriptor_data());
+        messages.push(TerminalExit::generated_message_descriptor_data());
+        messages.push(TerminalMessage::generated_message_descriptor_data());
+        messages.push(ForwardOpen::generated_message_descriptor_data());
+        messages.push(ForwardListen::generated_message_descriptor_data());
+        messages.push(ForwardResult::generated_message_descriptor_data());
+        messages.push(PortForwardMessage::generated_message_descriptor_data());
+        messages.push(AudioFormat::generated_message_descriptor_data());
+        messages.push(AudioFrame::generated_message_descriptor_data());
+        messages.push(AudioMessage::generated_message_descriptor_data());
+        messages.push(ChatText::generated_message_descriptor_data());
+        messages.push(ChatAck::generated_message_descriptor_data());
+        messages.push(ChatMessage::generated_message_descriptor_data());
+        messages.push(DisplayInfo::generated_message_descriptor_data());
+        messages.push(DisplayList::generated_message_descriptor_data());
+        messages.push(SwitchDisplay::generated_message_descriptor_data());
+        messages.push(SetResolution::generated_message_descriptor_data());
+        messages.push(VideoFrame::generated_message_descriptor_data());
+        messages.push(VideoMessage::generated_message_descriptor_data());
+        messages.push(InputStatus::generated_message_descriptor_data());
         let mut enums = ::std::vec::Vec::new();
         enums.push(ChannelControl::generated_enum_descriptor_data());
         enums.push(ChannelType::generated_enum_descriptor_data());
         enums.push(ChannelPower::generated_enum_descriptor_data());
         enums.push(login_response::Code::generated_enum_descriptor_data());
         enums.push(key_event::Active::generated_enum_descriptor_data());
+        enums.push(text_input::Composition::generated_enum_descriptor_data());
+        enums.push(special_key::Name::generated_enum_descriptor_data());
         enums.push(mouse_event::Active::generated_enum_descriptor_data());
+        enums.push(file_entry::Type::generated_enum_descriptor_data());
+        enums.push(input_status::Code::generated_enum_descriptor_data());
         ::protobuf::reflect::GeneratedFileDescriptor::new_generated(
             file_descriptor_proto(),
             deps,
@@ -33,6 +33,7 @@ pub struct RegisterPeer {
     pub cert: ::std::vec::Vec<u8>,
     pub pub_key: ::std::vec::Vec<u8>,
     pub u_id: ::std::string::String,
+    pub discoverable: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::rt::CachedSize,
@@ -76,6 +77,11 @@ impl RegisterPeer {
             |m: &RegisterPeer| { &m.u_id },
             |m: &mut RegisterPeer| { &mut m.u_id },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "discoverable",
+            |m: &RegisterPeer| { &m.discoverable },
+            |m: &mut RegisterPeer| { &mut m.discoverable },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RegisterPeer>(
             "RegisterPeer",
             0,
@@ -123,6 +129,12 @@ impl ::protobuf::Message for RegisterPeer {
                     }
                     self.u_id = is.read_string()?;
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.discoverable = is.read_bool()?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -150,6 +162,9 @@ impl ::protobuf::Message for RegisterPeer {
         if !self.u_id.is_empty() {
             my_size += ::protobuf::rt::string_size(5, &self.u_id);
         }
+        if self.discoverable != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -171,6 +186,9 @@ impl ::protobuf::Message for RegisterPeer {
         if !self.u_id.is_empty() {
             os.write_string(5, &self.u_id)?;
         }
+        if self.discoverable != false {
+            os.write_bool(6, self.discoverable)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -202,6 +220,7 @@ impl ::protobuf::Message for RegisterPeer {
             cert: ::std::vec::Vec::new(),
             pub_key: ::std::vec::Vec::new(),
             u_id: ::std::string::String::new(),
+            discoverable: false,
             unknown_fields: ::protobuf::UnknownFields::new(),
             cached_size: ::protobuf::rt::CachedSize::new(),
         };
@@ -216,6 +235,7 @@ impl ::protobuf::Clear for RegisterPeer {
         self.cert.clear();
         self.pub_key.clear();
         self.u_id.clear();
+        self.discoverable = false;
         self.unknown_fields.clear();
     }
 }
@@ -571,6 +591,8 @@ pub struct RelayResponse {
     pub ralay_pub_key: ::std::vec::Vec<u8>,
     pub code: ::protobuf::ProtobufEnumOrUnknown<relay_response::Code>,
     pub message: ::std::string::String,
+    pub retry_after: u32,
+    pub peer_addr: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::rt::CachedSize,
@@ -629,6 +651,16 @@ impl RelayResponse {
             |m: &RelayResponse| { &m.message },
             |m: &mut RelayResponse| { &mut m.message },
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "retry_after",
+            |m: &RelayResponse| { &m.retry_after },
+            |m: &mut RelayResponse| { &mut m.retry_after },
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "peer_addr",
+            |m: &RelayResponse| { &m.peer_addr },
+            |m: &mut RelayResponse| { &mut m.peer_addr },
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RelayResponse>(
             "RelayResponse",
             3,
@@ -694,6 +726,18 @@ impl ::protobuf::Message for RelayResponse {
                     }
                     self.message = is.read_string()?;
                 },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.retry_after = is.read_uint32()?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.peer_addr = is.read_string()?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -730,6 +774,12 @@ impl ::protobuf::Message for RelayResponse {
         if !self.message.is_empty() {
             my_size += ::protobuf::rt::string_size(8, &self.message);
         }
+        if self.retry_after != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.retry_after, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.peer_addr.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.peer_addr);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -760,6 +810,12 @@ impl ::protobuf::Message for RelayResponse {
         if !self.message.is_empty() {
             os.write_string(8, &self.message)?;
         }
+        if self.retry_after != 0 {
+            os.write_uint32(9, self.retry_after)?;
+        }
+        if !self.peer_addr.is_empty() {
+            os.write_string(10, &self.peer_addr)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -794,6 +850,8 @@ impl ::protobuf::Message for RelayResponse {
             ralay_pub_key: ::std::vec::Vec::new(),
             code: ::protobuf::ProtobufEnumOrUnknown::from_i32(0),
             message: ::std::string::String::new(),
+            retry_after: 0,
+            peer_addr: ::std::string::String::new(),
             unknown_fields: ::protobuf::UnknownFields::new(),
             cached_size: ::protobuf::rt::CachedSize::new(),
         };
@@ -811,6 +869,8 @@ impl ::protobuf::Clear for RelayResponse {
         self.ralay_pub_key.clear();
         self.code = ::protobuf::ProtobufEnumOrUnknown::new(relay_response::Code::Success);
         self.message.clear();
+        self.retry_after = 0;
+        self.peer_addr.clear();
         self.unknown_fields.clear();
     }
 }
@@ -832,6 +892,7 @@ pub mod relay_response {
         Success = 0,
         Fail = 1,
         Offline = 2,
+        Throttled = 3,
     }
 
     impl ::protobuf::ProtobufEnum for Code {
@@ -844,6 +905,7 @@ pub mod relay_response {
                 0 => ::std::option::Option::Some(Code::Success),
                 1 => ::std::option::Option::Some(Code::Fail),
                 2 => ::std::option::Option::Some(Code::Offline),
+                3 => ::std::option::Option::Some(Code::Throttled),
                 _ => ::std::option::Option::None
             }
         }
@@ -853,6 +915,7 @@ pub mod relay_response {
                 Code::Success,
                 Code::Fail,
                 Code::Offline,
+                Code::Throttled,
             ];
             values
         }
@@ -983,6 +1046,375 @@ impl ::protobuf::reflect::ProtobufValue for Ping {
     type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryOnline {
+    // message fields
+    pub peer_id: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryOnline {
+    fn default() -> &'a QueryOnline {
+        <QueryOnline as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryOnline {
+    pub fn new() -> QueryOnline {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "peer_id",
+            |m: &QueryOnline| { &m.peer_id },
+            |m: &mut QueryOnline| { &mut m.peer_id },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<QueryOnline>(
+            "QueryOnline",
+            5,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for QueryOnline {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.peer_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.peer_id {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.peer_id {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> QueryOnline {
+        QueryOnline::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 5)
+    }
+
+    fn default_instance() -> &'static QueryOnline {
+        static instance: QueryOnline = QueryOnline {
+            peer_id: ::std::vec::Vec::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for QueryOnline {
+    fn clear(&mut self) {
+        self.peer_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryOnline {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryOnline {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct OnlineResponse {
+    // message fields
+    pub online: ::std::vec::Vec<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a OnlineResponse {
+    fn default() -> &'a OnlineResponse {
+        <OnlineResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl OnlineResponse {
+    pub fn new() -> OnlineResponse {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_vec_simpler_accessor::<_, _>(
+            "online",
+            |m: &OnlineResponse| { &m.online },
+            |m: &mut OnlineResponse| { &mut m.online },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<OnlineResponse>(
+            "OnlineResponse",
+            6,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for OnlineResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.online)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.online {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.online {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> OnlineResponse {
+        OnlineResponse::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 6)
+    }
+
+    fn default_instance() -> &'static OnlineResponse {
+        static instance: OnlineResponse = OnlineResponse {
+            online: ::std::vec::Vec::new(),
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for OnlineResponse {
+    fn clear(&mut self) {
+        self.online.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for OnlineResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for OnlineResponse {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SetDiscoverable {
+    // message fields
+    pub discoverable: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::rt::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SetDiscoverable {
+    fn default() -> &'a SetDiscoverable {
+        <SetDiscoverable as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SetDiscoverable {
+    pub fn new() -> SetDiscoverable {
+        ::std::default::Default::default()
+    }
+
+    fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
+        let mut fields = ::std::vec::Vec::new();
+        fields.push(::protobuf::reflect::rt::v2::make_simpler_field_accessor::<_, _>(
+            "discoverable",
+            |m: &SetDiscoverable| { &m.discoverable },
+            |m: &mut SetDiscoverable| { &mut m.discoverable },
+        ));
+        ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<SetDiscoverable>(
+            "SetDiscoverable",
+            7,
+            fields,
+        )
+    }
+}
+
+impl ::protobuf::Message for SetDiscoverable {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.discoverable = is.read_bool()?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.discoverable != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.discoverable != false {
+            os.write_bool(1, self.discoverable)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn new() -> SetDiscoverable {
+        SetDiscoverable::new()
+    }
+
+    fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 7)
+    }
+
+    fn default_instance() -> &'static SetDiscoverable {
+        static instance: SetDiscoverable = SetDiscoverable {
+            discoverable: false,
+            unknown_fields: ::protobuf::UnknownFields::new(),
+            cached_size: ::protobuf::rt::CachedSize::new(),
+        };
+        &instance
+    }
+}
+
+impl ::protobuf::Clear for SetDiscoverable {
+    fn clear(&mut self) {
+        self.discoverable = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SetDiscoverable {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SetDiscoverable {
+    type RuntimeType = ::protobuf::reflect::runtime_types::RuntimeTypeMessage<Self>;
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct RendezvousMessage {
     // message oneof groups
@@ -1248,6 +1680,153 @@ impl RendezvousMessage {
         }
     }
 
+    // .QueryOnline query_online = 6;
+
+    pub fn get_query_online(&self) -> &QueryOnline {
+        match self.union {
+            ::std::option::Option::Some(rendezvous_message::Union::query_online(ref v)) => v,
+            _ => <QueryOnline as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_query_online(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_query_online(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(rendezvous_message::Union::query_online(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_query_online(&mut self, v: QueryOnline) {
+        self.union = ::std::option::Option::Some(rendezvous_message::Union::query_online(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_query_online(&mut self) -> &mut QueryOnline {
+        if let ::std::option::Option::Some(rendezvous_message::Union::query_online(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(rendezvous_message::Union::query_online(QueryOnline::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(rendezvous_message::Union::query_online(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_query_online(&mut self) -> QueryOnline {
+        if self.has_query_online() {
+            match self.union.take() {
+                ::std::option::Option::Some(rendezvous_message::Union::query_online(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryOnline::new()
+        }
+    }
+
+    // .OnlineResponse online_response = 7;
+
+    pub fn get_online_response(&self) -> &OnlineResponse {
+        match self.union {
+            ::std::option::Option::Some(rendezvous_message::Union::online_response(ref v)) => v,
+            _ => <OnlineResponse as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_online_response(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_online_response(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(rendezvous_message::Union::online_response(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_online_response(&mut self, v: OnlineResponse) {
+        self.union = ::std::option::Option::Some(rendezvous_message::Union::online_response(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_online_response(&mut self) -> &mut OnlineResponse {
+        if let ::std::option::Option::Some(rendezvous_message::Union::online_response(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(rendezvous_message::Union::online_response(OnlineResponse::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(rendezvous_message::Union::online_response(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_online_response(&mut self) -> OnlineResponse {
+        if self.has_online_response() {
+            match self.union.take() {
+                ::std::option::Option::Some(rendezvous_message::Union::online_response(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            OnlineResponse::new()
+        }
+    }
+
+    // .SetDiscoverable set_discoverable = 8;
+
+    pub fn get_set_discoverable(&self) -> &SetDiscoverable {
+        match self.union {
+            ::std::option::Option::Some(rendezvous_message::Union::set_discoverable(ref v)) => v,
+            _ => <SetDiscoverable as ::protobuf::Message>::default_instance(),
+        }
+    }
+
+    pub fn clear_set_discoverable(&mut self) {
+        self.union = ::std::option::Option::None;
+    }
+
+    pub fn has_set_discoverable(&self) -> bool {
+        match self.union {
+            ::std::option::Option::Some(rendezvous_message::Union::set_discoverable(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_set_discoverable(&mut self, v: SetDiscoverable) {
+        self.union = ::std::option::Option::Some(rendezvous_message::Union::set_discoverable(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_set_discoverable(&mut self) -> &mut SetDiscoverable {
+        if let ::std::option::Option::Some(rendezvous_message::Union::set_discoverable(_)) = self.union {
+        } else {
+            self.union = ::std::option::Option::Some(rendezvous_message::Union::set_discoverable(SetDiscoverable::new()));
+        }
+        match self.union {
+            ::std::option::Option::Some(rendezvous_message::Union::set_discoverable(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_set_discoverable(&mut self) -> SetDiscoverable {
+        if self.has_set_discoverable() {
+            match self.union.take() {
+                ::std::option::Option::Some(rendezvous_message::Union::set_discoverable(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SetDiscoverable::new()
+        }
+    }
+
     fn generated_message_descriptor_data() -> ::protobuf::reflect::GeneratedMessageDescriptorData {
         let mut fields = ::std::vec::Vec::new();
         fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, RegisterPeer>(
@@ -1285,9 +1864,30 @@ impl RendezvousMessage {
             RendezvousMessage::mut_ping,
             RendezvousMessage::set_ping,
         ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, QueryOnline>(
+            "query_online",
+            RendezvousMessage::has_query_online,
+            RendezvousMessage::get_query_online,
+            RendezvousMessage::mut_query_online,
+            RendezvousMessage::set_query_online,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, OnlineResponse>(
+            "online_response",
+            RendezvousMessage::has_online_response,
+            RendezvousMessage::get_online_response,
+            RendezvousMessage::mut_online_response,
+            RendezvousMessage::set_online_response,
+        ));
+        fields.push(::protobuf::reflect::rt::v2::make_oneof_message_has_get_mut_set_accessor::<_, SetDiscoverable>(
+            "set_discoverable",
+            RendezvousMessage::has_set_discoverable,
+            RendezvousMessage::get_set_discoverable,
+            RendezvousMessage::mut_set_discoverable,
+            RendezvousMessage::set_set_discoverable,
+        ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RendezvousMessage>(
             "RendezvousMessage",
-            5,
+            8,
             fields,
         )
     }
@@ -1320,6 +1920,21 @@ impl ::protobuf::Message for RendezvousMessage {
                 return false;
             }
         }
+        if let Some(rendezvous_message::Union::query_online(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(rendezvous_message::Union::online_response(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(rendezvous_message::Union::set_discoverable(ref v)) = self.union {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -1357,6 +1972,24 @@ impl ::protobuf::Message for RendezvousMessage {
                     }
                     self.union = ::std::option::Option::Some(rendezvous_message::Union::ping(is.read_message()?));
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(rendezvous_message::Union::query_online(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(rendezvous_message::Union::online_response(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.union = ::std::option::Option::Some(rendezvous_message::Union::set_discoverable(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1391,6 +2024,18 @@ impl ::protobuf::Message for RendezvousMessage {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &rendezvous_message::Union::query_online(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &rendezvous_message::Union::online_response(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &rendezvous_message::Union::set_discoverable(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -1416,6 +2061,15 @@ impl ::protobuf::Message for RendezvousMessage {
                 &rendezvous_message::Union::ping(ref v) => {
                     ::protobuf::rt::write_message_field_with_cached_size(5, v, os)?;
                 },
+                &rendezvous_message::Union::query_online(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(6, v, os)?;
+                },
+                &rendezvous_message::Union::online_response(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(7, v, os)?;
+                },
+                &rendezvous_message::Union::set_discoverable(ref v) => {
+                    ::protobuf::rt::write_message_field_with_cached_size(8, v, os)?;
+                },
             };
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -1439,7 +2093,7 @@ impl ::protobuf::Message for RendezvousMessage {
     }
 
     fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 5)
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 8)
     }
 
     fn default_instance() -> &'static RendezvousMessage {
@@ -1459,6 +2113,9 @@ impl ::protobuf::Clear for RendezvousMessage {
         self.union = ::std::option::Option::None;
         self.union = ::std::option::Option::None;
         self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
+        self.union = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -1483,6 +2140,9 @@ pub mod rendezvous_message {
         request_relay(super::RequestRelay),
         relay_response(super::RelayResponse),
         ping(super::Ping),
+        query_online(super::QueryOnline),
+        online_response(super::OnlineResponse),
+        set_discoverable(super::SetDiscoverable),
     }
 
     impl ::protobuf::Oneof for Union {
@@ -1530,7 +2190,7 @@ impl RelayConn {
         ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RelayConn>(
             "RelayConn",
-            6,
+            9,
             fields,
         )
     }
@@ -1620,7 +2280,7 @@ impl ::protobuf::Message for RelayConn {
     }
 
     fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 6)
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 9)
     }
 
     fn default_instance() -> &'static RelayConn {
@@ -1683,7 +2343,7 @@ impl RelayStart {
         ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RelayStart>(
             "RelayStart",
-            7,
+            10,
             fields,
         )
     }
@@ -1749,7 +2409,7 @@ impl ::protobuf::Message for RelayStart {
     }
 
     fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 7)
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 10)
     }
 
     fn default_instance() -> &'static RelayStart {
@@ -1915,7 +2575,7 @@ impl RelayMessage {
         ));
         ::protobuf::reflect::GeneratedMessageDescriptorData::new_2::<RelayMessage>(
             "RelayMessage",
-            8,
+            11,
             fields,
         )
     }
@@ -2013,7 +2673,7 @@ impl ::protobuf::Message for RelayMessage {
     }
 
     fn descriptor_static() -> ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 8)
+        ::protobuf::reflect::MessageDescriptor::new_generated_2(file_descriptor(), 11)
     }
 
     fn default_instance() -> &'static RelayMessage {
@@ -2058,39 +2718,49 @@ pub mod relay_message {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10rendezvous.proto\"\x92\x01\n\x0cRegisterPeer\x12\x19\n\x07user_id\
+    \n\x10rendezvous.proto\"\xb8\x01\n\x0cRegisterPeer\x12\x19\n\x07user_id\
     \x18\x01\x20\x01(\tR\x06userIdB\0\x12\x1f\n\nserver_key\x18\x02\x20\x01(\
     \tR\tserverKeyB\0\x12\x14\n\x04cert\x18\x03\x20\x01(\x0cR\x04certB\0\x12\
     \x19\n\x07pub_key\x18\x04\x20\x01(\x0cR\x06pubKeyB\0\x12\x13\n\x04u_id\
-    \x18\x05\x20\x01(\tR\x03uIdB\0:\0\"\xb9\x01\n\x14RegisterPeerResponse\
-    \x120\n\x04code\x18\x01\x20\x01(\x0e2\x1a.RegisterPeerResponse.CodeR\x04\
-    codeB\0\x12\x1a\n\x07message\x18\x02\x20\x01(\tR\x07messageB\0\"Q\n\x04C\
-    ode\x12\r\n\x07Success\x10\0\x1a\0\x12\n\n\x04Fail\x10\x01\x1a\0\x12\x0b\
-    \n\x05Error\x10\x02\x1a\0\x12\x0c\n\x06Repeat\x10\x03\x1a\0\x12\x11\n\
-    \x0bKeyNotMatch\x10\x04\x1a\0\x1a\0:\0\"+\n\x0cRequestRelay\x12\x19\n\
-    \x07peer_id\x18\x01\x20\x01(\tR\x06peerIdB\0:\0\"\xd0\x02\n\rRelayRespon\
-    se\x12\x1b\n\x08ralay_id\x18\x01\x20\x01(\tR\x07ralayIdB\0\x12\x1f\n\nre\
-    lay_addr\x18\x02\x20\x01(\tR\trelayAddrB\0\x12\x1f\n\nis_control\x18\x03\
-    \x20\x01(\x08R\tisControlB\0\x12\"\n\x0cpeer_pub_key\x18\x04\x20\x01(\
-    \x0cR\npeerPubKeyB\0\x12\x19\n\x07peer_id\x18\x05\x20\x01(\tR\x06peerIdB\
-    \0\x12$\n\rralay_pub_key\x18\x06\x20\x01(\x0cR\x0bralayPubKeyB\0\x12)\n\
-    \x04code\x18\x07\x20\x01(\x0e2\x13.RelayResponse.CodeR\x04codeB\0\x12\
-    \x1a\n\x07message\x18\x08\x20\x01(\tR\x07messageB\0\"2\n\x04Code\x12\r\n\
-    \x07Success\x10\0\x1a\0\x12\n\n\x04Fail\x10\x01\x1a\0\x12\r\n\x07Offline\
-    \x10\x02\x1a\0\x1a\0:\0\"\x08\n\x04Ping:\0\"\xbb\x02\n\x11RendezvousMess\
-    age\x126\n\rregister_peer\x18\x01\x20\x01(\x0b2\r.RegisterPeerH\0R\x0cre\
-    gisterPeerB\0\x12O\n\x16register_peer_response\x18\x02\x20\x01(\x0b2\x15\
-    .RegisterPeerResponseH\0R\x14registerPeerResponseB\0\x126\n\rrequest_rel\
-    ay\x18\x03\x20\x01(\x0b2\r.RequestRelayH\0R\x0crequestRelayB\0\x129\n\
-    \x0erelay_response\x18\x04\x20\x01(\x0b2\x0e.RelayResponseH\0R\rrelayRes\
-    ponseB\0\x12\x1d\n\x04ping\x18\x05\x20\x01(\x0b2\x05.PingH\0R\x04pingB\0\
-    B\t\n\x05union\x12\0:\0\"V\n\tRelayConn\x12\x1b\n\x08relay_id\x18\x01\
-    \x20\x01(\tR\x07relayIdB\0\x12\x12\n\x03key\x18\x02\x20\x01(\x0cR\x03key\
-    B\0\x12\x16\n\x05nonce\x18\x03\x20\x01(\x0cR\x05nonceB\0:\0\"$\n\nRelayS\
-    tart\x12\x14\n\x04rand\x18\x01\x20\x01(\x04R\x04randB\0:\0\"|\n\x0cRelay\
-    Message\x12-\n\nrelay_conn\x18\x01\x20\x01(\x0b2\n.RelayConnH\0R\trelayC\
-    onnB\0\x120\n\x0brelay_start\x18\x02\x20\x01(\x0b2\x0b.RelayStartH\0R\nr\
-    elayStartB\0B\t\n\x05union\x12\0:\0B\0b\x06proto3\
+    \x18\x05\x20\x01(\tR\x03uIdB\0\x12$\n\x0cdiscoverable\x18\x06\x20\x01(\
+    \x08R\x0cdiscoverableB\0:\0\"\xb9\x01\n\x14RegisterPeerResponse\x120\n\
+    \x04code\x18\x01\x20\x01(\x0e2\x1a.RegisterPeerResponse.CodeR\x04codeB\0\
+    \x12\x1a\n\x07message\x18\x02\x20\x01(\tR\x07messageB\0\"Q\n\x04Code\x12\
+    \r\n\x07Success\x10\0\x1a\0\x12\n\n\x04Fail\x10\x01\x1a\0\x12\x0b\n\x05E\
+    rror\x10\x02\x1a\0\x12\x0c\n\x06Repeat\x10\x03\x1a\0\x12\x11\n\x0bKeyNot\
+    Match\x10\x04\x1a\0\x1a\0:\0\"+\n\x0cRequestRelay\x12\x19\n\x07peer_id\
+    \x18\x01\x20\x01(\tR\x06peerIdB\0:\0\"\xa3\x03\n\rRelayResponse\x12\x1b\
+    \n\x08ralay_id\x18\x01\x20\x01(\tR\x07ralayIdB\0\x12\x1f\n\nrelay_addr\
+    \x18\x02\x20\x01(\tR\trelayAddrB\0\x12\x1f\n\nis_control\x18\x03\x20\x01\
+    (\x08R\tisControlB\0\x12\"\n\x0cpeer_pub_key\x18\x04\x20\x01(\x0cR\npeer\
+    PubKeyB\0\x12\x19\n\x07peer_id\x18\x05\x20\x01(\tR\x06peerIdB\0\x12$\n\r\
+    ralay_pub_key\x18\x06\x20\x01(\x0cR\x0bralayPubKeyB\0\x12)\n\x04code\x18\
+    \x07\x20\x01(\x0e2\x13.RelayResponse.CodeR\x04codeB\0\x12\x1a\n\x07messa\
+    ge\x18\x08\x20\x01(\tR\x07messageB\0\x12!\n\x0bretry_after\x18\t\x20\x01\
+    (\rR\nretryAfterB\0\x12\x1d\n\tpeer_addr\x18\n\x20\x01(\tR\x08peerAddrB\
+    \0\"C\n\x04Code\x12\r\n\x07Success\x10\0\x1a\0\x12\n\n\x04Fail\x10\x01\
+    \x1a\0\x12\r\n\x07Offline\x10\x02\x1a\0\x12\x0f\n\tThrottled\x10\x03\x1a\
+    \0\x1a\0:\0\"\x08\n\x04Ping:\0\"*\n\x0bQueryOnline\x12\x19\n\x07peer_id\
+    \x18\x01\x20\x03(\tR\x06peerIdB\0:\0\",\n\x0eOnlineResponse\x12\x18\n\
+    \x06online\x18\x01\x20\x03(\tR\x06onlineB\0:\0\"9\n\x0fSetDiscoverable\
+    \x12$\n\x0cdiscoverable\x18\x01\x20\x01(\x08R\x0cdiscoverableB\0:\0\"\
+    \xef\x03\n\x11RendezvousMessage\x126\n\rregister_peer\x18\x01\x20\x01(\
+    \x0b2\r.RegisterPeerH\0R\x0cregisterPeerB\0\x12O\n\x16register_peer_resp\
+    onse\x18\x02\x20\x01(\x0b2\x15.RegisterPeerResponseH\0R\x14registerPeerR\
+    esponseB\0\x126\n\rrequest_relay\x18\x03\x20\x01(\x0b2\r.RequestRelayH\0\
+    R\x0crequestRelayB\0\x129\n\x0erelay_response\x18\x04\x20\x01(\x0b2\x0e.\
+    RelayResponseH\0R\rrelayResponseB\0\x12\x1d\n\x04ping\x18\x05\x20\x01(\
+    \x0b2\x05.PingH\0R\x04pingB\0\x123\n\x0cquery_online\x18\x06\x20\x01(\
+    \x0b2\x0c.QueryOnlineH\0R\x0bqueryOnlineB\0\x12<\n\x0fonline_response\
+    \x18\x07\x20\x01(\x0b2\x0f.OnlineResponseH\0R\x0eonlineResponseB\0\x12?\
+    \n\x10set_discoverable\x18\x08\x20\x01(\x0b2\x10.SetDiscoverableH\0R\x0f\
+    setDiscoverableB\0B\t\n\x05union\x12\0:\0\"V\n\tRelayConn\x12\x1b\n\x08r\
+    elay_id\x18\x01\x20\x01(\tR\x07relayIdB\0\x12\x12\n\x03key\x18\x02\x20\
+    \x01(\x0cR\x03keyB\0\x12\x16\n\x05nonce\x18\x03\x20\x01(\x0cR\x05nonceB\
+    \0:\0\"$\n\nRelayStart\x12\x14\n\x04rand\x18\x01\x20\x01(\x04R\x04randB\
+    \0:\0\"|\n\x0cRelayMessage\x12-\n\nrelay_conn\x18\x01\x20\x01(\x0b2\n.Re\
+    layConnH\0R\trelayConnB\0\x120\n\x0brelay_start\x18\x02\x20\x01(\x0b2\
+    \x0b.RelayStartH\0R\nrelayStartB\0B\t\n\x05union\x12\0:\0B\0b\x06proto3\
 ";
 
 /// `FileDescriptorProto` object which was a source for this generated file
@@ -2112,6 +2782,9 @@ pub fn file_descriptor() -> ::protobuf::reflect::FileDescriptor {
         messages.push(RequestRelay::generated_message_descriptor_data());
         messages.push(RelayResponse::generated_message_descriptor_data());
         messages.push(Ping::generated_message_descriptor_data());
+        messages.push(QueryOnline::generated_message_descriptor_data());
+        messages.push(OnlineResponse::generated_message_descriptor_data());
+        messages.push(SetDiscoverable::generated_message_descriptor_data());
         messages.push(RendezvousMessage::generated_message_descriptor_data());
         messages.push(RelayConn::generated_message_descriptor_data());
         messages.push(RelayStart::generated_message_descriptor_data());
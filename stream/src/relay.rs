@@ -2,7 +2,6 @@ use std::net::TcpStream;
 
 use protobuf::Message;
 use rand::Rng;
-use sha2::{Digest, Sha256};
 
 use crate::{
     bytes_codec::TcpFramed,
@@ -10,8 +9,11 @@ use crate::{
         login_response, remote_message, ClientHello, LoginRequest, LoginResponse, RemoteMessage,
         ServerHello,
     },
+    permission::Permissions,
     rendezvous_proto::{relay_message, RelayConn, RelayMessage},
-    utils::{Aes128GcmUtil, RsaPrivKey, RsaPubKey},
+    utils::{
+        check_login_proof, derive_password, login_proof, Aes128GcmUtil, RsaPrivKey, RsaPubKey,
+    },
     RemoteError, ResultType,
 };
 
@@ -59,6 +61,65 @@ fn relay_start(
     };
     Ok((tcp_framed, relay_rand))
 }
+//主控端接受的最大迭代次数
+const MAX_ROUNDS: u32 = 10_000_000;
+
+///被控端保存的密码校验信息，不含明文
+#[derive(Debug, Clone, Default)]
+pub struct Credentials {
+    //本机所有密码共用的盐和迭代次数
+    pub salt: Vec<u8>,
+    pub rounds: u32,
+    //各个等级密码的校验值、对应的权限和是否还需要验证码
+    pub verifiers: Vec<(Vec<u8>, Permissions, bool)>,
+    //参数检查某个校验值能否通过本次的登录证明，匹配时同时作废临时密码并返回对应的权限
+    pub one_time: Option<fn(&dyn Fn(&[u8]) -> bool) -> Option<Permissions>>,
+    //校验第二因素验证码，None时不要求验证码
+    pub second_factor: Option<fn(&str) -> bool>,
+    //跨连接的失败记录，None时只限制单个连接内的尝试次数
+    pub guard: Option<LoginGuard>,
+}
+
+///被控端保存的登录失败记录，参数为主控端代号和中继来源，返回剩余的锁定秒数
+#[derive(Debug, Clone, Copy)]
+pub struct LoginGuard {
+    pub locked: fn(&str, &str) -> u64,
+    //密码或验证码错误
+    pub failed: fn(&str, &str) -> u64,
+    pub succeeded: fn(&str, &str),
+}
+
+//锁定期间拒绝登录，告诉主控端剩余的秒数
+fn lockout_response(secs: u64) -> ResultType<Vec<u8>> {
+    let mut msg = RemoteMessage::new();
+    msg.set_login_response(LoginResponse {
+        code: protobuf::ProtobufEnumOrUnknown::from(login_response::Code::Frequently),
+        error: format!("尝试次数过多，请{}秒后再试", secs),
+        lockout_secs: secs as u32,
+        ..Default::default()
+    });
+    Ok(msg.write_to_bytes()?)
+}
+
+//登录证明签名的内容，包含双方代号和本次连接的随机数，被截获后不能用于其他连接
+fn login_transcript(
+    client_id: &str,
+    server_id: &str,
+    hello_key: &[u8],
+    client_rand: (u64, u64),
+    server_rand: (u64, u64),
+) -> Vec<u8> {
+    let mut transcript = Vec::new();
+    for part in [client_id.as_bytes(), server_id.as_bytes(), hello_key] {
+        transcript.extend_from_slice(&(part.len() as u32).to_le_bytes());
+        transcript.extend_from_slice(part);
+    }
+    for rand in [client_rand.0, client_rand.1, server_rand.0, server_rand.1] {
+        transcript.extend_from_slice(&rand.to_le_bytes());
+    }
+    transcript
+}
+
 pub struct RelayClient;
 
 #[derive(Debug, Clone, Copy)]
@@ -66,8 +127,11 @@ pub enum LoginResponseEnum {
     First,
     NotMatch,
     Frequently,
+    //密码正确，回调返回用户输入的验证码
+    NeedSecondFactor,
 }
 impl RelayClient {
+    ///登录成功后返回连接和被控端授予的权限
     pub fn start<F>(
         my_id: String,
         peer_password_fn: F,
@@ -76,7 +140,7 @@ impl RelayClient {
         peer_id: String,
         peer_pub_key: Vec<u8>,
         relay_pub_key: Vec<u8>,
-    ) -> ResultType<TcpFramed>
+    ) -> ResultType<(TcpFramed, Permissions)>
     where
         F: Fn(String, String, LoginResponseEnum) -> Option<Vec<u8>> + 'static,
     {
@@ -100,7 +164,7 @@ impl RelayClient {
         peer_pub_key: Vec<u8>,
         relay_rand: u64,
         mut tcp_framed: TcpFramed,
-    ) -> ResultType<TcpFramed>
+    ) -> ResultType<(TcpFramed, Permissions)>
     where
         F: Fn(String, String, LoginResponseEnum) -> Option<Vec<u8>> + 'static,
     {
@@ -110,28 +174,33 @@ impl RelayClient {
         let rand2 = (relay_rand << 32) | (my_rand >> 32);
         log::info!("生成随机数 rand1:{},rand2:{}", rand1, rand2);
         let mut client_hello = RemoteMessage::new();
-        let key = rand::thread_rng().gen::<[u8; 16]>();
+        let key_bytes = rand::thread_rng().gen::<[u8; 16]>();
         let nonce = rand::thread_rng().gen::<[u8; 12]>();
         client_hello.set_client_hello(ClientHello {
-            my_id,
+            my_id: my_id.clone(),
             peer_id: peer_id.clone(),
             rand1,
             rand2,
-            key: key.to_vec(),
+            key: key_bytes.to_vec(),
             nonce: nonce.to_vec(),
             ..Default::default()
         });
-        let aes = Aes128GcmUtil::new(&key)?;
+        let aes = Aes128GcmUtil::new(&key_bytes)?;
         let peer_pub_key = RsaPubKey::new(peer_pub_key)?;
         let bytes = peer_pub_key.pub_key_encrypt(&client_hello.write_to_bytes()?)?;
         log::info!("client_hello::{:?}", client_hello);
         tcp_framed.send(bytes)?;
-        let (peer_rand1, peer_rand2, hash) = if let Ok(server_hello) = tcp_framed.next() {
+        let (peer_rand1, peer_rand2, salt, rounds) = if let Ok(server_hello) = tcp_framed.next() {
             if let Ok(server_hello) = aes.decrypt(&server_hello, &nonce) {
                 let msg = RemoteMessage::parse_from_bytes(&server_hello)?;
                 log::info!("server_hello::{:?}", msg);
                 if let Some(remote_message::Union::server_hello(server_hello)) = msg.union {
-                    (server_hello.rand1, server_hello.rand2, server_hello.hash)
+                    (
+                        server_hello.rand1,
+                        server_hello.rand2,
+                        server_hello.salt,
+                        server_hello.rounds,
+                    )
                 } else {
                     Err(RemoteError::Peer(String::from("被控方消息错误")))?
                 }
@@ -141,32 +210,47 @@ impl RelayClient {
         } else {
             Err(RemoteError::Peer(String::from("断开连接")))?
         };
+        //旧版本的被控端不提供盐，迭代次数过大时派生密钥会卡住
+        if salt.is_empty() || rounds == 0 || rounds > MAX_ROUNDS {
+            Err(RemoteError::Peer(String::from("被控方版本不兼容")))?
+        }
         let key = ((peer_rand1 as u128) << 64) | (rand1 as u128 >> 64);
         let nonce = ((peer_rand2 as u128) << 64) | (rand2 as u128 >> 64);
         log::info!("协商密钥 key:{},nonce:{}", key, nonce);
         tcp_framed.set_aes(&key.to_le_bytes(), nonce.to_le_bytes()[..12].to_vec())?;
+        let transcript = login_transcript(
+            &my_id,
+            &peer_id,
+            &key_bytes,
+            (rand1, rand2),
+            (peer_rand1, peer_rand2),
+        );
         let mut check_info = String::new();
         let mut login_enum = LoginResponseEnum::First;
-        loop {
-            let peer_password = if let Some(peer_password) =
+        //要求验证码时重发上次的密钥
+        let mut login_key = Vec::new();
+        let permissions = loop {
+            //回调返回用户输入的密码原文或验证码
+            let input = if let Some(input) =
                 peer_password_fn(peer_id.clone(), check_info.clone(), login_enum)
             {
-                peer_password
+                input
             } else {
                 Err(RemoteError::Login(String::from("关闭")))?
             };
-            let mut hasher2 = Sha256::new();
-            hasher2.update(&hash);
-            hasher2.update(peer_password);
-            let my_hash = rand::thread_rng().gen::<[u8; 16]>();
-            hasher2.update(my_hash);
+            let otp = if let LoginResponseEnum::NeedSecondFactor = login_enum {
+                String::from_utf8_lossy(&input).to_string()
+            } else {
+                //不发送明文和派生的密钥，只发送对本次连接的签名
+                login_key = derive_password(&input, &salt, rounds);
+                String::new()
+            };
             let mut login = RemoteMessage::new();
             login.set_login_request(LoginRequest {
-                password: hasher2.finalize()[..].into(),
-                hash: my_hash.into(),
+                proof: login_proof(&login_key, &transcript),
+                otp,
                 ..Default::default()
             });
-            log::info!("登录消息:{:?}", login);
             tcp_framed.send(login.write_to_bytes()?)?;
             if let Ok(login_response) = tcp_framed.next() {
                 let msg = RemoteMessage::parse_from_bytes(&login_response)?;
@@ -174,16 +258,31 @@ impl RelayClient {
                     log::info!("登录消息回应:{:?}", login_response);
                     match login_response.code.enum_value_or_default() {
                         crate::message::login_response::Code::Success => {
-                            break;
+                            //旧版本的被控端不返回权限，按完全控制处理
+                            if login_response.permissions == 0 {
+                                break Permissions::FULL;
+                            }
+                            break Permissions::from_bits(login_response.permissions);
                         }
                         crate::message::login_response::Code::NotMatch => {
                             check_info = login_response.error;
                             login_enum = LoginResponseEnum::NotMatch;
                         }
                         crate::message::login_response::Code::Frequently => {
+                            //被控端已锁定，重新输入也会被拒绝
+                            if login_response.lockout_secs > 0 {
+                                return Err(RemoteError::Rejected(login_response.error).into());
+                            }
                             check_info = login_response.error;
                             login_enum = LoginResponseEnum::Frequently;
                         }
+                        crate::message::login_response::Code::Rejected => {
+                            Err(RemoteError::Rejected(login_response.error))?
+                        }
+                        crate::message::login_response::Code::NeedSecondFactor => {
+                            check_info = login_response.error;
+                            login_enum = LoginResponseEnum::NeedSecondFactor;
+                        }
                     }
                 } else {
                     Err(RemoteError::Login(String::from("被控方消息错误")))?
@@ -191,35 +290,55 @@ impl RelayClient {
             } else {
                 Err(RemoteError::Peer(String::from("断开连接")))?
             }
-        }
+        };
 
         //登录成功
-        Ok(tcp_framed)
+        Ok((tcp_framed, permissions))
     }
 }
 
 pub struct RelayServer;
 impl RelayServer {
-    pub fn start(
+    ///密码验证通过后用该密码对应的权限调用approve_fn，返回最终授予的权限，None时拒绝连接，
+    ///source为ID服务器看到的主控端地址，和主控端代号一起记录失败次数
+    pub fn start<F>(
         my_id: String,
-        my_password: &[u8],
+        credentials: &Credentials,
         my_priv_key: &RsaPrivKey,
         relay_addr: String,
         relay_id: String,
         peer_id: String,
+        source: String,
         relay_pub_key: Vec<u8>,
-    ) -> ResultType<TcpFramed> {
+        approve_fn: F,
+    ) -> ResultType<(TcpFramed, Permissions)>
+    where
+        F: FnOnce(Permissions) -> Option<Permissions>,
+    {
         let (framed, _) = relay_start(relay_addr, relay_id, relay_pub_key)?;
-        RelayServer::connect_peer(my_id, my_password, my_priv_key, peer_id, framed)
+        RelayServer::connect_peer(
+            my_id,
+            credentials,
+            my_priv_key,
+            peer_id,
+            source,
+            framed,
+            approve_fn,
+        )
     }
-    fn connect_peer(
+    fn connect_peer<F>(
         my_id: String,
-        my_password: &[u8],
+        credentials: &Credentials,
         my_priv_key: &RsaPrivKey,
         peer_id: String,
+        source: String,
         mut framed: TcpFramed,
-    ) -> ResultType<TcpFramed> {
-        if let Ok(client_hello) = framed.next() {
+        approve_fn: F,
+    ) -> ResultType<(TcpFramed, Permissions)>
+    where
+        F: FnOnce(Permissions) -> Option<Permissions>,
+    {
+        let permissions = if let Ok(client_hello) = framed.next() {
             if let Ok(client_hello) = my_priv_key.priv_key_decrypt(&client_hello) {
                 let msg = RemoteMessage::parse_from_bytes(&client_hello)?;
                 if let Some(remote_message::Union::client_hello(client_hello)) = msg.union {
@@ -233,11 +352,11 @@ impl RelayServer {
                     let peer_rand2 = client_hello.rand2;
                     let rand1: u64 = rand::thread_rng().gen();
                     let rand2: u64 = rand::thread_rng().gen();
-                    let hash = rand::thread_rng().gen::<[u8; 16]>();
                     server_hello.set_server_hello(ServerHello {
                         rand1,
                         rand2,
-                        hash: hash.to_vec(),
+                        salt: credentials.salt.clone(),
+                        rounds: credentials.rounds,
                         ..Default::default()
                     });
                     framed
@@ -247,60 +366,146 @@ impl RelayServer {
                     let nonce = ((rand2 as u128) << 64) | (peer_rand2 as u128 >> 64);
                     log::info!("协商密钥 key:{},nonce:{}", key, nonce);
                     framed.set_aes(&key.to_le_bytes(), nonce.to_le_bytes()[..12].to_vec())?;
+                    let transcript = login_transcript(
+                        &peer_id,
+                        &my_id,
+                        &client_hello.key,
+                        (peer_rand1, peer_rand2),
+                        (rand1, rand2),
+                    );
                     //验证密码
                     let mut check = 0;
-                    loop {
+                    let permissions = loop {
                         if let Ok(password) = framed.next() {
                             let msg = RemoteMessage::parse_from_bytes(&password)?;
-                            log::info!("登录消息:{:?}", msg);
                             if let Some(remote_message::Union::login_request(login_request)) =
                                 msg.union
                             {
-                                let mut hasher2 = Sha256::new();
-                                hasher2.update(hash);
-                                hasher2.update(my_password);
-                                hasher2.update(login_request.hash);
-                                let password_hash = hasher2.finalize()[..].to_vec();
+                                //密码和验证码不能写入日志
+                                log::info!(
+                                    "登录请求:{},验证码:{}",
+                                    peer_id,
+                                    !login_request.otp.is_empty()
+                                );
+                                if let Some(guard) = credentials.guard {
+                                    let secs = (guard.locked)(&peer_id, &source);
+                                    if secs > 0 {
+                                        framed.send(lockout_response(secs)?)?;
+                                        Err(RemoteError::Login(String::from("登录已锁定")))?
+                                    }
+                                }
+                                //依次用各个等级密码的校验值检查登录证明
+                                let matches = |verifier: &[u8]| {
+                                    check_login_proof(verifier, &transcript, &login_request.proof)
+                                };
+                                let offered = credentials
+                                    .verifiers
+                                    .iter()
+                                    .find(|(v, _, _)| matches(v))
+                                    .map(|(_, permissions, need_otp)| (*permissions, *need_otp))
+                                    .or_else(|| {
+                                        //临时密码按当前的值比对，不需要验证码
+                                        let one_time = credentials.one_time?;
+                                        one_time(&matches).map(|permissions| (permissions, false))
+                                    });
                                 let mut login_response = RemoteMessage::new();
-                                if password_hash != login_request.password {
-                                    if check > 5 {
-                                        login_response.set_login_response(LoginResponse {
-                                            code: protobuf::ProtobufEnumOrUnknown::from(
-                                                login_response::Code::Frequently,
-                                            ),
-                                            error: String::from("密码错误,尝试密码次数多"),
-                                            ..Default::default()
-                                        });
-                                        framed.send(login_response.write_to_bytes()?)?;
-                                        Err(RemoteError::Login(String::from("尝试密码次数多")))?
-                                    } else {
+                                let offered = match (offered, credentials.second_factor) {
+                                    (Some((offered, true)), Some(second_factor)) => {
+                                        if login_request.otp.is_empty() {
+                                            //先让主控端输入验证码，不算失败
+                                            login_response.set_login_response(LoginResponse {
+                                                code: protobuf::ProtobufEnumOrUnknown::from(
+                                                    login_response::Code::NeedSecondFactor,
+                                                ),
+                                                ..Default::default()
+                                            });
+                                            framed.send(login_response.write_to_bytes()?)?;
+                                            continue;
+                                        }
+                                        if second_factor(&login_request.otp) {
+                                            Ok(offered)
+                                        } else {
+                                            Err((
+                                                login_response::Code::NeedSecondFactor,
+                                                "验证码错误",
+                                            ))
+                                        }
+                                    }
+                                    (Some((offered, _)), _) => Ok(offered),
+                                    (None, _) => Err((login_response::Code::NotMatch, "密码错误")),
+                                };
+                                let offered = match offered {
+                                    Ok(offered) => offered,
+                                    Err((code, error)) => {
+                                        let secs = credentials
+                                            .guard
+                                            .map(|guard| (guard.failed)(&peer_id, &source))
+                                            .unwrap_or(0);
+                                        if secs > 0 {
+                                            framed.send(lockout_response(secs)?)?;
+                                            Err(RemoteError::Login(String::from("登录已锁定")))?
+                                        }
+                                        if check > 5 {
+                                            login_response.set_login_response(LoginResponse {
+                                                code: protobuf::ProtobufEnumOrUnknown::from(
+                                                    login_response::Code::Frequently,
+                                                ),
+                                                error: String::from("密码错误,尝试密码次数多"),
+                                                ..Default::default()
+                                            });
+                                            framed.send(login_response.write_to_bytes()?)?;
+                                            Err(RemoteError::Login(String::from("尝试密码次数多")))?
+                                        } else {
+                                            login_response.set_login_response(LoginResponse {
+                                                code: protobuf::ProtobufEnumOrUnknown::from(code),
+                                                error: String::from(error),
+                                                ..Default::default()
+                                            });
+                                            framed.send(login_response.write_to_bytes()?)?;
+                                        }
+                                        check += 1;
+                                        continue;
+                                    }
+                                };
+                                if let Some(guard) = credentials.guard {
+                                    (guard.succeeded)(&peer_id, &source);
+                                }
+                                //等待被控端的用户确认，主控端这时在等登录结果
+                                let granted = match approve_fn(offered) {
+                                    //不能超出密码对应的权限
+                                    Some(granted) if granted & offered != Permissions::NONE => {
+                                        granted & offered
+                                    }
+                                    _ => {
                                         login_response.set_login_response(LoginResponse {
                                             code: protobuf::ProtobufEnumOrUnknown::from(
-                                                login_response::Code::NotMatch,
+                                                login_response::Code::Rejected,
                                             ),
-                                            error: String::from("密码错误"),
+                                            error: String::from("被控端拒绝了连接"),
                                             ..Default::default()
                                         });
                                         framed.send(login_response.write_to_bytes()?)?;
+                                        Err(RemoteError::Login(String::from("拒绝连接")))?
                                     }
-                                } else {
-                                    login_response.set_login_response(LoginResponse {
-                                        code: protobuf::ProtobufEnumOrUnknown::from(
-                                            login_response::Code::Success,
-                                        ),
-                                        ..Default::default()
-                                    });
-                                    framed.send(login_response.write_to_bytes()?)?;
-                                    break;
-                                }
-                                check += 1;
+                                };
+                                login_response.set_login_response(LoginResponse {
+                                    code: protobuf::ProtobufEnumOrUnknown::from(
+                                        login_response::Code::Success,
+                                    ),
+                                    permissions: granted.bits(),
+                                    ..Default::default()
+                                });
+                                framed.send(login_response.write_to_bytes()?)?;
+                                break granted;
                             } else {
                                 Err(RemoteError::Login(String::from("主控方消息错误")))?
                             }
                         } else {
                             Err(RemoteError::Peer(String::from("断开连接")))?
                         }
-                    }
+                    };
+                    log::info!("授予权限:{}", permissions.describe());
+                    permissions
                 } else {
                     Err(RemoteError::Peer(String::from("主控方消息错误")))?
                 }
@@ -311,6 +516,6 @@ impl RelayServer {
             Err(RemoteError::Peer(String::from("断开连接")))?
         };
 
-        Ok(framed)
+        Ok((framed, permissions))
     }
 }
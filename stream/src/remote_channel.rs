@@ -1,7 +1,7 @@
 use std::{
     ops::{Deref, DerefMut},
     sync::{
-        atomic::AtomicBool,
+        atomic::{AtomicBool, AtomicU32},
         mpsc::{sync_channel, Receiver, SyncSender},
         Arc,
     },
@@ -10,18 +10,19 @@ use std::{
 use dashmap::DashMap;
 use protobuf::Message;
 
-use crate::{RemoteError, ResultType, bytes_codec::{TcpFramed }, message::{ChannelControl, ChannelMover, ChannelPower, ChannelType}};
+use crate::{RemoteError, ResultType, bytes_codec::{TcpFramed }, message::{ChannelControl, ChannelMover, ChannelPower, ChannelType}, permission::Permissions};
 
 pub struct ChannelManager {
-    id: u32,
+    id: Arc<AtomicU32>,
     sender_in: SyncSender<Vec<u8>>,
     channel_map: Arc<DashMap<u32, SyncSender<Vec<u8>>>>,
     status: Arc<AtomicBool>,
+    peer_id: Arc<String>,
 }
 impl Drop for ChannelManager {
     fn drop(&mut self) {
         //回收客户端通道
-        if self.id & 1 == 0 {
+        if self.id.load(std::sync::atomic::Ordering::SeqCst) & 1 == 0 {
             log::info!("回收客户端通道");
             if self.status.load(std::sync::atomic::Ordering::SeqCst) {
                 match self.sender_in.send(Vec::new()) {
@@ -47,10 +48,14 @@ impl ChannelManager {
         self.sender_in.send(data)?;
         Ok(())
     }
+    ///permissions为允许对方建立的通道，超出的建立请求直接销毁，
+    ///peer_id为登录成功的对方代号，回调时从ChannelOpener取得
     pub fn new_relay(
         is_client: bool,
         framed: TcpFramed,
-        create_channel_callback: fn(Option<ChannelSender>, Option<ChannelReceiver>, ChannelType),
+        peer_id: String,
+        permissions: Permissions,
+        create_channel_callback: fn(Option<ChannelSender>, Option<ChannelReceiver>, ChannelType, ChannelOpener),
         destroy_channel_callback: fn(u32, ChannelType),
     ) -> ResultType<Self> {
         let channel_map: Arc<DashMap<u32, SyncSender<Vec<u8>>>> = Arc::new(DashMap::new());
@@ -85,42 +90,45 @@ impl ChannelManager {
             }
             status1.store(false, std::sync::atomic::Ordering::SeqCst);
         });
-        let status2 = status.clone();
-        let sender_in1 = sender_in.clone();
-        let channel_map_in = channel_map.clone();
+        //客户端建立的通道二进制0结尾，服务端建立的通道1结尾
+        let id = Arc::new(AtomicU32::new(if is_client { 0 } else { 1 }));
+        let manager = ChannelManager {
+            id,
+            sender_in,
+            channel_map,
+            status,
+            peer_id: Arc::new(peer_id),
+        };
+        //对方建立的通道和本连接的opener一起交给回调
+        let opener = manager.opener();
         std::thread::spawn(move || {
             let rs = ChannelManager::next_loop(
-                sender_in1,
-                channel_map_in,
+                &opener,
                 framed,
+                permissions,
                 create_channel_callback,
                 destroy_channel_callback,
             );
             log::info!("通道管理器 loop 关闭流{:?}", rs);
-            status2.store(false, std::sync::atomic::Ordering::SeqCst);
+            opener.status.store(false, std::sync::atomic::Ordering::SeqCst);
+            //连接断开后各通道的接收端随之结束
+            opener.channel_map.clear();
         });
-        //客户端建立的通道二进制0结尾，服务端建立的通道1结尾
-        let id = if is_client { 0 } else { 1 };
-        Ok(ChannelManager {
-            id,
-            sender_in,
-            channel_map,
-            status,
-        })
+        Ok(manager)
     }
     fn next_loop(
-        sender: SyncSender<Vec<u8>>,
-        channel_map: Arc<DashMap<u32, SyncSender<Vec<u8>>>>,
+        opener: &ChannelOpener,
         mut tcp_stream: TcpFramed,
-        create_channel_callback: fn(Option<ChannelSender>, Option<ChannelReceiver>, ChannelType),
+        permissions: Permissions,
+        create_channel_callback: fn(Option<ChannelSender>, Option<ChannelReceiver>, ChannelType, ChannelOpener),
         destroy_channel_callback: fn(u32, ChannelType),
     ) -> ResultType<()> {
         loop {
             let data = tcp_stream.next()?;
             ChannelManager::next_msg(
                 data,
-                &sender,
-                &channel_map,
+                opener,
+                permissions,
                 create_channel_callback,
                 destroy_channel_callback,
             )?;
@@ -128,15 +136,29 @@ impl ChannelManager {
     }
     fn next_msg(
         data: Vec<u8>,
-        sender: &SyncSender<Vec<u8>>,
-        channel_map: &Arc<DashMap<u32, SyncSender<Vec<u8>>>>,
-        create_channel_callback: fn(Option<ChannelSender>, Option<ChannelReceiver>, ChannelType),
+        opener: &ChannelOpener,
+        permissions: Permissions,
+        create_channel_callback: fn(Option<ChannelSender>, Option<ChannelReceiver>, ChannelType, ChannelOpener),
         destroy_channel_callback: fn(u32, ChannelType),
     ) -> ResultType<()> {
+        let sender = &opener.sender_in;
+        let channel_map = &opener.channel_map;
         let rs = ChannelMover::parse_from_bytes(&data)?;
         if let Ok(control) = rs.control.enum_value() {
             match control {
                 ChannelControl::Create => {
+                    let channel_type = rs.channel_type.enum_value_or_default();
+                    if !permissions.allows(channel_type) {
+                        log::info!("没有权限建立通道:{:?}", channel_type);
+                        let channel_mover = ChannelMover {
+                            id: rs.id,
+                            control: protobuf::ProtobufEnumOrUnknown::new(ChannelControl::Destroy),
+                            channel_type: rs.channel_type,
+                            ..Default::default()
+                        };
+                        sender.send(channel_mover.write_to_bytes()?)?;
+                        return Ok(());
+                    }
                     match rs.channel_power.enum_value_or_default() {
                         ChannelPower::Both => {
                             let (sender_down, receiver_down) = sync_channel::<Vec<u8>>(10);
@@ -146,6 +168,7 @@ impl ChannelManager {
                                 Some(ChannelSender(sender_up, rs.id)),
                                 Some(ChannelReceiver(receiver_down)),
                                 rs.channel_type.enum_value_or_default(),
+                                opener.clone(),
                             )
                         }
                         ChannelPower::Read => {
@@ -155,6 +178,7 @@ impl ChannelManager {
                                 Some(ChannelSender(sender_up, rs.id)),
                                 None,
                                 rs.channel_type.enum_value_or_default(),
+                                opener.clone(),
                             )
                         }
                         ChannelPower::Write => {
@@ -165,6 +189,7 @@ impl ChannelManager {
                                 None,
                                 Some(ChannelReceiver(receiver_down)),
                                 rs.channel_type.enum_value_or_default(),
+                                opener.clone(),
                             )
                         }
                     }
@@ -210,10 +235,10 @@ impl ChannelManager {
             Err(RemoteError::Disconnection)?;
         }
         let (sender_down, receiver_down) = sync_channel::<Vec<u8>>(10);
-        self.id += 2;
-        self.channel_map.insert(self.id, sender_down);
+        let id = self.next_id();
+        self.channel_map.insert(id, sender_down);
         let channel = ChannelMover {
-            id: self.id,
+            id,
             control: protobuf::ProtobufEnumOrUnknown::new(ChannelControl::Create),
             channel_type: protobuf::ProtobufEnumOrUnknown::new(channel_type),
             channel_power: protobuf::ProtobufEnumOrUnknown::new(ChannelPower::Read),
@@ -228,9 +253,9 @@ impl ChannelManager {
         if !self.status.load(std::sync::atomic::Ordering::SeqCst) {
             Err(RemoteError::Disconnection)?;
         }
-        self.id += 2;
+        let id = self.next_id();
         let channel = ChannelMover {
-            id: self.id,
+            id,
             control: protobuf::ProtobufEnumOrUnknown::new(ChannelControl::Create),
             channel_type: protobuf::ProtobufEnumOrUnknown::new(channel_type),
             channel_power: protobuf::ProtobufEnumOrUnknown::new(ChannelPower::Write),
@@ -239,44 +264,96 @@ impl ChannelManager {
         let sender_up = self.sender_in.clone();
         log::info!("建立通道只写:{:?}", channel_type);
         self.send(channel.write_to_bytes()?)?;
-        Ok(ChannelSender(sender_up, self.id))
+        Ok(ChannelSender(sender_up, id))
     }
     //读写
     pub fn create_channel(
         &mut self,
         channel_type: ChannelType,
+    ) -> ResultType<(ChannelSender, ChannelReceiver)> {
+        self.opener().create_channel(channel_type)
+    }
+    pub fn destroy_channel(&mut self, channel_id: u32) -> ResultType<()> {
+        self.opener().destroy_channel(channel_id)
+    }
+    fn next_id(&self) -> u32 {
+        self.id.fetch_add(2, std::sync::atomic::Ordering::SeqCst) + 2
+    }
+    ///可以在其他线程中建立通道，如每个转发的TCP连接一个通道
+    pub fn opener(&self) -> ChannelOpener {
+        ChannelOpener {
+            id: self.id.clone(),
+            sender_in: self.sender_in.clone(),
+            channel_map: self.channel_map.clone(),
+            status: self.status.clone(),
+            peer_id: self.peer_id.clone(),
+        }
+    }
+}
+///和通道管理器共用通道编号和通道表，不负责关闭连接
+#[derive(Clone)]
+pub struct ChannelOpener {
+    id: Arc<AtomicU32>,
+    sender_in: SyncSender<Vec<u8>>,
+    channel_map: Arc<DashMap<u32, SyncSender<Vec<u8>>>>,
+    status: Arc<AtomicBool>,
+    peer_id: Arc<String>,
+}
+impl ChannelOpener {
+    pub fn is_run(&self) -> bool {
+        self.status.load(std::sync::atomic::Ordering::SeqCst)
+    }
+    //该连接对方的代号
+    pub fn peer_id(&self) -> &str {
+        &self.peer_id
+    }
+    //读写
+    pub fn create_channel(
+        &self,
+        channel_type: ChannelType,
     ) -> ResultType<(ChannelSender, ChannelReceiver)> {
         if !self.status.load(std::sync::atomic::Ordering::SeqCst) {
             Err(RemoteError::Disconnection)?;
         }
         let (sender_down, receiver_down) = sync_channel::<Vec<u8>>(10);
-        self.id += 2;
-        self.channel_map.insert(self.id, sender_down);
+        let id = self.id.fetch_add(2, std::sync::atomic::Ordering::SeqCst) + 2;
+        self.channel_map.insert(id, sender_down);
         let channel = ChannelMover {
-            id: self.id,
+            id,
             control: protobuf::ProtobufEnumOrUnknown::new(ChannelControl::Create),
             channel_type: protobuf::ProtobufEnumOrUnknown::new(channel_type),
             channel_power: protobuf::ProtobufEnumOrUnknown::new(ChannelPower::Both),
             ..Default::default()
         };
         let sender_up = self.sender_in.clone();
-        self.send(channel.write_to_bytes()?)?;
-        return Ok((
-            ChannelSender(sender_up, self.id),
-            ChannelReceiver(receiver_down),
-        ));
+        self.sender_in.send(channel.write_to_bytes()?)?;
+        Ok((ChannelSender(sender_up, id), ChannelReceiver(receiver_down)))
     }
-    pub fn destroy_channel(&mut self, channel_id: u32) -> ResultType<()> {
+    pub fn destroy_channel(&self, channel_id: u32) -> ResultType<()> {
         self.channel_map.remove(&channel_id);
         let channel_mover = ChannelMover {
             id: channel_id,
             control: protobuf::ProtobufEnumOrUnknown::new(ChannelControl::Destroy),
             ..Default::default()
         };
-        self.send(channel_mover.write_to_bytes()?)?;
+        self.sender_in.send(channel_mover.write_to_bytes()?)?;
         Ok(())
     }
 }
+///不经过连接的一对通道，用于在本机测试各通道的处理逻辑。
+///ChannelSender发送的ChannelMover由返回的Receiver收到，返回的SyncSender发送的数据由ChannelReceiver收到
+pub fn local_channel(
+    id: u32,
+) -> (ChannelSender, Receiver<Vec<u8>>, SyncSender<Vec<u8>>, ChannelReceiver) {
+    let (sender_up, receiver_up) = sync_channel::<Vec<u8>>(10);
+    let (sender_down, receiver_down) = sync_channel::<Vec<u8>>(10);
+    (
+        ChannelSender(sender_up, id),
+        receiver_up,
+        sender_down,
+        ChannelReceiver(receiver_down),
+    )
+}
 #[derive(Debug, Clone)]
 pub struct ChannelSender(SyncSender<Vec<u8>>, u32);
 
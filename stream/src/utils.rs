@@ -1,12 +1,14 @@
 use aes_gcm::aead::{Aead, NewAead};
 use aes_gcm::{Aes128Gcm, Key, Nonce};
 
+use hmac::Hmac;
 use p12::PFX;
 use rcgen::{Certificate, RcgenError};
 use rsa::{
-    pkcs1::{FromRsaPublicKey, ToRsaPublicKey},
+    pkcs1::{FromRsaPrivateKey, FromRsaPublicKey, ToRsaPrivateKey, ToRsaPublicKey},
     PaddingScheme, PublicKey, RsaPrivateKey, RsaPublicKey,
 };
+use sha2::{Digest, Sha256};
 
 #[derive(Clone)]
 pub struct Aes128GcmUtil(Aes128Gcm);
@@ -47,6 +49,57 @@ impl Aes128GcmUtil {
 
 use crate::{RemoteError, ResultType};
 
+///公钥指纹，SHA-256的前16字节，用于人工核对对方的身份
+pub fn fingerprint(pub_key: &[u8]) -> String {
+    let hash = Sha256::digest(pub_key);
+    hash[..16]
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+///由密码、盐和迭代次数派生登录用的密钥，PBKDF2-HMAC-SHA256
+pub fn derive_password(password: &[u8], salt: &[u8], rounds: u32) -> Vec<u8> {
+    let mut key = vec![0u8; 32];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(password, salt, rounds, &mut key);
+    key
+}
+
+///被控端保存的密码校验值，即以派生密钥为私钥的Ed25519公钥，拿到它也不能登录
+pub fn password_verifier(key: &[u8]) -> Vec<u8> {
+    match ed25519_dalek::SecretKey::from_bytes(key) {
+        Ok(secret) => ed25519_dalek::PublicKey::from(&secret).to_bytes().to_vec(),
+        Err(_) => Vec::new(),
+    }
+}
+
+///主控端用派生密钥对本次连接的内容签名，每次连接都不同，被截获后不能重放
+pub fn login_proof(key: &[u8], transcript: &[u8]) -> Vec<u8> {
+    match ed25519_dalek::SecretKey::from_bytes(key) {
+        Ok(secret) => {
+            let public = ed25519_dalek::PublicKey::from(&secret);
+            ed25519_dalek::ExpandedSecretKey::from(&secret)
+                .sign(transcript, &public)
+                .to_bytes()
+                .to_vec()
+        }
+        Err(_) => Vec::new(),
+    }
+}
+
+///被控端用保存的校验值检查登录证明
+pub fn check_login_proof(verifier: &[u8], transcript: &[u8], proof: &[u8]) -> bool {
+    let public = match ed25519_dalek::PublicKey::from_bytes(verifier) {
+        Ok(public) => public,
+        Err(_) => return false,
+    };
+    match ed25519_dalek::Signature::from_bytes(proof) {
+        Ok(signature) => public.verify_strict(transcript, &signature).is_ok(),
+        Err(_) => false,
+    }
+}
+
 pub struct Cert(Certificate);
 impl Cert {
     pub fn new() -> ResultType<Self> {
@@ -76,6 +129,13 @@ impl RsaPrivKey {
         let priv_key = RsaPrivateKey::new(&mut rng, bits)?;
         Ok(RsaPrivKey(priv_key))
     }
+    ///从保存的PKCS#1 DER格式读取
+    pub fn from_bytes(data: &[u8]) -> ResultType<Self> {
+        Ok(RsaPrivKey(RsaPrivateKey::from_pkcs1_der(data)?))
+    }
+    pub fn to_bytes(&self) -> ResultType<Vec<u8>> {
+        Ok(self.0.to_pkcs1_der()?.as_der().to_vec())
+    }
     pub fn priv_key_decrypt(&self, data: &[u8]) -> ResultType<Vec<u8>> {
         let dec_data = self.0.decrypt(PaddingScheme::PKCS1v15Encrypt, data)?;
         Ok(dec_data)
@@ -110,3 +170,21 @@ impl RsaPubKey {
             .verify(PaddingScheme::new_pkcs1v15_sign(None), data, sign)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_login_proof() {
+        let key = derive_password(b"password", b"salt", 1);
+        let verifier = password_verifier(&key);
+        let proof = login_proof(&key, b"session1");
+        assert!(check_login_proof(&verifier, b"session1", &proof));
+        //其他连接不能重放
+        assert!(!check_login_proof(&verifier, b"session2", &proof));
+        let other = password_verifier(&derive_password(b"other", b"salt", 1));
+        assert!(!check_login_proof(&other, b"session1", &proof));
+        assert!(!check_login_proof(&verifier, b"session1", &key));
+    }
+}